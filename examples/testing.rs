@@ -2,32 +2,39 @@ const TOKEN: &str = include_str!("../token.secret");
 
 use std::sync::{Arc, Mutex};
 
+use vivcord::framework::prefix::{PrefixFramework, TextCommand, TextContext};
+
 #[tokio::main]
 async fn main() {
     let count = Arc::new(Mutex::new(0));
-    let client = vivcord::Client::new(TOKEN.to_owned(), count);
-
     let intents = vivcord::Intents::MESSAGE_CONTENT | vivcord::Intents::GUILD_MESSAGES;
-    client
-        .run(&intents, |event, client| async move {
-            if let vivcord::EventData::MessageCreate(msg) = event {
-                if msg.content == "test" {
-                    let new_msg = {
-                        let mut count = client.state.lock().unwrap();
-                        *count += 1;
+    let client = vivcord::Client::builder(TOKEN.to_owned(), count)
+        .intents(intents)
+        .build_checked()
+        .await
+        .unwrap();
 
-                        vivcord::CreateMessageParams {
-                            content: Some(count.to_string()),
-                        }
-                    };
+    let framework = Arc::new(PrefixFramework::new().prefix("!").command(TextCommand::new(
+        "test",
+        "Count how often this was used",
+        |ctx: TextContext<Arc<Mutex<i32>>>, _| async move {
+            let count = {
+                let mut count = ctx.context.state.lock().unwrap();
+                *count += 1;
+                *count
+            };
 
-                    client
-                        .api
-                        .read()
-                        .await
-                        .create_message(msg.channel_id, new_msg)
-                        .await
-                        .unwrap();
+            ctx.say(count.to_string()).await?;
+            Ok(())
+        },
+    )));
+
+    client
+        .run(move |event, ctx| {
+            let framework = framework.clone();
+            async move {
+                if let Err(err) = framework.handle(&event, &ctx).await {
+                    eprintln!("{err}");
                 }
             }
         })
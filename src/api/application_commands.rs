@@ -0,0 +1,399 @@
+//! Application command endpoints
+
+use serde::Serialize;
+
+use super::{Api, Error, Request};
+use crate::datatypes::{
+    ApplicationCommand, ApplicationCommandPermission, CreateApplicationCommandParams,
+    EditApplicationCommandParams, GuildApplicationCommandPermissions, Snowflake,
+};
+
+/// Query parameters for the endpoints listing commands
+#[derive(Serialize)]
+struct WithLocalizations {
+    with_localizations: bool,
+}
+
+/// Body of [`Api::edit_application_command_permissions`]
+#[derive(Serialize)]
+struct EditPermissions<'a> {
+    permissions: &'a [ApplicationCommandPermission],
+}
+
+impl Api {
+    /// Get all global commands of a application
+    ///
+    /// When `with_localizations` is `true` the localization fields will be set.
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn get_global_application_commands<A: Into<Snowflake>>(
+        &self,
+        application_id: A,
+        with_localizations: bool,
+    ) -> Result<Vec<ApplicationCommand>, Error> {
+        let application_id = application_id.into().0;
+
+        self.send(
+            Request::get(format!("applications/{application_id}/commands"))
+                .query(&WithLocalizations { with_localizations }),
+        )
+        .await
+    }
+
+    /// Create a global command, or update the existing command with the same name.
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as having too many commands.
+    /// [`Error::ValidationErr`] if the command exceeds discords limits, see [`CreateApplicationCommandParams::validate`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vivcord::{Api, api::Error};
+    /// # use vivcord::datatypes::CreateApplicationCommandParams;
+    /// # tokio_test::block_on(async move {
+    /// let api = Api::new("TOKEN");
+    /// api.create_global_application_command(
+    ///     12345,
+    ///     CreateApplicationCommandParams::chat_input("ping", "Check if the bot is alive"),
+    /// )
+    /// .await?;
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    pub async fn create_global_application_command<A: Into<Snowflake>>(
+        &self,
+        application_id: A,
+        command: CreateApplicationCommandParams,
+    ) -> Result<ApplicationCommand, Error> {
+        let application_id = application_id.into().0;
+        command.validate()?;
+
+        self.send(Request::post(format!("applications/{application_id}/commands")).json(&command))
+            .await
+    }
+
+    /// Get a single global command
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn get_global_application_command<A: Into<Snowflake>, C: Into<Snowflake>>(
+        &self,
+        application_id: A,
+        command_id: C,
+    ) -> Result<ApplicationCommand, Error> {
+        let application_id = application_id.into().0;
+        let command_id = command_id.into().0;
+
+        self.send(Request::get(format!(
+            "applications/{application_id}/commands/{command_id}"
+        )))
+        .await
+    }
+
+    /// Edit a global command
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn edit_global_application_command<A: Into<Snowflake>, C: Into<Snowflake>>(
+        &self,
+        application_id: A,
+        command_id: C,
+        params: EditApplicationCommandParams,
+    ) -> Result<ApplicationCommand, Error> {
+        let application_id = application_id.into().0;
+        let command_id = command_id.into().0;
+
+        self.send(
+            Request::patch(format!(
+                "applications/{application_id}/commands/{command_id}"
+            ))
+            .json(&params),
+        )
+        .await
+    }
+
+    /// Delete a global command
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn delete_global_application_command<A: Into<Snowflake>, C: Into<Snowflake>>(
+        &self,
+        application_id: A,
+        command_id: C,
+    ) -> Result<(), Error> {
+        let application_id = application_id.into().0;
+        let command_id = command_id.into().0;
+
+        self.send(Request::delete(format!(
+            "applications/{application_id}/commands/{command_id}"
+        )))
+        .await
+    }
+
+    /// Replace all global commands, commands not in the list are deleted.
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as having too many commands.
+    /// [`Error::ValidationErr`] if a command exceeds discords limits, see [`CreateApplicationCommandParams::validate`].
+    pub async fn bulk_overwrite_global_application_commands<A: Into<Snowflake>>(
+        &self,
+        application_id: A,
+        commands: Vec<CreateApplicationCommandParams>,
+    ) -> Result<Vec<ApplicationCommand>, Error> {
+        let application_id = application_id.into().0;
+        commands
+            .iter()
+            .try_for_each(CreateApplicationCommandParams::validate)?;
+
+        self.send(Request::put(format!("applications/{application_id}/commands")).json(&commands))
+            .await
+    }
+
+    /// Get all commands of a application in a guild
+    ///
+    /// When `with_localizations` is `true` the localization fields will be set.
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn get_guild_application_commands<A: Into<Snowflake>, G: Into<Snowflake>>(
+        &self,
+        application_id: A,
+        guild_id: G,
+        with_localizations: bool,
+    ) -> Result<Vec<ApplicationCommand>, Error> {
+        let application_id = application_id.into().0;
+        let guild_id = guild_id.into().0;
+
+        self.send(
+            Request::get(format!(
+                "applications/{application_id}/guilds/{guild_id}/commands"
+            ))
+            .query(&WithLocalizations { with_localizations }),
+        )
+        .await
+    }
+
+    /// Create a guild command, or update the existing command with the same name.
+    ///
+    /// Guild commands update instantly, which makes them useful for testing.
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as having too many commands.
+    /// [`Error::ValidationErr`] if the command exceeds discords limits, see [`CreateApplicationCommandParams::validate`].
+    pub async fn create_guild_application_command<A: Into<Snowflake>, G: Into<Snowflake>>(
+        &self,
+        application_id: A,
+        guild_id: G,
+        command: CreateApplicationCommandParams,
+    ) -> Result<ApplicationCommand, Error> {
+        let application_id = application_id.into().0;
+        let guild_id = guild_id.into().0;
+        command.validate()?;
+
+        self.send(
+            Request::post(format!(
+                "applications/{application_id}/guilds/{guild_id}/commands"
+            ))
+            .json(&command),
+        )
+        .await
+    }
+
+    /// Get a single guild command
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn get_guild_application_command<
+        A: Into<Snowflake>,
+        G: Into<Snowflake>,
+        C: Into<Snowflake>,
+    >(
+        &self,
+        application_id: A,
+        guild_id: G,
+        command_id: C,
+    ) -> Result<ApplicationCommand, Error> {
+        let application_id = application_id.into().0;
+        let guild_id = guild_id.into().0;
+        let command_id = command_id.into().0;
+
+        self.send(Request::get(format!(
+            "applications/{application_id}/guilds/{guild_id}/commands/{command_id}"
+        )))
+        .await
+    }
+
+    /// Edit a guild command
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn edit_guild_application_command<
+        A: Into<Snowflake>,
+        G: Into<Snowflake>,
+        C: Into<Snowflake>,
+    >(
+        &self,
+        application_id: A,
+        guild_id: G,
+        command_id: C,
+        params: EditApplicationCommandParams,
+    ) -> Result<ApplicationCommand, Error> {
+        let application_id = application_id.into().0;
+        let guild_id = guild_id.into().0;
+        let command_id = command_id.into().0;
+
+        self.send(
+            Request::patch(format!(
+                "applications/{application_id}/guilds/{guild_id}/commands/{command_id}"
+            ))
+            .json(&params),
+        )
+        .await
+    }
+
+    /// Delete a guild command
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn delete_guild_application_command<
+        A: Into<Snowflake>,
+        G: Into<Snowflake>,
+        C: Into<Snowflake>,
+    >(
+        &self,
+        application_id: A,
+        guild_id: G,
+        command_id: C,
+    ) -> Result<(), Error> {
+        let application_id = application_id.into().0;
+        let guild_id = guild_id.into().0;
+        let command_id = command_id.into().0;
+
+        self.send(Request::delete(format!(
+            "applications/{application_id}/guilds/{guild_id}/commands/{command_id}"
+        )))
+        .await
+    }
+
+    /// Replace all commands in a guild, commands not in the list are deleted.
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as having too many commands.
+    /// [`Error::ValidationErr`] if a command exceeds discords limits, see [`CreateApplicationCommandParams::validate`].
+    pub async fn bulk_overwrite_guild_application_commands<
+        A: Into<Snowflake>,
+        G: Into<Snowflake>,
+    >(
+        &self,
+        application_id: A,
+        guild_id: G,
+        commands: Vec<CreateApplicationCommandParams>,
+    ) -> Result<Vec<ApplicationCommand>, Error> {
+        let application_id = application_id.into().0;
+        let guild_id = guild_id.into().0;
+        commands
+            .iter()
+            .try_for_each(CreateApplicationCommandParams::validate)?;
+
+        self.send(
+            Request::put(format!(
+                "applications/{application_id}/guilds/{guild_id}/commands"
+            ))
+            .json(&commands),
+        )
+        .await
+    }
+
+    /// Get the permissions of all commands of a application in a guild
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn get_guild_application_command_permissions<
+        A: Into<Snowflake>,
+        G: Into<Snowflake>,
+    >(
+        &self,
+        application_id: A,
+        guild_id: G,
+    ) -> Result<Vec<GuildApplicationCommandPermissions>, Error> {
+        let application_id = application_id.into().0;
+        let guild_id = guild_id.into().0;
+
+        self.send(Request::get(format!(
+            "applications/{application_id}/guilds/{guild_id}/commands/permissions"
+        )))
+        .await
+    }
+
+    /// Get the permissions of a single command in a guild
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn get_application_command_permissions<
+        A: Into<Snowflake>,
+        G: Into<Snowflake>,
+        C: Into<Snowflake>,
+    >(
+        &self,
+        application_id: A,
+        guild_id: G,
+        command_id: C,
+    ) -> Result<GuildApplicationCommandPermissions, Error> {
+        let application_id = application_id.into().0;
+        let guild_id = guild_id.into().0;
+        let command_id = command_id.into().0;
+
+        self.send(Request::get(format!(
+            "applications/{application_id}/guilds/{guild_id}/commands/{command_id}/permissions"
+        )))
+        .await
+    }
+
+    /// Replace the permissions of a command in a guild
+    ///
+    /// # Important
+    /// Discord only allows this with a oauth2 bearer token of a user that can manage the guild,
+    /// requests using a bot token are rejected.
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as using a bot token.
+    pub async fn edit_application_command_permissions<
+        A: Into<Snowflake>,
+        G: Into<Snowflake>,
+        C: Into<Snowflake>,
+    >(
+        &self,
+        application_id: A,
+        guild_id: G,
+        command_id: C,
+        permissions: &[ApplicationCommandPermission],
+    ) -> Result<GuildApplicationCommandPermissions, Error> {
+        let application_id = application_id.into().0;
+        let guild_id = guild_id.into().0;
+        let command_id = command_id.into().0;
+
+        self.send(
+            Request::put(format!(
+                "applications/{application_id}/guilds/{guild_id}/commands/{command_id}/permissions"
+            ))
+            .json(&EditPermissions { permissions }),
+        )
+        .await
+    }
+}
@@ -0,0 +1,25 @@
+//! Application endpoints
+
+use super::{Api, Error, Request};
+use crate::datatypes::PartialApplication;
+
+impl Api {
+    /// Get the application of the bot
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vivcord::{Api, api::Error};
+    /// # tokio_test::block_on(async move {
+    /// let api = Api::new("TOKEN");
+    /// let application = api.get_current_application().await?;
+    /// println!("{:?}", application.flags);
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    pub async fn get_current_application(&self) -> Result<PartialApplication, Error> {
+        self.send(Request::get("applications/@me".to_owned())).await
+    }
+}
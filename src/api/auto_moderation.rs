@@ -0,0 +1,124 @@
+//! Auto moderation rule endpoints
+
+use super::{Api, Error, Request};
+use crate::datatypes::{
+    AutoModerationRule, CreateAutoModerationRuleParams, ModifyAutoModerationRuleParams, Snowflake,
+};
+
+impl Api {
+    /// Get all auto moderation rules of a guild
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids or missing `MANAGE_GUILD` permission.
+    pub async fn list_auto_moderation_rules<I: Into<Snowflake>>(
+        &self,
+        guild_id: I,
+    ) -> Result<Vec<AutoModerationRule>, Error> {
+        let guild_id = guild_id.into().0;
+
+        self.send(Request::get(format!("guilds/{guild_id}/auto-moderation/rules")))
+            .await
+    }
+
+    /// Get a single auto moderation rule
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids or missing `MANAGE_GUILD` permission.
+    pub async fn get_auto_moderation_rule<G: Into<Snowflake>, R: Into<Snowflake>>(
+        &self,
+        guild_id: G,
+        rule_id: R,
+    ) -> Result<AutoModerationRule, Error> {
+        let guild_id = guild_id.into().0;
+        let rule_id = rule_id.into().0;
+
+        self.send(Request::get(format!(
+            "guilds/{guild_id}/auto-moderation/rules/{rule_id}"
+        )))
+        .await
+    }
+
+    /// Create a auto moderation rule
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as too many rules of the same trigger type.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vivcord::{Api, api::Error};
+    /// # use vivcord::datatypes::*;
+    /// # tokio_test::block_on(async move {
+    /// let api = Api::new("TOKEN");
+    /// api.create_auto_moderation_rule(12345, CreateAutoModerationRuleParams {
+    ///     name: "No cursing".to_owned(),
+    ///     event_type: AutoModerationEventType::MessageSend,
+    ///     trigger_type: AutoModerationTriggerType::Keyword,
+    ///     trigger_metadata: Some(AutoModerationTriggerMetadata {
+    ///         keyword_filter: vec!["heck".to_owned()],
+    ///         ..Default::default()
+    ///     }),
+    ///     actions: vec![AutoModerationAction {
+    ///         kind: AutoModerationActionType::BlockMessage,
+    ///         metadata: None,
+    ///     }],
+    ///     enabled: Some(true),
+    ///     exempt_roles: Vec::new(),
+    ///     exempt_channels: Vec::new(),
+    /// }).await?;
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    pub async fn create_auto_moderation_rule<I: Into<Snowflake>>(
+        &self,
+        guild_id: I,
+        rule: CreateAutoModerationRuleParams,
+    ) -> Result<AutoModerationRule, Error> {
+        let guild_id = guild_id.into().0;
+
+        self.send(Request::post(format!("guilds/{guild_id}/auto-moderation/rules")).json(&rule))
+            .await
+    }
+
+    /// Modify a auto moderation rule
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn modify_auto_moderation_rule<G: Into<Snowflake>, R: Into<Snowflake>>(
+        &self,
+        guild_id: G,
+        rule_id: R,
+        rule: ModifyAutoModerationRuleParams,
+    ) -> Result<AutoModerationRule, Error> {
+        let guild_id = guild_id.into().0;
+        let rule_id = rule_id.into().0;
+
+        self.send(
+            Request::patch(format!("guilds/{guild_id}/auto-moderation/rules/{rule_id}"))
+                .json(&rule),
+        )
+        .await
+    }
+
+    /// Delete a auto moderation rule
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn delete_auto_moderation_rule<G: Into<Snowflake>, R: Into<Snowflake>>(
+        &self,
+        guild_id: G,
+        rule_id: R,
+    ) -> Result<(), Error> {
+        let guild_id = guild_id.into().0;
+        let rule_id = rule_id.into().0;
+
+        self.send(Request::delete(format!(
+            "guilds/{guild_id}/auto-moderation/rules/{rule_id}"
+        )))
+        .await
+    }
+}
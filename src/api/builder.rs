@@ -0,0 +1,222 @@
+//! Configuring how the [`Api`] connects to discord
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::header::HeaderValue;
+
+use super::hook::RequestHooks;
+use super::{Api, RequestHook, RetryPolicy};
+
+/// Default base url of discord api requests, without the version
+pub(crate) const DEFAULT_BASE_URL: &str = "https://discord.com/api";
+
+/// Default discord api version
+pub(crate) const DEFAULT_API_VERSION: u8 = 10;
+
+/// Errors that can happen while building a [`Api`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum BuildError {
+    /// Token contains characters that are not allowed in a http header
+    InvalidToken,
+    /// User agent contains characters that are not allowed in a http header
+    InvalidUserAgent,
+    /// Base url could not be parsed
+    InvalidBaseUrl(String),
+    /// The http client could not be created, for example when no tls backend was found.
+    HttpClient(reqwest::Error),
+}
+
+impl std::fmt::Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidToken => write!(f, "token is not a valid header value"),
+            Self::InvalidUserAgent => write!(f, "user agent is not a valid header value"),
+            Self::InvalidBaseUrl(url) => write!(f, "invalid base url {url:?}"),
+            Self::HttpClient(err) => write!(f, "failed to create http client: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::HttpClient(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Builder for a [`Api`] with custom settings
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use vivcord::api::ApiBuilder;
+/// let api = ApiBuilder::new("TOKEN")
+///     .base_url("http://localhost:8080/api")
+///     .timeout(Duration::from_secs(5))
+///     .build()?;
+/// # Ok::<(), vivcord::api::BuildError>(())
+/// ```
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ApiBuilder {
+    token: String,
+    base_url: String,
+    api_version: u8,
+    timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxy: Option<reqwest::Proxy>,
+    http_client: Option<reqwest::Client>,
+    retry_policy: RetryPolicy,
+    hooks: RequestHooks,
+}
+
+impl ApiBuilder {
+    /// Start building a api using a bot token
+    pub fn new(token: &str) -> Self {
+        Self {
+            token: token.to_owned(),
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            api_version: DEFAULT_API_VERSION,
+            timeout: None,
+            user_agent: None,
+            proxy: None,
+            http_client: None,
+            retry_policy: RetryPolicy::default(),
+            hooks: RequestHooks::default(),
+        }
+    }
+
+    /// Url requests are sent to, without the version.
+    /// Defaults to `https://discord.com/api`
+    ///
+    /// Useful for pointing the api at a local mock, or a http proxy that handles rate limits.
+    pub fn base_url(mut self, url: &str) -> Self {
+        url.trim_end_matches('/').clone_into(&mut self.base_url);
+        self
+    }
+
+    /// Discord api version to use, defaults to `10`
+    pub fn api_version(mut self, version: u8) -> Self {
+        self.api_version = version;
+        self
+    }
+
+    /// Max time a single request is allowed to take, no timeout by default
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// User agent to send, discord wants this to be in the format `DiscordBot ($url, $versionNumber)`
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_owned());
+        self
+    }
+
+    /// Send all requests through a proxy
+    ///
+    /// This is ignored when using [`http_client`][Self::http_client], configure the proxy on that client instead.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Use a existing http client, for example to share a connection pool with the rest of your application.
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Which failed requests to retry, see [`RetryPolicy`]
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Call `hook` around every request, see [`RequestHook`]
+    pub fn hook<H: RequestHook + 'static>(mut self, hook: H) -> Self {
+        self.hooks.push(hook);
+        self
+    }
+
+    /// Create the [`Api`]
+    ///
+    /// # Errors
+    /// If the token, user agent or base url is invalid,
+    /// or if the http client could not be created.
+    pub fn build(self) -> Result<Api, BuildError> {
+        let mut token = HeaderValue::from_str(&format!("Bot {}", self.token))
+            .map_err(|_| BuildError::InvalidToken)?;
+        token.set_sensitive(true);
+
+        let user_agent = match &self.user_agent {
+            Some(user_agent) => HeaderValue::from_str(user_agent),
+            None => HeaderValue::from_str(&format!(
+                "DiscordBot ({}, {})",
+                env!("CARGO_PKG_REPOSITORY"),
+                env!("CARGO_PKG_VERSION")
+            )),
+        }
+        .map_err(|_| BuildError::InvalidUserAgent)?;
+
+        let base_url = format!("{}/v{}/", self.base_url, self.api_version);
+        if reqwest::Url::parse(&base_url).is_err() {
+            return Err(BuildError::InvalidBaseUrl(self.base_url));
+        }
+
+        let http_client = if let Some(client) = self.http_client {
+            client
+        } else {
+            let mut builder = reqwest::Client::builder();
+            if let Some(proxy) = self.proxy {
+                builder = builder.proxy(proxy);
+            }
+            builder.build().map_err(BuildError::HttpClient)?
+        };
+
+        Ok(Api {
+            http_client,
+            base_url: base_url.into(),
+            token,
+            user_agent,
+            timeout: self.timeout,
+            ratelimiter: Arc::default(),
+            retry_policy: self.retry_policy,
+            hooks: self.hooks,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_url() {
+        let api = ApiBuilder::new("TOKEN")
+            .base_url("http://localhost:8080/api/")
+            .api_version(9)
+            .build()
+            .unwrap();
+
+        assert_eq!(&*api.base_url, "http://localhost:8080/api/v9/");
+    }
+
+    #[test]
+    fn test_invalid_token() {
+        let result = ApiBuilder::new("bad\ntoken").build();
+
+        assert!(matches!(result, Err(BuildError::InvalidToken)));
+    }
+
+    #[test]
+    fn test_invalid_base_url() {
+        let result = ApiBuilder::new("TOKEN").base_url("not a url").build();
+
+        assert!(matches!(result, Err(BuildError::InvalidBaseUrl(_))));
+    }
+}
@@ -0,0 +1,66 @@
+//! Channel endpoints
+
+use serde::Serialize;
+
+use super::{Api, Error, Request};
+use crate::datatypes::{PartialChannel, Snowflake};
+
+impl Api {
+    /// Show that the bot is typing in a channel, for about 10 seconds or until it sends a message
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord.
+    /// Or other general error with the input data, such as invalid ids.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vivcord::{Api, api::Error};
+    /// # tokio_test::block_on(async move {
+    /// let api = Api::new("TOKEN");
+    /// api.trigger_typing_indicator(12345).await?;
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    pub async fn trigger_typing_indicator<I: Into<Snowflake>>(
+        &self,
+        channel_id: I,
+    ) -> Result<(), Error> {
+        let channel_id = channel_id.into().0;
+
+        self.send(Request::post(format!("channels/{channel_id}/typing")))
+            .await
+    }
+
+    /// Get the dm channel with a user, creating it if needed
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vivcord::{Api, CreateMessageParams, api::Error};
+    /// # tokio_test::block_on(async move {
+    /// let api = Api::new("TOKEN");
+    /// let channel = api.create_dm(12345).await?;
+    /// api.create_message(channel.id, CreateMessageParams::from("hello")).await?;
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    pub async fn create_dm<I: Into<Snowflake>>(
+        &self,
+        recipient_id: I,
+    ) -> Result<PartialChannel, Error> {
+        #[derive(Serialize)]
+        struct CreateDmParams {
+            recipient_id: Snowflake,
+        }
+
+        self.send(
+            Request::post("users/@me/channels".to_owned()).json(&CreateDmParams {
+                recipient_id: recipient_id.into(),
+            }),
+        )
+        .await
+    }
+}
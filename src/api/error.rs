@@ -0,0 +1,423 @@
+//! Errors returned by the [`Api`][crate::Api]
+
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+int_enum! {
+    /// Well known discord [error codes](https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes)
+    pub enum DiscordErrorCode: u32 {
+        /// General error, see [`DiscordErrorData::message`]
+        General = 0,
+        /// Unknown account
+        UnknownAccount = 10001,
+        /// Unknown application
+        UnknownApplication = 10002,
+        /// Unknown channel
+        UnknownChannel = 10003,
+        /// Unknown guild
+        UnknownGuild = 10004,
+        /// Unknown integration
+        UnknownIntegration = 10005,
+        /// Unknown invite
+        UnknownInvite = 10006,
+        /// Unknown member
+        UnknownMember = 10007,
+        /// Unknown message
+        UnknownMessage = 10008,
+        /// Unknown permission overwrite
+        UnknownPermissionOverwrite = 10009,
+        /// Unknown role
+        UnknownRole = 10011,
+        /// Unknown user
+        UnknownUser = 10013,
+        /// Unknown emoji
+        UnknownEmoji = 10014,
+        /// Unknown webhook
+        UnknownWebhook = 10015,
+        /// Unknown ban
+        UnknownBan = 10026,
+        /// Unknown interaction
+        UnknownInteraction = 10062,
+        /// Unknown application command
+        UnknownApplicationCommand = 10063,
+        /// Unknown stage instance
+        UnknownStageInstance = 10067,
+        /// Unknown guild scheduled event
+        UnknownGuildScheduledEvent = 10070,
+        /// Unknown guild scheduled event user
+        UnknownGuildScheduledEventUser = 10071,
+        /// Bots can not use this endpoint
+        BotsCannotUseEndpoint = 20001,
+        /// Only bots can use this endpoint
+        OnlyBotsCanUseEndpoint = 20002,
+        /// The channel is being slowmoded
+        SlowmodeRateLimit = 20016,
+        /// Maximum number of guilds reached
+        MaxGuildsReached = 30001,
+        /// Maximum number of pins reached
+        MaxPinsReached = 30003,
+        /// Maximum number of reactions reached
+        MaxReactionsReached = 30010,
+        /// Unauthorized, provide a valid token
+        Unauthorized = 40001,
+        /// Request entity too large
+        RequestTooLarge = 40005,
+        /// Interaction has already been acknowledged
+        InteractionAlreadyAcknowledged = 40060,
+        /// Missing access
+        MissingAccess = 50001,
+        /// Invalid account type
+        InvalidAccountType = 50002,
+        /// Cannot execute action on a DM channel
+        CannotExecuteOnDmChannel = 50003,
+        /// Cannot edit a message authored by another user
+        CannotEditOtherUsersMessage = 50005,
+        /// Cannot send an empty message
+        CannotSendEmptyMessage = 50006,
+        /// Cannot send messages to this user
+        CannotSendMessagesToUser = 50007,
+        /// Missing permissions
+        MissingPermissions = 50013,
+        /// Invalid authentication token provided
+        InvalidToken = 50014,
+        /// Note was too long
+        NoteTooLong = 50015,
+        /// Provided too few or too many messages to delete
+        InvalidBulkDeleteCount = 50016,
+        /// A message can only be pinned to the channel it was sent in
+        CannotPinInDifferentChannel = 50019,
+        /// Invite code was either invalid or taken
+        InvalidInviteCode = 50020,
+        /// Cannot execute action on a system message
+        CannotExecuteOnSystemMessage = 50021,
+        /// A message provided was too old to bulk delete
+        MessageTooOldToBulkDelete = 50034,
+        /// Invalid form body
+        InvalidFormBody = 50035,
+        /// Invalid api version provided
+        InvalidApiVersion = 50041,
+        /// Reaction was blocked
+        ReactionBlocked = 90001,
+        /// Message was blocked by automatic moderation
+        AutoModerationBlocked = 200_000,
+    }
+}
+
+/// Error from the discord api
+#[derive(Deserialize, Clone, Debug)]
+pub struct DiscordErrorData {
+    /// Http status of the response
+    #[serde(skip)]
+    pub status: StatusCode,
+    /// Json Structure describing exactly what was wrong
+    pub errors: Option<serde_json::Value>,
+    /// Generic message of what was wrong, usually not needed
+    pub message: String,
+    /// Discord [error code](https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes)
+    pub code: DiscordErrorCode,
+}
+
+impl DiscordErrorData {
+    /// Flatten the nested [`errors`][Self::errors] object into `(field path, message)` pairs.
+    ///
+    /// # Example
+    /// ```
+    /// # use vivcord::api::DiscordErrorData;
+    /// let error: DiscordErrorData = serde_json::from_str(r#"{
+    ///     "code": 50035,
+    ///     "message": "Invalid Form Body",
+    ///     "errors": {"embeds": {"0": {"title": {"_errors": [
+    ///         {"code": "BASE_TYPE_MAX_LENGTH", "message": "Must be 256 or fewer in length."}
+    ///     ]}}}}
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(error.field_errors(), vec![
+    ///     ("embeds.0.title".to_owned(), "Must be 256 or fewer in length.".to_owned())
+    /// ]);
+    /// ```
+    #[must_use]
+    pub fn field_errors(&self) -> Vec<(String, String)> {
+        let mut result = Vec::new();
+        if let Some(errors) = &self.errors {
+            flatten_errors(errors, "", &mut result);
+        }
+        result
+    }
+}
+
+/// Walk the error object, collecting every `_errors` list with the path leading to it
+fn flatten_errors(value: &serde_json::Value, path: &str, result: &mut Vec<(String, String)>) {
+    let serde_json::Value::Object(fields) = value else {
+        return;
+    };
+
+    for (key, value) in fields {
+        if key == "_errors" {
+            for error in value.as_array().into_iter().flatten() {
+                if let Some(message) = error.get("message").and_then(serde_json::Value::as_str) {
+                    result.push((path.to_owned(), message.to_owned()));
+                }
+            }
+        } else if path.is_empty() {
+            flatten_errors(value, key, result);
+        } else {
+            flatten_errors(value, &format!("{path}.{key}"), result);
+        }
+    }
+}
+
+impl std::fmt::Display for DiscordErrorData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (code {}, status {})",
+            self.message,
+            u32::from(self.code),
+            self.status
+        )?;
+        for (path, message) in self.field_errors() {
+            write!(f, "\n  {path}: {message}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Holds possible errors from the api
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// Generic http error, such as failing to connect
+    ReqwestErr(reqwest::Error),
+    /// Error from discord api
+    DiscordErr(DiscordErrorData),
+    /// Non successful response that is not a discord error
+    HttpErr {
+        /// Http status of the response
+        status: StatusCode,
+        /// Raw response body
+        body: String,
+    },
+    /// Discord had a internal error (`5xx` status)
+    ServerErr {
+        /// Http status of the response
+        status: StatusCode,
+        /// Raw response body
+        body: String,
+    },
+    /// Still rate limited after retrying
+    RateLimited {
+        /// How long until the request can be tried again
+        retry_after: Duration,
+        /// Was this the global rate limit
+        global: bool,
+    },
+    /// Failed to read a file being uploaded
+    IoErr(std::io::Error),
+    /// Input was rejected before sending the request, because discord would reject it anyway
+    ValidationErr(String),
+    /// A [`RequestHook`][crate::api::RequestHook] stopped the request from being sent
+    Cancelled(String),
+    /// Response could not be parsed into the expected type
+    JsonErr {
+        /// The parse error
+        error: serde_json::Error,
+        /// Raw response body
+        body: String,
+    },
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Self::ReqwestErr(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Self::IoErr(err)
+    }
+}
+
+impl Error {
+    /// Http status of the response that caused this error, if there was one
+    #[must_use]
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::ReqwestErr(err) => err.status(),
+            Self::DiscordErr(data) => Some(data.status),
+            Self::HttpErr { status, .. } | Self::ServerErr { status, .. } => Some(*status),
+            Self::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            Self::IoErr(_) | Self::ValidationErr(_) | Self::Cancelled(_) | Self::JsonErr { .. } => {
+                None
+            }
+        }
+    }
+
+    /// Discord error code, if this is a [`Error::DiscordErr`]
+    #[must_use]
+    pub fn discord_code(&self) -> Option<DiscordErrorCode> {
+        match self {
+            Self::DiscordErr(data) => Some(data.code),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ReqwestErr(err) => write!(f, "http error: {err}"),
+            Self::DiscordErr(data) => write!(f, "discord error: {data}"),
+            Self::HttpErr { status, body } => write!(f, "unexpected response {status}: {body}"),
+            Self::ServerErr { status, body } => write!(f, "discord server error {status}: {body}"),
+            Self::RateLimited {
+                retry_after,
+                global,
+            } => {
+                let scope = if *global { "globally " } else { "" };
+                write!(f, "{scope}rate limited, retry after {retry_after:?}")
+            }
+            Self::IoErr(err) => write!(f, "failed to read file: {err}"),
+            Self::ValidationErr(reason) => write!(f, "invalid input: {reason}"),
+            Self::Cancelled(reason) => write!(f, "request cancelled: {reason}"),
+            Self::JsonErr { error, body } => write!(f, "invalid json response ({error}): {body}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ReqwestErr(err) => Some(err),
+            Self::IoErr(err) => Some(err),
+            Self::JsonErr { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Parse a response body that might be `T` or might be a error
+///
+/// Empty bodies (such as `204 No Content`) are parsed as `null`, so they can be read into `()`.
+pub(crate) fn parse_response<T: serde::de::DeserializeOwned>(
+    status: StatusCode,
+    body: &[u8],
+) -> Result<T, Error> {
+    let text = || String::from_utf8_lossy(body).into_owned();
+
+    if status.is_server_error() {
+        return Err(Error::ServerErr {
+            status,
+            body: text(),
+        });
+    }
+
+    if !status.is_success() {
+        return match serde_json::from_slice::<DiscordErrorData>(body) {
+            Ok(mut data) => {
+                data.status = status;
+                Err(Error::DiscordErr(data))
+            }
+            Err(_) => Err(Error::HttpErr {
+                status,
+                body: text(),
+            }),
+        };
+    }
+
+    let result = if body.is_empty() {
+        serde_json::from_value(serde_json::Value::Null)
+    } else {
+        serde_json::from_slice(body)
+    };
+    result.map_err(|error| Error::JsonErr {
+        error,
+        body: text(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_discord_error() {
+        let result: Result<(), Error> = parse_response(
+            StatusCode::FORBIDDEN,
+            br#"{"message": "Missing Permissions", "code": 50013}"#,
+        );
+
+        let Err(Error::DiscordErr(data)) = result else {
+            panic!("Expected DiscordErr got {result:?}");
+        };
+        assert_eq!(data.code, DiscordErrorCode::MissingPermissions);
+        assert_eq!(data.status, StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn test_parse_unknown_code() {
+        let result: Result<(), Error> = parse_response(
+            StatusCode::BAD_REQUEST,
+            br#"{"message": "Something new", "code": 12345}"#,
+        );
+
+        assert_eq!(
+            result.unwrap_err().discord_code(),
+            Some(DiscordErrorCode::Unknown(12345))
+        );
+    }
+
+    #[test]
+    fn test_parse_non_discord_error() {
+        let result: Result<(), Error> = parse_response(StatusCode::NOT_FOUND, b"<html></html>");
+
+        assert!(matches!(result, Err(Error::HttpErr { status: StatusCode::NOT_FOUND, .. })));
+    }
+
+    #[test]
+    fn test_parse_server_error() {
+        let result: Result<(), Error> = parse_response(StatusCode::BAD_GATEWAY, b"bad gateway");
+
+        assert!(matches!(result, Err(Error::ServerErr { status: StatusCode::BAD_GATEWAY, .. })));
+    }
+
+    #[test]
+    fn test_parse_invalid_json() {
+        let result: Result<u32, Error> = parse_response(StatusCode::OK, br#"{"id": "1"}"#);
+
+        let Err(Error::JsonErr { body, .. }) = result else {
+            panic!("Expected JsonErr got {result:?}");
+        };
+        assert_eq!(body, r#"{"id": "1"}"#);
+    }
+
+    #[test]
+    fn test_parse_empty_body() {
+        let result: Result<(), Error> = parse_response(StatusCode::NO_CONTENT, b"");
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_flatten_errors() {
+        let data: DiscordErrorData = serde_json::from_value(serde_json::json!({
+            "code": 50035,
+            "message": "Invalid Form Body",
+            "errors": {
+                "content": {"_errors": [{"code": "A", "message": "too long"}]},
+                "embeds": {"1": {"fields": {"0": {"name": {"_errors": [{"code": "B", "message": "required"}]}}}}}
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            data.field_errors(),
+            vec![
+                ("content".to_owned(), "too long".to_owned()),
+                ("embeds.1.fields.0.name".to_owned(), "required".to_owned()),
+            ]
+        );
+    }
+}
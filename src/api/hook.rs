@@ -0,0 +1,114 @@
+//! Observing and blocking requests made by the [`Api`][crate::Api]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+
+use super::Error;
+
+/// The request a [`RequestHook`] is called for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestInfo {
+    /// Http method
+    pub method: Method,
+    /// Path relative to the api base url, such as `channels/1234/messages`
+    pub path: String,
+}
+
+/// Called around every request the [`Api`][crate::Api] sends, for example for logging or metrics.
+///
+/// Both methods do nothing by default.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use vivcord::api::{ApiBuilder, Error, RequestHook, RequestInfo};
+/// # use reqwest::StatusCode;
+/// struct Logger;
+///
+/// impl RequestHook for Logger {
+///     fn after(&self, request: &RequestInfo, result: Result<StatusCode, &Error>, elapsed: Duration) {
+///         println!("{} {} {result:?} in {elapsed:?}", request.method, request.path);
+///     }
+/// }
+///
+/// let api = ApiBuilder::new("TOKEN").hook(Logger).build()?;
+/// # Ok::<(), vivcord::api::BuildError>(())
+/// ```
+#[allow(unused_variables)]
+pub trait RequestHook: Send + Sync {
+    /// Called before the request is sent, returning a error stops the request
+    /// and it is returned to the caller, usually a [`Error::Cancelled`].
+    ///
+    /// # Errors
+    /// When the request should not be sent.
+    fn before(&self, request: &RequestInfo) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Called when the request is done, including retries,
+    /// with the status of the response or the error that stopped it from getting one.
+    fn after(&self, request: &RequestInfo, result: Result<StatusCode, &Error>, elapsed: Duration) {}
+}
+
+/// Hooks of a api, in the order they were added
+#[derive(Clone, Default)]
+pub(crate) struct RequestHooks(Arc<Vec<Arc<dyn RequestHook>>>);
+
+impl RequestHooks {
+    /// Add a hook after the existing ones
+    pub fn push<H: RequestHook + 'static>(&mut self, hook: H) {
+        Arc::make_mut(&mut self.0).push(Arc::new(hook));
+    }
+
+    /// Call [`RequestHook::before`] of each hook, stopping at the first error
+    pub fn before(&self, request: &RequestInfo) -> Result<(), Error> {
+        self.0.iter().try_for_each(|hook| hook.before(request))
+    }
+
+    /// Call [`RequestHook::after`] of each hook
+    pub fn after(
+        &self,
+        request: &RequestInfo,
+        result: Result<StatusCode, &Error>,
+        elapsed: Duration,
+    ) {
+        for hook in self.0.iter() {
+            hook.after(request, result, elapsed);
+        }
+    }
+}
+
+impl std::fmt::Debug for RequestHooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RequestHooks({})", self.0.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::ApiBuilder;
+
+    /// Blocks every request
+    struct Block;
+
+    impl RequestHook for Block {
+        fn before(&self, request: &RequestInfo) -> Result<(), Error> {
+            Err(Error::Cancelled(format!("{} blocked", request.path)))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_before_cancels() {
+        let api = ApiBuilder::new("TOKEN")
+            .base_url("http://127.0.0.1:1")
+            .hook(Block)
+            .build()
+            .unwrap();
+
+        let result = api.get_gateway_url().await;
+        assert!(matches!(result, Err(Error::Cancelled(reason)) if reason == "gateway blocked"));
+    }
+}
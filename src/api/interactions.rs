@@ -0,0 +1,206 @@
+//! Interaction response and followup message endpoints
+//!
+//! These endpoints are authenticated by the interaction token, and do not count towards the global rate limit.
+
+use super::{Api, Error, Request};
+use crate::datatypes::{
+    EditMessageParams, InteractionMessage, InteractionResponse, Message, Snowflake,
+};
+
+impl Api {
+    /// Respond to a interaction, this must be done within 3 seconds of receiving it.
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, failing to read a uploaded file.
+    /// Or other general error with the input data, such as the interaction already being responded to.
+    /// [`Error::ValidationErr`] if the response exceeds discords limits, see [`InteractionResponse::validate`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vivcord::{Api, api::Error};
+    /// # use vivcord::datatypes::{Interaction, InteractionMessage, InteractionResponse};
+    /// # async fn example(api: Api, interaction: Interaction) -> Result<(), Error> {
+    /// api.create_interaction_response(
+    ///     interaction.id,
+    ///     &interaction.token,
+    ///     InteractionResponse::ChannelMessageWithSource(InteractionMessage::content("Pong!")),
+    /// )
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_interaction_response<I: Into<Snowflake>>(
+        &self,
+        interaction_id: I,
+        interaction_token: &str,
+        mut response: InteractionResponse,
+    ) -> Result<(), Error> {
+        let interaction_id = interaction_id.into().0;
+        response.validate()?;
+        let is_edit = matches!(response, InteractionResponse::UpdateMessage(_));
+        let files = response.take_files();
+
+        let request = Request::post(format!(
+            "interactions/{interaction_id}/{interaction_token}/callback"
+        ))
+        .json(&response);
+        let request = if is_edit {
+            request.edit_files(files)
+        } else {
+            request.files(files)
+        };
+        self.send(request).await
+    }
+
+    /// Get the message sent as the response to a interaction
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as a expired interaction token.
+    pub async fn get_original_interaction_response<A: Into<Snowflake>>(
+        &self,
+        application_id: A,
+        interaction_token: &str,
+    ) -> Result<Message, Error> {
+        let application_id = application_id.into().0;
+
+        self.send(Request::get(format!(
+            "webhooks/{application_id}/{interaction_token}/messages/@original"
+        )))
+        .await
+    }
+
+    /// Edit the message sent as the response to a interaction,
+    /// this is also how deferred responses are completed.
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response, failing to read a uploaded file.
+    /// Or other general error with the input data, such as a expired interaction token.
+    /// [`Error::ValidationErr`] if the new values exceed discords limits, see [`EditMessageParams::validate`].
+    pub async fn edit_original_interaction_response<A: Into<Snowflake>>(
+        &self,
+        application_id: A,
+        interaction_token: &str,
+        msg: EditMessageParams,
+    ) -> Result<Message, Error> {
+        let application_id = application_id.into().0;
+        msg.validate()?;
+
+        self.send(
+            Request::patch(format!(
+                "webhooks/{application_id}/{interaction_token}/messages/@original"
+            ))
+            .json(&msg)
+            .edit_files(msg.files),
+        )
+        .await
+    }
+
+    /// Delete the message sent as the response to a interaction
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord.
+    /// Or other general error with the input data, such as a expired interaction token.
+    pub async fn delete_original_interaction_response<A: Into<Snowflake>>(
+        &self,
+        application_id: A,
+        interaction_token: &str,
+    ) -> Result<(), Error> {
+        let application_id = application_id.into().0;
+
+        self.send(Request::delete(format!(
+            "webhooks/{application_id}/{interaction_token}/messages/@original"
+        )))
+        .await
+    }
+
+    /// Send a extra message after responding to a interaction
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response, failing to read a uploaded file.
+    /// Or other general error with the input data, such as a expired interaction token.
+    /// [`Error::ValidationErr`] if the message exceeds discords limits, see [`InteractionMessage::validate`].
+    pub async fn create_followup_message<A: Into<Snowflake>>(
+        &self,
+        application_id: A,
+        interaction_token: &str,
+        msg: InteractionMessage,
+    ) -> Result<Message, Error> {
+        let application_id = application_id.into().0;
+        msg.validate()?;
+
+        self.send(
+            Request::post(format!("webhooks/{application_id}/{interaction_token}"))
+                .json(&msg)
+                .files(msg.files),
+        )
+        .await
+    }
+
+    /// Get a followup message
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn get_followup_message<A: Into<Snowflake>, M: Into<Snowflake>>(
+        &self,
+        application_id: A,
+        interaction_token: &str,
+        message_id: M,
+    ) -> Result<Message, Error> {
+        let application_id = application_id.into().0;
+        let message_id = message_id.into().0;
+
+        self.send(Request::get(format!(
+            "webhooks/{application_id}/{interaction_token}/messages/{message_id}"
+        )))
+        .await
+    }
+
+    /// Edit a followup message
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response, failing to read a uploaded file.
+    /// Or other general error with the input data, such as invalid ids.
+    /// [`Error::ValidationErr`] if the new values exceed discords limits, see [`EditMessageParams::validate`].
+    pub async fn edit_followup_message<A: Into<Snowflake>, M: Into<Snowflake>>(
+        &self,
+        application_id: A,
+        interaction_token: &str,
+        message_id: M,
+        msg: EditMessageParams,
+    ) -> Result<Message, Error> {
+        let application_id = application_id.into().0;
+        let message_id = message_id.into().0;
+        msg.validate()?;
+
+        self.send(
+            Request::patch(format!(
+                "webhooks/{application_id}/{interaction_token}/messages/{message_id}"
+            ))
+            .json(&msg)
+            .edit_files(msg.files),
+        )
+        .await
+    }
+
+    /// Delete a followup message
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn delete_followup_message<A: Into<Snowflake>, M: Into<Snowflake>>(
+        &self,
+        application_id: A,
+        interaction_token: &str,
+        message_id: M,
+    ) -> Result<(), Error> {
+        let application_id = application_id.into().0;
+        let message_id = message_id.into().0;
+
+        self.send(Request::delete(format!(
+            "webhooks/{application_id}/{interaction_token}/messages/{message_id}"
+        )))
+        .await
+    }
+}
@@ -0,0 +1,279 @@
+//! Message endpoints
+
+use std::time::{Duration, SystemTime};
+
+use serde::Serialize;
+
+use super::{Api, Error, Request};
+use crate::datatypes::{EditMessageParams, GetChannelMessagesParams, Message, Snowflake};
+use crate::CreateMessageParams;
+
+/// Messages older than this can not be bulk deleted
+const BULK_DELETE_MAX_AGE: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+
+/// Check that `messages` can be passed to [`Api::bulk_delete_messages`]
+fn validate_bulk_delete(messages: &[Snowflake], now: SystemTime) -> Result<(), Error> {
+    if !(2..=100).contains(&messages.len()) {
+        return Err(Error::ValidationErr(format!(
+            "bulk delete needs 2 to 100 messages, got {}",
+            messages.len()
+        )));
+    }
+
+    let oldest_allowed = now - BULK_DELETE_MAX_AGE;
+    if let Some(old) = messages.iter().find(|id| id.created_at() < oldest_allowed) {
+        return Err(Error::ValidationErr(format!(
+            "message {old} is older than 14 days and can not be bulk deleted"
+        )));
+    }
+
+    Ok(())
+}
+
+impl Api {
+    /// Send message to specific channel
+    /// 
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response. 
+    /// Or other general error with the input data, such as invalid ids.
+    /// [`Error::ValidationErr`] if the message exceeds discords limits, see [`CreateMessageParams::validate`].
+    /// 
+    /// # Example
+    /// ```no_run
+    /// # use vivcord::{Api, CreateMessageParams, api::Error};
+    /// # tokio_test::block_on(async move {
+    /// let api = Api::new("TOKEN");
+    /// api.create_message(12345, CreateMessageParams {
+    ///     content: Some("hello".to_owned()),
+    ///     ..Default::default()
+    /// }).await?;
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    pub async fn create_message<I: Into<Snowflake>>(
+        &self,
+        channel_id: I,
+        msg: CreateMessageParams,
+    ) -> Result<Message, Error> {
+        let id: u64 = channel_id.into().0;
+        msg.validate()?;
+
+        self.send(
+            Request::post(format!("channels/{id}/messages"))
+                .json(&msg)
+                .files(msg.files),
+        )
+        .await
+    }
+
+    /// Get messages in a channel, newest first
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vivcord::{Api, api::Error, datatypes::GetChannelMessagesParams};
+    /// # tokio_test::block_on(async move {
+    /// let api = Api::new("TOKEN");
+    /// let messages = api.get_channel_messages(12345, GetChannelMessagesParams {
+    ///     limit: Some(10),
+    ///     ..Default::default()
+    /// }).await?;
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    pub async fn get_channel_messages<I: Into<Snowflake>>(
+        &self,
+        channel_id: I,
+        params: GetChannelMessagesParams,
+    ) -> Result<Vec<Message>, Error> {
+        let channel_id = channel_id.into().0;
+
+        self.send(Request::get(format!("channels/{channel_id}/messages")).query(&params))
+            .await
+    }
+
+    /// Get a single message
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn get_channel_message<C: Into<Snowflake>, M: Into<Snowflake>>(
+        &self,
+        channel_id: C,
+        message_id: M,
+    ) -> Result<Message, Error> {
+        let channel_id = channel_id.into().0;
+        let message_id = message_id.into().0;
+
+        self.send(Request::get(format!(
+            "channels/{channel_id}/messages/{message_id}"
+        )))
+        .await
+    }
+
+    /// Edit a message sent by the bot
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as editing a message sent by someone else.
+    /// [`Error::ValidationErr`] if the new values exceed discords limits, see [`EditMessageParams::validate`].
+    pub async fn edit_message<C: Into<Snowflake>, M: Into<Snowflake>>(
+        &self,
+        channel_id: C,
+        message_id: M,
+        msg: EditMessageParams,
+    ) -> Result<Message, Error> {
+        let channel_id = channel_id.into().0;
+        let message_id = message_id.into().0;
+        msg.validate()?;
+
+        self.send(
+            Request::patch(format!("channels/{channel_id}/messages/{message_id}"))
+                .json(&msg)
+                .edit_files(msg.files),
+        )
+        .await
+    }
+
+    /// Delete a message
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord.
+    /// Or other general error with the input data, such as missing `MANAGE_MESSAGES` permission.
+    pub async fn delete_message<C: Into<Snowflake>, M: Into<Snowflake>>(
+        &self,
+        channel_id: C,
+        message_id: M,
+    ) -> Result<(), Error> {
+        let channel_id = channel_id.into().0;
+        let message_id = message_id.into().0;
+
+        self.send(Request::delete(format!(
+            "channels/{channel_id}/messages/{message_id}"
+        )))
+        .await
+    }
+
+    /// Delete 2 to 100 messages at once
+    ///
+    /// # Errors
+    /// [`Error::ValidationErr`] if there are not 2 to 100 messages, or if any message is older than 14 days.
+    /// Otherwise same as [`Api::delete_message`].
+    pub async fn bulk_delete_messages<I: Into<Snowflake>>(
+        &self,
+        channel_id: I,
+        messages: Vec<Snowflake>,
+    ) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct BulkDelete {
+            messages: Vec<Snowflake>,
+        }
+
+        let channel_id = channel_id.into().0;
+        validate_bulk_delete(&messages, SystemTime::now())?;
+
+        self.send(
+            Request::post(format!("channels/{channel_id}/messages/bulk-delete"))
+                .json(&BulkDelete { messages }),
+        )
+        .await
+    }
+
+    /// Publish a message in a announcement channel to all following channels
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as the channel not being a announcement channel.
+    pub async fn crosspost_message<C: Into<Snowflake>, M: Into<Snowflake>>(
+        &self,
+        channel_id: C,
+        message_id: M,
+    ) -> Result<Message, Error> {
+        let channel_id = channel_id.into().0;
+        let message_id = message_id.into().0;
+
+        self.send(Request::post(format!(
+            "channels/{channel_id}/messages/{message_id}/crosspost"
+        )))
+        .await
+    }
+
+    /// Get all pinned messages in a channel
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn get_pinned_messages<I: Into<Snowflake>>(
+        &self,
+        channel_id: I,
+    ) -> Result<Vec<Message>, Error> {
+        let channel_id = channel_id.into().0;
+
+        self.send(Request::get(format!("channels/{channel_id}/pins")))
+            .await
+    }
+
+    /// Pin a message, a channel can have at most 50 pins
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord.
+    /// Or other general error with the input data, such as too many pins.
+    pub async fn pin_message<C: Into<Snowflake>, M: Into<Snowflake>>(
+        &self,
+        channel_id: C,
+        message_id: M,
+    ) -> Result<(), Error> {
+        let channel_id = channel_id.into().0;
+        let message_id = message_id.into().0;
+
+        self.send(Request::put(format!("channels/{channel_id}/pins/{message_id}")))
+            .await
+    }
+
+    /// Unpin a message
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn unpin_message<C: Into<Snowflake>, M: Into<Snowflake>>(
+        &self,
+        channel_id: C,
+        message_id: M,
+    ) -> Result<(), Error> {
+        let channel_id = channel_id.into().0;
+        let message_id = message_id.into().0;
+
+        self.send(Request::delete(format!("channels/{channel_id}/pins/{message_id}")))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bulk_delete_count() {
+        let now = SystemTime::now();
+        let id = Snowflake::from_time(now);
+
+        assert!(validate_bulk_delete(&[id], now).is_err());
+        assert!(validate_bulk_delete(&[id, id], now).is_ok());
+        assert!(validate_bulk_delete(&vec![id; 101], now).is_err());
+    }
+
+    #[test]
+    fn test_bulk_delete_age() {
+        let now = SystemTime::now();
+        let old = Snowflake::from_time(now - Duration::from_secs(15 * 24 * 60 * 60));
+        let new = Snowflake::from_time(now);
+
+        assert!(matches!(
+            validate_bulk_delete(&[new, old], now),
+            Err(Error::ValidationErr(_))
+        ));
+    }
+}
@@ -0,0 +1,252 @@
+//! Code for interacting with the discord REST api
+
+mod application_commands;
+mod applications;
+mod auto_moderation;
+mod builder;
+mod channels;
+mod error;
+mod hook;
+mod interactions;
+mod messages;
+mod ratelimit;
+mod reactions;
+mod request;
+mod retry;
+mod scheduled_events;
+mod webhooks;
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use reqwest::header::HeaderValue;
+use reqwest::StatusCode;
+
+use serde::{de::DeserializeOwned, Deserialize};
+
+pub use builder::{ApiBuilder, BuildError};
+pub use error::{DiscordErrorCode, DiscordErrorData, Error};
+use error::parse_response;
+pub use hook::{RequestHook, RequestInfo};
+use hook::RequestHooks;
+use ratelimit::{RateLimitHeaders, RateLimitedResponse, RateLimiter, Route};
+pub(crate) use request::Request;
+pub use retry::RetryPolicy;
+
+/// How many times a request is retried when rate limited, before giving up
+const MAX_RATE_LIMIT_RETRIES: u32 = 5;
+
+/// Api client making requests to discord.
+///
+/// Requests are delayed to respect discords [rate limits](https://discord.com/developers/docs/topics/rate-limits),
+/// and are automatically retried if discord still responds with `429 Too Many Requests`.
+/// Other temporary failures are retried according to the [`RetryPolicy`].
+///
+/// Cloning is cheap, clones share the same connection pool and rate limits.
+#[derive(Debug, Clone)]
+pub struct Api {
+    /// Internal http client used to make requests
+    http_client: reqwest::Client,
+    /// Url requests are sent to, including the version and a trailing `/`
+    base_url: Arc<str>,
+    /// `Authorization` header
+    token: HeaderValue,
+    /// `User-Agent` header
+    user_agent: HeaderValue,
+    /// Max time a single request is allowed to take
+    timeout: Option<Duration>,
+    /// Keeps track of rate limit buckets
+    ratelimiter: Arc<RateLimiter>,
+    /// Which failed requests to retry
+    retry_policy: RetryPolicy,
+    /// Called around every request
+    hooks: RequestHooks,
+}
+
+impl Api {
+    /// Create new api client instance with the specified oauth token
+    /// Takes a discord api oauth token.
+    ///
+    /// Even if not *all* endpoint technically require a oauth token, 99% does, so we require it to create our instance.
+    ///
+    /// Use [`Api::builder`] to change settings, or to handle a invalid token without panicking.
+    ///
+    /// # Panics
+    /// If the token is not a valid header value, or if a tls background can not be found
+    #[must_use]
+    pub fn new(token: &str) -> Self {
+        Self::builder(token).build().expect("Invalid Token")
+    }
+
+    /// Create a [`ApiBuilder`] to configure the api
+    pub fn builder(token: &str) -> ApiBuilder {
+        ApiBuilder::new(token)
+    }
+
+    /// Use `policy` to decide which failed requests are retried
+    #[must_use]
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Call `hook` around every request, after the existing hooks.
+    ///
+    /// Existing clones of the api are not affected.
+    #[must_use]
+    pub fn with_hook<H: RequestHook + 'static>(mut self, hook: H) -> Self {
+        self.hooks.push(hook);
+        self
+    }
+
+    /// Get a api that never retries failed requests, sharing rate limits with this one.
+    ///
+    /// Use this to opt out of retries for a single request.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vivcord::{Api, api::Error};
+    /// # tokio_test::block_on(async move {
+    /// let api = Api::new("TOKEN");
+    /// api.without_retries().delete_guild_scheduled_event(12345, 67890).await?;
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn without_retries(&self) -> Self {
+        self.clone().with_retry_policy(RetryPolicy::disabled())
+    }
+
+    /// Get the connection url for the discord gateway
+    /// At the time of writing this url is most likely `wss://gateway.discord.gg/`, but this might change.
+    ///
+    /// # Errors
+    /// Can be due to a general connection error like not authorized,
+    /// Can also be caused by invalid response from the discord api.
+    /// 
+    /// # Example
+    /// ```no_run
+    /// # use vivcord::Api;
+    /// # tokio_test::block_on(async {
+    /// let client = Api::new("");
+    /// let url = client.get_gateway_url().await?;
+    /// # Ok::<(), vivcord::api::Error>(())
+    /// # });
+    /// ```
+    pub async fn get_gateway_url(&self) -> Result<String, Error> {
+        #[derive(Deserialize)]
+        struct GatewayResponse {
+            url: String,
+        }
+
+        let result: GatewayResponse = self.send(Request::get("gateway".to_owned())).await?;
+
+        Ok(result.url)
+    }
+
+    /// Send a request to discord and parse the response.
+    ///
+    /// Retries the request according to the [`RetryPolicy`], and calls the [`RequestHook`]s around it.
+    pub(crate) async fn send<T: DeserializeOwned>(&self, request: Request) -> Result<T, Error> {
+        let info = RequestInfo {
+            method: request.method.clone(),
+            path: request.path.clone(),
+        };
+        self.hooks.before(&info)?;
+
+        let start = Instant::now();
+        let result = self.send_with_retries(&request).await;
+        self.hooks.after(
+            &info,
+            result.as_ref().map(|(status, _)| *status),
+            start.elapsed(),
+        );
+
+        let (status, body) = result?;
+        parse_response(status, &body)
+    }
+
+    /// Send the request until it succeeds or should not be retried, returning the raw response.
+    async fn send_with_retries(&self, request: &Request) -> Result<(StatusCode, Vec<u8>), Error> {
+        let route = Route::new(&request.method, &request.path);
+        let policy = &self.retry_policy;
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let result = self.send_once(&route, request).await;
+
+            let retry = match &result {
+                Ok((status, _)) => policy.retry_status(&request.method, *status),
+                Err(Error::ReqwestErr(err)) => policy.retry_error(&request.method, err),
+                Err(_) => false,
+            };
+            if retry && attempt < policy.max_attempts && request.is_reusable() {
+                tokio::time::sleep(policy.delay(attempt)).await;
+                continue;
+            }
+
+            return result;
+        }
+    }
+
+    /// Send a single attempt of a request, returning the raw response.
+    ///
+    /// Waits for the rate limit bucket of the route, and sends the request again when being rate limited
+    /// if it can be [reused][Request::is_reusable].
+    async fn send_once(
+        &self,
+        route: &Route,
+        request: &Request,
+    ) -> Result<(StatusCode, Vec<u8>), Error> {
+        let mut rate_limit_retries = 0;
+        loop {
+            let ticket = self.ratelimiter.acquire(route).await;
+
+            let mut builder = self
+                .http_client
+                .request(request.method.clone(), format!("{}{}", self.base_url, request.path))
+                .header(reqwest::header::AUTHORIZATION, self.token.clone())
+                .header(reqwest::header::USER_AGENT, self.user_agent.clone())
+                .query(&request.query);
+            if let Some(timeout) = self.timeout {
+                builder = builder.timeout(timeout);
+            }
+            if !request.files.is_empty() {
+                builder = builder.multipart(request.multipart_form().await?);
+            } else if let Some(body) = &request.body {
+                builder = builder.json(body);
+            }
+            let response = builder.send().await?;
+
+            let headers = RateLimitHeaders::from(response.headers());
+            self.ratelimiter.update(route, &headers, ticket).await;
+
+            let status = response.status();
+            let body = response.bytes().await?.to_vec();
+            if status != StatusCode::TOO_MANY_REQUESTS {
+                return Ok((status, body));
+            }
+
+            let Ok(limited) = serde_json::from_slice::<RateLimitedResponse>(&body) else {
+                return Ok((status, body));
+            };
+            let retry_after = Duration::try_from_secs_f64(limited.retry_after).unwrap_or_default();
+            let global = limited.global || headers.global;
+
+            // files read from a stream can not be sent again
+            if rate_limit_retries >= MAX_RATE_LIMIT_RETRIES || !request.is_reusable() {
+                return Err(Error::RateLimited {
+                    retry_after,
+                    global,
+                });
+            }
+            rate_limit_retries += 1;
+
+            // shared limits are not caused by us, but we still need to wait them out
+            self.ratelimiter
+                .rate_limited(route, retry_after, global)
+                .await;
+        }
+    }
+}
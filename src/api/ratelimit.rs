@@ -0,0 +1,426 @@
+//! Client side handling of discord [rate limits](https://discord.com/developers/docs/topics/rate-limits)
+//!
+//! Discord groups routes into buckets, which are only known after the first response from a route.
+//! Until then each route is treated as its own bucket.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+use reqwest::Method;
+use serde::Deserialize;
+use tokio::sync::OwnedMutexGuard;
+use tokio::time::{sleep_until, Instant};
+
+/// Max amount of requests per second across all routes
+const GLOBAL_LIMIT: u32 = 50;
+
+/// Resources whose id is a major parameter, requests with different major parameters never share a bucket.
+const MAJOR_RESOURCES: [&str; 3] = ["channels", "guilds", "webhooks"];
+
+/// Route of a request, split into the parts used to find its bucket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Route {
+    /// Method and path with all ids replaced, used to look up the bucket hash
+    template: String,
+    /// Major parameters of the path
+    major: String,
+    /// Does this route count towards the global limit
+    global: bool,
+}
+
+impl Route {
+    /// Find the route of a request to `path`
+    pub fn new(method: &Method, path: &str) -> Self {
+        let segments: Vec<&str> = path.split('/').collect();
+        let mut template = Vec::with_capacity(segments.len());
+        let mut major = Vec::new();
+        let mut has_token = false;
+
+        for (index, segment) in segments.iter().enumerate() {
+            let previous = index.checked_sub(1).map(|index| segments[index]);
+            let is_id = !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit());
+
+            let part = match previous {
+                Some(resource) if is_id && MAJOR_RESOURCES.contains(&resource) => {
+                    major.push(*segment);
+                    ":major"
+                }
+                // webhook tokens are part of the major parameter
+                Some(_) if index >= 2 && segments[index - 2] == "webhooks" && !is_id => {
+                    major.push(*segment);
+                    has_token = true;
+                    ":token"
+                }
+                Some("reactions") => ":emoji",
+                _ if index >= 2 && segments[index - 2] == "interactions" => ":token",
+                _ if is_id => ":id",
+                _ => segment,
+            };
+            template.push(part);
+        }
+
+        Self {
+            template: format!("{method} {}", template.join("/")),
+            major: major.join("/"),
+            // requests authenticated by a interaction or webhook token are not sent as the bot
+            global: !has_token && !path.starts_with("interactions/"),
+        }
+    }
+}
+
+/// Rate limit information discord sends with each response
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct RateLimitHeaders {
+    /// `X-RateLimit-Bucket`, unique id of the bucket
+    pub bucket: Option<String>,
+    /// `X-RateLimit-Limit`, max requests in the bucket
+    pub limit: Option<u32>,
+    /// `X-RateLimit-Remaining`, requests left before the bucket resets
+    pub remaining: Option<u32>,
+    /// `X-RateLimit-Reset-After`, seconds until the bucket resets
+    pub reset_after: Option<f64>,
+    /// `X-RateLimit-Global`, is this a global rate limit
+    pub global: bool,
+    /// `X-RateLimit-Scope`, `user`, `global` or `shared`
+    pub scope: Option<String>,
+}
+
+impl From<&HeaderMap> for RateLimitHeaders {
+    fn from(headers: &HeaderMap) -> Self {
+        let get = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+
+        Self {
+            bucket: get("x-ratelimit-bucket").map(ToOwned::to_owned),
+            limit: get("x-ratelimit-limit").and_then(|value| value.parse().ok()),
+            remaining: get("x-ratelimit-remaining").and_then(|value| value.parse().ok()),
+            reset_after: get("x-ratelimit-reset-after").and_then(|value| value.parse().ok()),
+            global: get("x-ratelimit-global") == Some("true"),
+            scope: get("x-ratelimit-scope").map(ToOwned::to_owned),
+        }
+    }
+}
+
+/// Body of a `429 Too Many Requests` response
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct RateLimitedResponse {
+    /// Seconds to wait before trying again
+    pub retry_after: f64,
+    /// Is this a global rate limit
+    #[serde(default)]
+    pub global: bool,
+}
+
+/// State of a single bucket
+#[derive(Debug, Default)]
+pub(crate) struct BucketState {
+    /// Requests left, [`None`] when not known yet
+    remaining: Option<u32>,
+    /// When the bucket resets
+    reset_at: Option<Instant>,
+}
+
+impl BucketState {
+    /// Apply the limits of a response
+    fn apply(&mut self, remaining: u32, reset_at: Instant) {
+        match (self.remaining, self.reset_at) {
+            // responses in the same window can arrive out of order, the lowest count is the newest
+            (Some(current), Some(current_reset_at)) if Instant::now() < current_reset_at => {
+                self.remaining = Some(current.min(remaining));
+            }
+            _ => {
+                self.remaining = Some(remaining);
+                self.reset_at = Some(reset_at);
+            }
+        }
+    }
+}
+
+/// Bucket shared by one or more routes.
+///
+/// The lock is held while waiting for the bucket to reset and while its limits are unknown, so requests are queued in order.
+type Bucket = tokio::sync::Mutex<BucketState>;
+
+/// Permission to send a request, given by [`RateLimiter::acquire`] and handed back to [`RateLimiter::update`].
+///
+/// While the remaining requests of a bucket are unknown the ticket holds the bucket,
+/// so only a single request is sent until its response tells us the limit.
+#[derive(Debug)]
+pub(crate) struct Ticket(Option<(Arc<Bucket>, OwnedMutexGuard<BucketState>)>);
+
+/// State of the global rate limit
+#[derive(Debug)]
+struct GlobalState {
+    /// Start of the current one second window
+    window_start: Instant,
+    /// Requests sent in the current window
+    count: u32,
+    /// Set when discord tells us we hit the global limit
+    blocked_until: Option<Instant>,
+}
+
+/// Keeps track of buckets and makes requests wait until they are allowed to be sent.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    /// Route template -> bucket hash sent by discord
+    hashes: Mutex<HashMap<String, String>>,
+    /// Bucket hash + major parameters -> bucket
+    buckets: Mutex<HashMap<String, Arc<Bucket>>>,
+    global: tokio::sync::Mutex<GlobalState>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self {
+            hashes: Mutex::default(),
+            buckets: Mutex::default(),
+            global: tokio::sync::Mutex::new(GlobalState {
+                window_start: Instant::now(),
+                count: 0,
+                blocked_until: None,
+            }),
+        }
+    }
+}
+
+impl RateLimiter {
+    /// Get the bucket `route` currently belongs to
+    fn bucket(&self, route: &Route) -> Arc<Bucket> {
+        let hash = self
+            .hashes
+            .lock()
+            .unwrap()
+            .get(&route.template)
+            .cloned()
+            .unwrap_or_else(|| route.template.clone());
+
+        self.buckets
+            .lock()
+            .unwrap()
+            .entry(format!("{hash}:{}", route.major))
+            .or_default()
+            .clone()
+    }
+
+    /// Wait until the global limit allows another request
+    async fn acquire_global(&self) {
+        let mut global = self.global.lock().await;
+
+        if let Some(blocked_until) = global.blocked_until.take() {
+            sleep_until(blocked_until).await;
+        }
+
+        let window = Duration::from_secs(1);
+        if global.window_start.elapsed() >= window {
+            global.window_start = Instant::now();
+            global.count = 0;
+        }
+        if global.count >= GLOBAL_LIMIT {
+            sleep_until(global.window_start + window).await;
+            global.window_start = Instant::now();
+            global.count = 0;
+        }
+        global.count += 1;
+    }
+
+    /// Wait until a request to `route` is allowed to be sent
+    pub async fn acquire(&self, route: &Route) -> Ticket {
+        let bucket = self.bucket(route);
+        let mut state = bucket.clone().lock_owned().await;
+
+        if let Some(reset_at) = state.reset_at {
+            if state.remaining == Some(0) {
+                sleep_until(reset_at).await;
+            }
+            if Instant::now() >= reset_at {
+                state.remaining = None;
+                state.reset_at = None;
+            }
+        }
+
+        // counted only once the bucket allows the request, so it is sent in the window it is counted in
+        if route.global {
+            self.acquire_global().await;
+        }
+
+        if let Some(remaining) = state.remaining.as_mut() {
+            *remaining = remaining.saturating_sub(1);
+            Ticket(None)
+        } else {
+            Ticket(Some((bucket, state)))
+        }
+    }
+
+    /// Update the bucket of `route` using the headers of a response, releasing the ticket of the request
+    pub async fn update(&self, route: &Route, headers: &RateLimitHeaders, ticket: Ticket) {
+        if let Some(hash) = &headers.bucket {
+            self.hashes
+                .lock()
+                .unwrap()
+                .insert(route.template.clone(), hash.clone());
+        }
+
+        let (Some(remaining), Some(reset_after)) = (headers.remaining, headers.reset_after) else {
+            return;
+        };
+        let reset_at =
+            Instant::now() + Duration::try_from_secs_f64(reset_after).unwrap_or_default();
+        let bucket = self.bucket(route);
+
+        // the route might have moved to the bucket discord told us about,
+        // update the held one as well so the requests queued on it are let through
+        if let Ticket(Some((held, mut state))) = ticket {
+            state.apply(remaining, reset_at);
+            if Arc::ptr_eq(&held, &bucket) {
+                return;
+            }
+        }
+        bucket.lock().await.apply(remaining, reset_at);
+    }
+
+    /// Block `route` (or all routes for global limits) after getting a `429` response
+    pub async fn rate_limited(&self, route: &Route, retry_after: Duration, global: bool) {
+        let until = Instant::now() + retry_after;
+
+        if global {
+            self.global.lock().await.blocked_until = Some(until);
+        } else {
+            let bucket = self.bucket(route);
+            let mut state = bucket.lock().await;
+            state.remaining = Some(0);
+            state.reset_at = Some(until);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_major_parameters() {
+        let first = Route::new(&Method::POST, "channels/123/messages");
+        let second = Route::new(&Method::POST, "channels/456/messages");
+
+        assert_eq!(first.template, second.template);
+        assert_eq!(first.template, "POST channels/:major/messages");
+        assert_eq!(first.major, "123");
+        assert_ne!(first.major, second.major);
+    }
+
+    #[test]
+    fn test_route_minor_parameters() {
+        let route = Route::new(
+            &Method::PUT,
+            "channels/1/messages/2/reactions/%F0%9F%91%8D/@me",
+        );
+
+        assert_eq!(
+            route.template,
+            "PUT channels/:major/messages/:id/reactions/:emoji/@me"
+        );
+        assert_eq!(route.major, "1");
+    }
+
+    #[test]
+    fn test_route_webhook_token() {
+        let route = Route::new(&Method::POST, "webhooks/1/secret-token");
+
+        assert_eq!(route.template, "POST webhooks/:major/:token");
+        assert_eq!(route.major, "1/secret-token");
+        assert!(!route.global);
+
+        let followup = Route::new(&Method::PATCH, "webhooks/1/secret-token/messages/@original");
+        assert_eq!(
+            followup.template,
+            "PATCH webhooks/:major/:token/messages/@original"
+        );
+        assert!(!followup.global);
+
+        assert!(Route::new(&Method::GET, "webhooks/1").global);
+    }
+
+    #[test]
+    fn test_route_interaction_not_global() {
+        let route = Route::new(&Method::POST, "interactions/1/secret-token/callback");
+
+        assert_eq!(route.template, "POST interactions/:id/:token/callback");
+        assert!(!route.global);
+    }
+
+    fn headers(bucket: &str, remaining: u32) -> RateLimitHeaders {
+        RateLimitHeaders {
+            bucket: Some(bucket.to_owned()),
+            limit: Some(5),
+            remaining: Some(remaining),
+            reset_after: Some(60.0),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unknown_bucket_sends_one_request() {
+        let limiter = RateLimiter::default();
+        let route = Route::new(&Method::GET, "channels/1/messages");
+
+        let first = limiter.acquire(&route).await;
+        let blocked = tokio::time::timeout(Duration::from_millis(50), limiter.acquire(&route));
+        assert!(blocked.await.is_err());
+
+        limiter.update(&route, &headers("abcd", 4), first).await;
+        let second = tokio::time::timeout(Duration::from_millis(50), limiter.acquire(&route));
+        assert!(second.await.unwrap().0.is_none());
+        assert_eq!(limiter.bucket(&route).lock().await.remaining, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_out_of_order_response() {
+        let limiter = RateLimiter::default();
+        let route = Route::new(&Method::GET, "channels/1/messages");
+
+        let first = limiter.acquire(&route).await;
+        limiter.update(&route, &headers("abcd", 2), first).await;
+        let late = limiter.acquire(&route).await;
+        limiter.update(&route, &headers("abcd", 3), late).await;
+
+        assert_eq!(limiter.bucket(&route).lock().await.remaining, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_global_counted_after_bucket_wait() {
+        let limiter = RateLimiter::default();
+        let route = Route::new(&Method::GET, "channels/1/messages");
+        limiter
+            .rate_limited(&route, Duration::from_secs(60), false)
+            .await;
+
+        let waiting = tokio::time::timeout(Duration::from_millis(50), limiter.acquire(&route));
+        assert!(waiting.await.is_err());
+        assert_eq!(limiter.global.lock().await.count, 0);
+    }
+
+    #[test]
+    fn test_parse_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-bucket", "abcd".parse().unwrap());
+        headers.insert("x-ratelimit-limit", "5".parse().unwrap());
+        headers.insert("x-ratelimit-remaining", "4".parse().unwrap());
+        headers.insert("x-ratelimit-reset-after", "1.5".parse().unwrap());
+        headers.insert("x-ratelimit-scope", "user".parse().unwrap());
+
+        let parsed = RateLimitHeaders::from(&headers);
+
+        assert_eq!(
+            parsed,
+            RateLimitHeaders {
+                bucket: Some("abcd".to_owned()),
+                limit: Some(5),
+                remaining: Some(4),
+                reset_after: Some(1.5),
+                global: false,
+                scope: Some("user".to_owned()),
+            }
+        );
+    }
+}
@@ -0,0 +1,160 @@
+//! Reaction endpoints
+
+use super::{Api, Error, Request};
+use crate::datatypes::{EmojiRef, GetReactionsParams, Snowflake, User};
+
+impl Api {
+    /// React to a message as the bot
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord.
+    /// Or other general error with the input data, such as a unknown emoji.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vivcord::{Api, api::Error};
+    /// # tokio_test::block_on(async move {
+    /// let api = Api::new("TOKEN");
+    /// api.create_reaction(12345, 67890, '👍').await?;
+    /// api.create_reaction(12345, 67890, "custom_emoji:1234").await?;
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    pub async fn create_reaction<C: Into<Snowflake>, M: Into<Snowflake>, E: Into<EmojiRef>>(
+        &self,
+        channel_id: C,
+        message_id: M,
+        emoji: E,
+    ) -> Result<(), Error> {
+        let channel_id = channel_id.into().0;
+        let message_id = message_id.into().0;
+        let emoji = emoji.into().url_encoded();
+
+        self.send(Request::put(format!(
+            "channels/{channel_id}/messages/{message_id}/reactions/{emoji}/@me"
+        )))
+        .await
+    }
+
+    /// Remove a reaction the bot made
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn delete_own_reaction<C: Into<Snowflake>, M: Into<Snowflake>, E: Into<EmojiRef>>(
+        &self,
+        channel_id: C,
+        message_id: M,
+        emoji: E,
+    ) -> Result<(), Error> {
+        let channel_id = channel_id.into().0;
+        let message_id = message_id.into().0;
+        let emoji = emoji.into().url_encoded();
+
+        self.send(Request::delete(format!(
+            "channels/{channel_id}/messages/{message_id}/reactions/{emoji}/@me"
+        )))
+        .await
+    }
+
+    /// Remove a reaction made by someone else
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord.
+    /// Or other general error with the input data, such as missing `MANAGE_MESSAGES` permission.
+    pub async fn delete_user_reaction<C, M, E, U>(
+        &self,
+        channel_id: C,
+        message_id: M,
+        emoji: E,
+        user_id: U,
+    ) -> Result<(), Error>
+    where
+        C: Into<Snowflake>,
+        M: Into<Snowflake>,
+        E: Into<EmojiRef>,
+        U: Into<Snowflake>,
+    {
+        let channel_id = channel_id.into().0;
+        let message_id = message_id.into().0;
+        let emoji = emoji.into().url_encoded();
+        let user_id = user_id.into().0;
+
+        self.send(Request::delete(format!(
+            "channels/{channel_id}/messages/{message_id}/reactions/{emoji}/{user_id}"
+        )))
+        .await
+    }
+
+    /// Get users that reacted with a emoji
+    ///
+    /// Results are sorted by user id, use [`GetReactionsParams::after`] to get the next page.
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn get_reactions<C: Into<Snowflake>, M: Into<Snowflake>, E: Into<EmojiRef>>(
+        &self,
+        channel_id: C,
+        message_id: M,
+        emoji: E,
+        params: GetReactionsParams,
+    ) -> Result<Vec<User>, Error> {
+        let channel_id = channel_id.into().0;
+        let message_id = message_id.into().0;
+        let emoji = emoji.into().url_encoded();
+
+        self.send(
+            Request::get(format!(
+                "channels/{channel_id}/messages/{message_id}/reactions/{emoji}"
+            ))
+            .query(&params),
+        )
+        .await
+    }
+
+    /// Remove all reactions from a message
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord.
+    /// Or other general error with the input data, such as missing `MANAGE_MESSAGES` permission.
+    pub async fn delete_all_reactions<C: Into<Snowflake>, M: Into<Snowflake>>(
+        &self,
+        channel_id: C,
+        message_id: M,
+    ) -> Result<(), Error> {
+        let channel_id = channel_id.into().0;
+        let message_id = message_id.into().0;
+
+        self.send(Request::delete(format!(
+            "channels/{channel_id}/messages/{message_id}/reactions"
+        )))
+        .await
+    }
+
+    /// Remove all reactions of a single emoji from a message
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord.
+    /// Or other general error with the input data, such as missing `MANAGE_MESSAGES` permission.
+    pub async fn delete_all_reactions_for_emoji<C, M, E>(
+        &self,
+        channel_id: C,
+        message_id: M,
+        emoji: E,
+    ) -> Result<(), Error>
+    where
+        C: Into<Snowflake>,
+        M: Into<Snowflake>,
+        E: Into<EmojiRef>,
+    {
+        let channel_id = channel_id.into().0;
+        let message_id = message_id.into().0;
+        let emoji = emoji.into().url_encoded();
+
+        self.send(Request::delete(format!(
+            "channels/{channel_id}/messages/{message_id}/reactions/{emoji}"
+        )))
+        .await
+    }
+}
@@ -0,0 +1,308 @@
+//! Description of a single request to the discord api
+
+use std::collections::HashSet;
+
+use reqwest::multipart::{Form, Part};
+use reqwest::Method;
+use serde::Serialize;
+
+use super::Error;
+use crate::datatypes::AttachmentFile;
+
+/// A request that has not been sent yet.
+///
+/// This is kept separate from [`reqwest::RequestBuilder`] so the [`Api`][crate::Api] can look at the route before sending it.
+#[derive(Debug, Clone)]
+pub(crate) struct Request {
+    /// Http method
+    pub method: Method,
+    /// Path relative to the api base url, without a leading `/`
+    pub path: String,
+    /// Query parameters
+    pub query: Vec<(String, String)>,
+    /// Json body
+    pub body: Option<serde_json::Value>,
+    /// Files to upload with their attachment id, when not empty the request is sent as `multipart/form-data`
+    pub files: Vec<(u64, AttachmentFile)>,
+}
+
+impl Request {
+    /// Create a request with the given method and path
+    pub fn new(method: Method, path: String) -> Self {
+        Self {
+            method,
+            path,
+            query: Vec::new(),
+            body: None,
+            files: Vec::new(),
+        }
+    }
+
+    /// `GET` request
+    pub fn get(path: String) -> Self {
+        Self::new(Method::GET, path)
+    }
+
+    /// `POST` request
+    pub fn post(path: String) -> Self {
+        Self::new(Method::POST, path)
+    }
+
+    /// `PATCH` request
+    pub fn patch(path: String) -> Self {
+        Self::new(Method::PATCH, path)
+    }
+
+    /// `PUT` request
+    pub fn put(path: String) -> Self {
+        Self::new(Method::PUT, path)
+    }
+
+    /// `DELETE` request
+    pub fn delete(path: String) -> Self {
+        Self::new(Method::DELETE, path)
+    }
+
+    /// Add the fields of `params` as query parameters, [`None`] fields are skipped.
+    ///
+    /// # Panics
+    /// If `params` does not serialize into a json object
+    pub fn query<Q: Serialize>(mut self, params: &Q) -> Self {
+        let value = serde_json::to_value(params).expect("query params should serialize");
+        let serde_json::Value::Object(fields) = value else {
+            panic!("query params should be a struct");
+        };
+
+        for (key, value) in fields {
+            let value = match value {
+                serde_json::Value::Null => continue,
+                serde_json::Value::String(value) => value,
+                other => other.to_string(),
+            };
+            self.query.push((key, value));
+        }
+        self
+    }
+
+    /// Use `body` as the json body
+    ///
+    /// # Panics
+    /// If `body` can not be serialized to json
+    pub fn json<B: Serialize>(mut self, body: &B) -> Self {
+        self.body = Some(serde_json::to_value(body).expect("body should serialize"));
+        self
+    }
+
+    /// Upload `files` with the request.
+    ///
+    /// Should be called after [`Request::json`], a entry is added to the `attachments` of the body for each file,
+    /// using ids that are not taken by the attachments already listed.
+    /// Interaction callbacks nest the message in a `data` object, the `attachments` are placed there instead.
+    pub fn files(self, files: Vec<AttachmentFile>) -> Self {
+        self.attach(files, true)
+    }
+
+    /// Same as [`Request::files`], but for edits.
+    ///
+    /// When the body does not list any `attachments` none are generated,
+    /// as discord would remove the existing attachments not in the list, the new files are added to the message instead.
+    pub fn edit_files(self, files: Vec<AttachmentFile>) -> Self {
+        self.attach(files, false)
+    }
+
+    /// Add the files and their entries, creating the `attachments` list if `create_list` is set
+    fn attach(mut self, files: Vec<AttachmentFile>, create_list: bool) -> Self {
+        if files.is_empty() {
+            return self;
+        }
+
+        let mut body = self.body.get_or_insert_with(|| serde_json::json!({}));
+        if body.get("data").is_some_and(serde_json::Value::is_object) {
+            body = &mut body["data"];
+        }
+        let mut attachments = match body.as_object_mut() {
+            Some(body) if create_list => body
+                .entry("attachments")
+                .or_insert_with(|| serde_json::json!([]))
+                .as_array_mut(),
+            Some(body) => body
+                .get_mut("attachments")
+                .and_then(serde_json::Value::as_array_mut),
+            None => None,
+        };
+
+        // existing attachments use their snowflake, but new files listed by the caller use small ids
+        let mut taken: HashSet<u64> = attachments
+            .iter()
+            .flat_map(|attachments| attachments.iter())
+            .filter_map(|attachment| match &attachment["id"] {
+                serde_json::Value::String(id) => id.parse().ok(),
+                id => id.as_u64(),
+            })
+            .collect();
+
+        let mut id = 0;
+        for file in files {
+            while taken.contains(&id) {
+                id += 1;
+            }
+            taken.insert(id);
+
+            if let Some(attachments) = attachments.as_mut() {
+                attachments.push(serde_json::json!({
+                    "id": id,
+                    "filename": file.upload_filename(),
+                    "description": file.upload_description(),
+                }));
+            }
+            self.files.push((id, file));
+        }
+        self
+    }
+
+    /// Can this request be sent again if it fails
+    pub fn is_reusable(&self) -> bool {
+        self.files.iter().all(|(_, file)| file.is_reusable())
+    }
+
+    /// Create the `multipart/form-data` body, with the json body in the `payload_json` field.
+    pub async fn multipart_form(&self) -> Result<Form, Error> {
+        let mut form = Form::new();
+        if let Some(body) = &self.body {
+            form = form.part(
+                "payload_json",
+                Part::text(body.to_string()).mime_str("application/json")?,
+            );
+        }
+
+        for (id, file) in &self.files {
+            form = form.part(format!("files[{id}]"), file.to_part().await?);
+        }
+        Ok(form)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AttachmentFile, Request};
+
+    #[test]
+    fn test_query_skips_none() {
+        #[derive(serde::Serialize)]
+        struct Params {
+            limit: Option<u8>,
+            before: Option<u64>,
+            with_user_count: bool,
+        }
+
+        let request = Request::get("test".to_owned()).query(&Params {
+            limit: Some(10),
+            before: None,
+            with_user_count: true,
+        });
+
+        assert_eq!(
+            request.query,
+            vec![
+                ("limit".to_owned(), "10".to_owned()),
+                ("with_user_count".to_owned(), "true".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_files_generate_attachments() {
+        #[derive(serde::Serialize)]
+        struct Body {
+            content: &'static str,
+        }
+
+        let request = Request::post("test".to_owned())
+            .json(&Body { content: "hello" })
+            .files(vec![
+                AttachmentFile::from_bytes("a.txt", "a").description("first"),
+                AttachmentFile::from_bytes("b.txt", "b").spoiler(true),
+            ]);
+
+        assert_eq!(
+            request.body,
+            Some(serde_json::json!({
+                "content": "hello",
+                "attachments": [
+                    {"id": 0, "filename": "a.txt", "description": "first"},
+                    {"id": 1, "filename": "SPOILER_b.txt", "description": null},
+                ]
+            }))
+        );
+    }
+
+    #[test]
+    fn test_files_keep_listed_attachments() {
+        let request = Request::post("test".to_owned())
+            .json(&serde_json::json!({
+                "attachments": [{"id": "1234"}, {"id": 0, "description": "listed"}]
+            }))
+            .files(vec![AttachmentFile::from_bytes("a.txt", "a")]);
+
+        assert_eq!(
+            request.body.unwrap()["attachments"],
+            serde_json::json!([
+                {"id": "1234"},
+                {"id": 0, "description": "listed"},
+                {"id": 1, "filename": "a.txt", "description": null},
+            ])
+        );
+        assert_eq!(request.files[0].0, 1);
+    }
+
+    #[test]
+    fn test_edit_files() {
+        use crate::datatypes::{EditMessageParams, PartialAttachment, Snowflake};
+
+        let files = || vec![AttachmentFile::from_bytes("a.txt", "a")];
+
+        // without a list discord keeps the existing attachments
+        let request = Request::patch("test".to_owned())
+            .json(&EditMessageParams::default())
+            .edit_files(files());
+        assert_eq!(request.body, Some(serde_json::json!({})));
+        assert_eq!(request.files[0].0, 0);
+
+        let keep = EditMessageParams {
+            attachments: Some(vec![PartialAttachment {
+                id: Snowflake(1234),
+                filename: None,
+                description: None,
+            }]),
+            ..Default::default()
+        };
+        let request = Request::patch("test".to_owned())
+            .json(&keep)
+            .edit_files(files());
+        assert_eq!(
+            request.body.unwrap()["attachments"],
+            serde_json::json!([
+                {"id": "1234"},
+                {"id": 0, "filename": "a.txt", "description": null},
+            ])
+        );
+    }
+
+    #[test]
+    fn test_files_attachments_in_callback_data() {
+        let request = Request::post("test".to_owned())
+            .json(&serde_json::json!({"type": 4, "data": {"content": "hello"}}))
+            .files(vec![AttachmentFile::from_bytes("a.txt", "a")]);
+
+        assert_eq!(
+            request.body,
+            Some(serde_json::json!({
+                "type": 4,
+                "data": {
+                    "content": "hello",
+                    "attachments": [{"id": 0, "filename": "a.txt", "description": null}]
+                }
+            }))
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Retrying requests that failed because of temporary problems
+
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+
+/// Decides which failed requests the [`Api`][crate::Api] retries, and how long it waits between attempts.
+///
+/// Rate limits are handled separately and do not count as attempts.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use vivcord::{Api, api::RetryPolicy};
+/// let api = Api::new("TOKEN").with_retry_policy(RetryPolicy {
+///     max_attempts: 5,
+///     base_delay: Duration::from_millis(250),
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Max amount of times a request is sent, including the first attempt.
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled after each attempt
+    pub base_delay: Duration,
+    /// Upper limit on the delay between attempts
+    pub max_delay: Duration,
+    /// Randomize delays, so many failed requests do not all retry at the same time
+    pub jitter: bool,
+    /// Response statuses that are retried
+    pub status_codes: Vec<StatusCode>,
+    /// Methods that are safe to retry.
+    ///
+    /// Requests that failed to connect are retried for any method, since discord never saw them.
+    pub methods: Vec<Method>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+            status_codes: vec![
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            methods: vec![Method::GET, Method::HEAD, Method::PUT, Method::DELETE],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that never retries
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Should a request with `method` be retried after getting a response with `status`
+    pub(crate) fn retry_status(&self, method: &Method, status: StatusCode) -> bool {
+        self.methods.contains(method) && self.status_codes.contains(&status)
+    }
+
+    /// Should a request with `method` be retried after failing with `error`
+    pub(crate) fn retry_error(&self, method: &Method, error: &reqwest::Error) -> bool {
+        error.is_connect()
+            || (self.methods.contains(method) && (error.is_timeout() || error.is_request()))
+    }
+
+    /// How long to wait after `attempt` failed, `attempt` starts at `1`
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2_u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+
+        if self.jitter {
+            // somewhere between half and the full delay
+            delay.mul_f64(0.5 + rand::random::<f64>() / 2.0)
+        } else {
+            delay
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_exponential() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(350),
+            jitter: false,
+            ..Default::default()
+        };
+
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(350));
+        assert_eq!(policy.delay(100), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn test_delay_jitter() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            ..Default::default()
+        };
+
+        for _ in 0..100 {
+            let delay = policy.delay(2);
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn test_retry_status() {
+        let policy = RetryPolicy::default();
+
+        assert!(policy.retry_status(&Method::GET, StatusCode::BAD_GATEWAY));
+        assert!(!policy.retry_status(&Method::GET, StatusCode::NOT_FOUND));
+        assert!(!policy.retry_status(&Method::POST, StatusCode::BAD_GATEWAY));
+    }
+}
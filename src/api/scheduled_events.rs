@@ -0,0 +1,163 @@
+//! Guild scheduled event endpoints
+
+use serde::Serialize;
+
+use super::{Api, Error, Request};
+use crate::datatypes::{
+    CreateGuildScheduledEventParams, GetGuildScheduledEventUsersParams, GuildScheduledEvent,
+    GuildScheduledEventUser, ModifyGuildScheduledEventParams, Snowflake,
+};
+
+/// Query parameters shared by the endpoints returning scheduled events
+#[derive(Serialize)]
+struct WithUserCount {
+    with_user_count: bool,
+}
+
+impl Api {
+    /// Get all scheduled events in a guild
+    ///
+    /// When `with_user_count` is `true` [`GuildScheduledEvent::user_count`] will be set.
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vivcord::{Api, api::Error};
+    /// # tokio_test::block_on(async move {
+    /// let api = Api::new("TOKEN");
+    /// for event in api.list_guild_scheduled_events(12345, true).await? {
+    ///     println!("{} has {:?} subscribers", event.name, event.user_count);
+    /// }
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    pub async fn list_guild_scheduled_events<I: Into<Snowflake>>(
+        &self,
+        guild_id: I,
+        with_user_count: bool,
+    ) -> Result<Vec<GuildScheduledEvent>, Error> {
+        let guild_id = guild_id.into().0;
+
+        self.send(
+            Request::get(format!("guilds/{guild_id}/scheduled-events"))
+                .query(&WithUserCount { with_user_count }),
+        )
+        .await
+    }
+
+    /// Create a scheduled event in a guild
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as a missing location for external events.
+    pub async fn create_guild_scheduled_event<I: Into<Snowflake>>(
+        &self,
+        guild_id: I,
+        event: CreateGuildScheduledEventParams,
+    ) -> Result<GuildScheduledEvent, Error> {
+        let guild_id = guild_id.into().0;
+
+        self.send(Request::post(format!("guilds/{guild_id}/scheduled-events")).json(&event))
+            .await
+    }
+
+    /// Get a single scheduled event
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn get_guild_scheduled_event<G: Into<Snowflake>, E: Into<Snowflake>>(
+        &self,
+        guild_id: G,
+        event_id: E,
+        with_user_count: bool,
+    ) -> Result<GuildScheduledEvent, Error> {
+        let guild_id = guild_id.into().0;
+        let event_id = event_id.into().0;
+
+        self.send(
+            Request::get(format!("guilds/{guild_id}/scheduled-events/{event_id}"))
+                .query(&WithUserCount { with_user_count }),
+        )
+        .await
+    }
+
+    /// Modify a scheduled event, this is also used to start, end or cancel the event.
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as a invalid status transition.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vivcord::{Api, api::Error};
+    /// # use vivcord::datatypes::{ModifyGuildScheduledEventParams, GuildScheduledEventStatus};
+    /// # tokio_test::block_on(async move {
+    /// let api = Api::new("TOKEN");
+    /// api.modify_guild_scheduled_event(12345, 67890, ModifyGuildScheduledEventParams {
+    ///     status: Some(GuildScheduledEventStatus::Active),
+    ///     ..Default::default()
+    /// }).await?;
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    pub async fn modify_guild_scheduled_event<G: Into<Snowflake>, E: Into<Snowflake>>(
+        &self,
+        guild_id: G,
+        event_id: E,
+        event: ModifyGuildScheduledEventParams,
+    ) -> Result<GuildScheduledEvent, Error> {
+        let guild_id = guild_id.into().0;
+        let event_id = event_id.into().0;
+
+        self.send(
+            Request::patch(format!("guilds/{guild_id}/scheduled-events/{event_id}")).json(&event),
+        )
+        .await
+    }
+
+    /// Delete a scheduled event
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn delete_guild_scheduled_event<G: Into<Snowflake>, E: Into<Snowflake>>(
+        &self,
+        guild_id: G,
+        event_id: E,
+    ) -> Result<(), Error> {
+        let guild_id = guild_id.into().0;
+        let event_id = event_id.into().0;
+
+        self.send(Request::delete(format!(
+            "guilds/{guild_id}/scheduled-events/{event_id}"
+        )))
+        .await
+    }
+
+    /// Get users subscribed to a scheduled event
+    ///
+    /// Results are sorted by user id, use [`GetGuildScheduledEventUsersParams::after`] to get the next page.
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// Or other general error with the input data, such as invalid ids.
+    pub async fn get_guild_scheduled_event_users<G: Into<Snowflake>, E: Into<Snowflake>>(
+        &self,
+        guild_id: G,
+        event_id: E,
+        params: GetGuildScheduledEventUsersParams,
+    ) -> Result<Vec<GuildScheduledEventUser>, Error> {
+        let guild_id = guild_id.into().0;
+        let event_id = event_id.into().0;
+
+        self.send(
+            Request::get(format!("guilds/{guild_id}/scheduled-events/{event_id}/users"))
+                .query(&params),
+        )
+        .await
+    }
+}
@@ -0,0 +1,57 @@
+//! Webhook endpoints
+
+use serde::Serialize;
+
+use super::{Api, Error, Request};
+use crate::datatypes::{ExecuteWebhookParams, Message, Snowflake};
+
+/// Query parameters for [`Api::execute_webhook`]
+#[derive(Serialize)]
+struct ExecuteWebhookQuery {
+    wait: bool,
+}
+
+impl Api {
+    /// Send a message using a webhook
+    ///
+    /// When `wait` is `true` discord waits for the message to be saved and returns it,
+    /// otherwise `None` is returned.
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response, failing to read a uploaded file.
+    /// Or other general error with the input data, such as a invalid webhook token.
+    /// [`Error::ValidationErr`] if the message exceeds discords limits, see [`ExecuteWebhookParams::validate`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vivcord::{Api, api::Error};
+    /// # use vivcord::datatypes::{AttachmentFile, ExecuteWebhookParams};
+    /// # tokio_test::block_on(async move {
+    /// let api = Api::new("TOKEN");
+    /// api.execute_webhook(12345, "webhook-token", ExecuteWebhookParams {
+    ///     content: Some("Todays report".to_owned()),
+    ///     files: vec![AttachmentFile::from_path("./report.pdf")],
+    ///     ..Default::default()
+    /// }, false).await?;
+    /// # Ok::<(), Error>(())
+    /// # });
+    /// ```
+    pub async fn execute_webhook<I: Into<Snowflake>>(
+        &self,
+        webhook_id: I,
+        webhook_token: &str,
+        params: ExecuteWebhookParams,
+        wait: bool,
+    ) -> Result<Option<Message>, Error> {
+        let webhook_id = webhook_id.into().0;
+        params.validate()?;
+
+        self.send(
+            Request::post(format!("webhooks/{webhook_id}/{webhook_token}"))
+                .query(&ExecuteWebhookQuery { wait })
+                .json(&params)
+                .files(params.files),
+        )
+        .await
+    }
+}
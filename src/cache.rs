@@ -0,0 +1,303 @@
+//! Keeping data received from the gateway, so it does not need to be requested again
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+
+use crate::datatypes::{Message, Snowflake};
+use crate::EventData;
+
+/// Default amount of messages kept per channel
+pub const DEFAULT_MAX_MESSAGES: usize = 50;
+
+/// Default amount of channels messages are kept for
+pub const DEFAULT_MAX_CHANNELS: usize = 1000;
+
+/// What the [`Cache`] keeps
+///
+/// # Example
+/// ```
+/// # use vivcord::cache::CachePolicy;
+/// let policy = CachePolicy {
+///     max_messages: 200,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CachePolicy {
+    /// Max messages kept per channel, `0` disables caching messages
+    pub max_messages: usize,
+    /// Max channels messages are kept for, once reached the channel with the oldest newest message is dropped
+    pub max_channels: usize,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self {
+            max_messages: DEFAULT_MAX_MESSAGES,
+            max_channels: DEFAULT_MAX_CHANNELS,
+        }
+    }
+}
+
+impl CachePolicy {
+    /// Policy that keeps nothing
+    #[must_use]
+    pub fn none() -> Self {
+        Self {
+            max_messages: 0,
+            max_channels: 0,
+        }
+    }
+}
+
+/// Recent messages received by the [`Client`][crate::Client], updated before events are handled.
+///
+/// The cache only knows what the gateway sent, so it can be stale:
+/// messages edited or deleted while the bot was disconnected, or in channels the bot gets no events for, are not updated.
+/// Request the data from the [`Api`][crate::Api] when it has to be current.
+///
+/// Clones share the same data.
+#[derive(Debug, Clone)]
+pub struct Cache {
+    inner: Arc<RwLock<CacheData>>,
+}
+
+#[derive(Debug)]
+struct CacheData {
+    /// Max messages kept per channel
+    max_messages: usize,
+    /// Max channels messages are kept for
+    max_channels: usize,
+    /// Messages per channel, oldest first
+    messages: HashMap<Snowflake, VecDeque<Message>>,
+}
+
+impl CacheData {
+    /// Keep a new message, dropping the oldest one of the channel when it is full
+    fn insert_message(&mut self, message: &Message) {
+        if self.max_messages == 0 || self.max_channels == 0 {
+            return;
+        }
+        if !self.messages.contains_key(&message.channel_id)
+            && self.messages.len() >= self.max_channels
+        {
+            self.evict_channel();
+        }
+
+        let messages = self.messages.entry(message.channel_id).or_default();
+        if messages.len() >= self.max_messages {
+            messages.pop_front();
+        }
+        messages.push_back(message.clone());
+    }
+
+    /// Drop the channel whose newest message is the oldest
+    fn evict_channel(&mut self) {
+        let oldest = self
+            .messages
+            .iter()
+            .min_by_key(|(_, messages)| messages.back().map(|message| message.id))
+            .map(|(channel_id, _)| *channel_id);
+        if let Some(channel_id) = oldest {
+            self.messages.remove(&channel_id);
+        }
+    }
+
+    /// Replace the cached copy of a edited message
+    fn update_message(&mut self, message: &Message) {
+        let cached = self
+            .messages
+            .get_mut(&message.channel_id)
+            .and_then(|messages| messages.iter_mut().find(|cached| cached.id == message.id));
+        if let Some(cached) = cached {
+            *cached = message.clone();
+        }
+    }
+
+    /// Forget deleted messages, and the channel once it has none left
+    fn remove_messages(&mut self, channel_id: Snowflake, ids: &[Snowflake]) {
+        let Some(messages) = self.messages.get_mut(&channel_id) else {
+            return;
+        };
+        messages.retain(|message| !ids.contains(&message.id));
+        if messages.is_empty() {
+            self.messages.remove(&channel_id);
+        }
+    }
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        Self::with_policy(CachePolicy::default())
+    }
+}
+
+impl Cache {
+    /// Cache keeping up to `max_messages` per channel, `0` disables caching messages
+    #[must_use]
+    pub fn new(max_messages: usize) -> Self {
+        Self::with_policy(CachePolicy {
+            max_messages,
+            ..Default::default()
+        })
+    }
+
+    /// Cache keeping what the policy allows
+    #[must_use]
+    pub fn with_policy(policy: CachePolicy) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(CacheData {
+                max_messages: policy.max_messages,
+                max_channels: policy.max_channels,
+                messages: HashMap::new(),
+            })),
+        }
+    }
+
+    /// A cached message
+    ///
+    /// # Panics
+    /// If a thread panicked while updating the cache
+    #[must_use]
+    pub fn message<C: Into<Snowflake>, M: Into<Snowflake>>(
+        &self,
+        channel_id: C,
+        message_id: M,
+    ) -> Option<Message> {
+        let message_id = message_id.into();
+        self.inner
+            .read()
+            .unwrap()
+            .messages
+            .get(&channel_id.into())?
+            .iter()
+            .find(|message| message.id == message_id)
+            .cloned()
+    }
+
+    /// The cached messages of a channel, oldest first
+    ///
+    /// # Panics
+    /// If a thread panicked while updating the cache
+    #[must_use]
+    pub fn channel_messages<C: Into<Snowflake>>(&self, channel_id: C) -> Vec<Message> {
+        self.inner
+            .read()
+            .unwrap()
+            .messages
+            .get(&channel_id.into())
+            .map(|messages| messages.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Store the data of a event, must be called in the order the events are received
+    pub(crate) fn update(&self, event: &EventData) {
+        let data = || self.inner.write().unwrap();
+        match event {
+            EventData::MessageCreate(message) => data().insert_message(message),
+            EventData::MessageUpdate(message) => data().update_message(message),
+            EventData::MessageDelete { id, channel_id, .. } => {
+                data().remove_messages(*channel_id, &[*id]);
+            }
+            EventData::MessageDeleteBulk {
+                ids, channel_id, ..
+            } => data().remove_messages(*channel_id, ids),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message_data(id: u64, channel_id: u64, content: &str) -> Message {
+        serde_json::from_value(serde_json::json!({
+            "id": id.to_string(),
+            "channel_id": channel_id.to_string(),
+            "author": {"id": "2", "username": "vivax", "discriminator": "0"},
+            "content": content
+        }))
+        .unwrap()
+    }
+
+    fn message(id: u64) -> EventData {
+        EventData::MessageCreate(message_data(id, 1, "hello"))
+    }
+
+    fn ids(messages: &[Message]) -> Vec<Snowflake> {
+        messages.iter().map(|message| message.id).collect()
+    }
+
+    #[test]
+    fn test_message_limit() {
+        let cache = Cache::new(2);
+        for id in 10..13 {
+            cache.update(&message(id));
+        }
+
+        assert_eq!(
+            ids(&cache.channel_messages(1)),
+            [Snowflake(11), Snowflake(12)]
+        );
+        assert!(cache.message(1, 10).is_none());
+        assert_eq!(cache.message(1, 12).unwrap().content, "hello");
+
+        let disabled = Cache::new(0);
+        disabled.update(&message(10));
+        assert!(disabled.channel_messages(1).is_empty());
+    }
+
+    #[test]
+    fn test_message_update_and_delete() {
+        let cache = Cache::new(10);
+        for id in 10..14 {
+            cache.update(&message(id));
+        }
+
+        cache.update(&EventData::MessageUpdate(message_data(11, 1, "edited")));
+        assert_eq!(cache.message(1, 11).unwrap().content, "edited");
+        // messages that are not cached are not added by edits
+        cache.update(&EventData::MessageUpdate(message_data(5, 1, "old")));
+        assert!(cache.message(1, 5).is_none());
+
+        cache.update(&EventData::MessageDelete {
+            id: Snowflake(10),
+            channel_id: Snowflake(1),
+            guild_id: None,
+        });
+        cache.update(&EventData::MessageDeleteBulk {
+            ids: vec![Snowflake(12), Snowflake(13)],
+            channel_id: Snowflake(1),
+            guild_id: None,
+        });
+        assert_eq!(ids(&cache.channel_messages(1)), [Snowflake(11)]);
+
+        cache.update(&EventData::MessageDelete {
+            id: Snowflake(11),
+            channel_id: Snowflake(1),
+            guild_id: None,
+        });
+        assert!(cache.inner.read().unwrap().messages.is_empty());
+    }
+
+    #[test]
+    fn test_channel_limit() {
+        let cache = Cache::with_policy(CachePolicy {
+            max_messages: 10,
+            max_channels: 2,
+        });
+        cache.update(&EventData::MessageCreate(message_data(10, 1, "a")));
+        cache.update(&EventData::MessageCreate(message_data(11, 2, "b")));
+        cache.update(&EventData::MessageCreate(message_data(12, 1, "c")));
+        cache.update(&EventData::MessageCreate(message_data(13, 3, "d")));
+
+        // channel 2 had the oldest newest message
+        assert!(cache.channel_messages(2).is_empty());
+        assert_eq!(
+            ids(&cache.channel_messages(1)),
+            [Snowflake(10), Snowflake(12)]
+        );
+        assert_eq!(ids(&cache.channel_messages(3)), [Snowflake(13)]);
+    }
+}
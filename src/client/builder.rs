@@ -0,0 +1,365 @@
+//! Configuring the [`Client`] before connecting
+
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::Client;
+use crate::api::{ApiBuilder, BuildError, RetryPolicy};
+use crate::cache::{Cache, CachePolicy};
+use crate::datatypes::{ApplicationFlags, Presence};
+use crate::gateway::DEFAULT_EVENT_BUFFER;
+use crate::{Gateway, Intents};
+
+/// Allowed values of [`ClientBuilder::large_threshold`]
+const LARGE_THRESHOLD_RANGE: std::ops::RangeInclusive<u8> = 50..=250;
+
+/// Errors that can happen while building a [`Client`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ClientBuildError {
+    /// The [`Api`][crate::Api] could not be created
+    Api(BuildError),
+    /// The shard range is empty or goes past the shard count
+    InvalidShards {
+        /// Shards this client would run
+        shards: Range<u32>,
+        /// Total shards of the bot
+        shard_count: u32,
+    },
+    /// Large threshold is not between 50 and 250
+    InvalidLargeThreshold(u8),
+    /// Event buffer size is `0`
+    InvalidEventBuffer,
+    /// Privileged intents that are not enabled for the bot in the developer portal,
+    /// only returned by [`ClientBuilder::build_checked`]
+    DisallowedIntents(Intents),
+    /// Getting the application of the bot failed, only returned by [`ClientBuilder::build_checked`]
+    Request(crate::api::Error),
+}
+
+impl std::fmt::Display for ClientBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Api(err) => write!(f, "failed to create api: {err}"),
+            Self::InvalidShards {
+                shards,
+                shard_count,
+            } => write!(f, "invalid shards {shards:?} out of {shard_count}"),
+            Self::InvalidLargeThreshold(threshold) => {
+                write!(f, "large threshold {threshold} is not between 50 and 250")
+            }
+            Self::InvalidEventBuffer => write!(f, "event buffer can not be empty"),
+            Self::DisallowedIntents(intents) => write!(
+                f,
+                "privileged intents {intents:?} are not enabled in the developer portal"
+            ),
+            Self::Request(err) => write!(f, "failed to get application: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientBuildError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Api(err) => Some(err),
+            Self::Request(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<BuildError> for ClientBuildError {
+    fn from(err: BuildError) -> Self {
+        Self::Api(err)
+    }
+}
+
+/// Settings the client uses when connecting to the gateway
+#[derive(Debug)]
+pub(crate) struct ClientConfig {
+    /// Events to receive
+    pub intents: Intents,
+    /// Shards to run and the total shard count, `None` when not sharding
+    pub shards: Option<(Range<u32>, u32)>,
+    /// Presence shown once connected
+    pub presence: Option<Presence>,
+    /// Member count from which discord stops sending offline members
+    pub large_threshold: Option<u8>,
+    /// Gateway url to use instead of asking discord
+    pub gateway_url: Option<String>,
+}
+
+impl ClientConfig {
+    /// Ids of the shards to run
+    pub fn shard_ids(&self) -> Range<u32> {
+        self.shards
+            .as_ref()
+            .map_or(0..1, |(shards, _)| shards.clone())
+    }
+}
+
+/// Builder for a [`Client`] with custom settings
+///
+/// # Example
+/// ```no_run
+/// # use std::time::Duration;
+/// # use vivcord::Client;
+/// # use vivcord::cache::CachePolicy;
+/// # use vivcord::datatypes::{Activity, Presence, Status};
+/// let intents = vivcord::Intents::GUILD_MESSAGES | vivcord::Intents::MESSAGE_CONTENT;
+/// let client = Client::builder("TOKEN".to_owned(), ())
+///     .intents(intents)
+///     .shards(0..2, 4)
+///     .presence(Presence::new(Status::Online).activity(Activity::listening("!help")))
+///     .cache(CachePolicy { max_messages: 200, ..Default::default() })
+///     .timeout(Duration::from_secs(10))
+///     .build()?;
+/// # Ok::<(), vivcord::client::ClientBuildError>(())
+/// ```
+#[allow(missing_debug_implementations)]
+#[must_use]
+pub struct ClientBuilder<S> {
+    token: String,
+    state: S,
+    api: ApiBuilder,
+    intents: Intents,
+    shards: Option<(Range<u32>, u32)>,
+    presence: Option<Presence>,
+    cache: CachePolicy,
+    event_buffer: usize,
+    large_threshold: Option<u8>,
+    gateway_url: Option<String>,
+}
+
+impl<S> ClientBuilder<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Start building a client using a bot token
+    pub fn new(token: String, state: S) -> Self {
+        Self {
+            api: ApiBuilder::new(&token),
+            token,
+            state,
+            intents: Intents::empty(),
+            shards: None,
+            presence: None,
+            cache: CachePolicy::default(),
+            event_buffer: DEFAULT_EVENT_BUFFER,
+            large_threshold: None,
+            gateway_url: None,
+        }
+    }
+
+    /// Events to receive from the gateway, no intents by default
+    pub fn intents(mut self, intents: Intents) -> Self {
+        self.intents = intents;
+        self
+    }
+
+    /// Run the shards in `shards` out of `shard_count` total, by default the bot is not sharded.
+    ///
+    /// Use this to split large bots over multiple processes, each running part of the shards.
+    pub fn shards(mut self, shards: Range<u32>, shard_count: u32) -> Self {
+        self.shards = Some((shards, shard_count));
+        self
+    }
+
+    /// Presence shown once connected
+    pub fn presence(mut self, presence: Presence) -> Self {
+        self.presence = Some(presence);
+        self
+    }
+
+    /// What the [`Cache`] keeps, see [`CachePolicy`]
+    pub fn cache(mut self, policy: CachePolicy) -> Self {
+        self.cache = policy;
+        self
+    }
+
+    /// Amount of events each shard buffers for handlers that fall behind, defaults to `5`.
+    ///
+    /// Handlers run in their own task, so this only needs to be raised when events come in faster than they can be spawned.
+    pub fn event_buffer(mut self, size: usize) -> Self {
+        self.event_buffer = size;
+        self
+    }
+
+    /// Member count from which discord stops sending offline members of a guild, between 50 and 250.
+    /// Uses discords default of 50 when not set.
+    pub fn large_threshold(mut self, large_threshold: u8) -> Self {
+        self.large_threshold = Some(large_threshold);
+        self
+    }
+
+    /// Gateway url to connect to, instead of getting it from [`Api::get_gateway_url`][crate::Api::get_gateway_url]
+    pub fn gateway_url(mut self, url: &str) -> Self {
+        self.gateway_url = Some(url.to_owned());
+        self
+    }
+
+    /// Url api requests are sent to, see [`ApiBuilder::base_url`]
+    pub fn base_url(mut self, url: &str) -> Self {
+        self.api = self.api.base_url(url);
+        self
+    }
+
+    /// Max time a single api request is allowed to take, see [`ApiBuilder::timeout`]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.api = self.api.timeout(timeout);
+        self
+    }
+
+    /// Which failed api requests to retry, see [`RetryPolicy`]
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.api = self.api.retry_policy(policy);
+        self
+    }
+
+    /// Configure the rest of the [`Api`][crate::Api], such as the user agent or request hooks
+    ///
+    /// # Example
+    /// ```
+    /// # use vivcord::Client;
+    /// let client = Client::builder("TOKEN".to_owned(), ())
+    ///     .api(|api| api.user_agent("DiscordBot (https://example.com, 1.0)"))
+    ///     .build()?;
+    /// # Ok::<(), vivcord::client::ClientBuildError>(())
+    /// ```
+    pub fn api<F: FnOnce(ApiBuilder) -> ApiBuilder>(mut self, configure: F) -> Self {
+        self.api = configure(self.api);
+        self
+    }
+
+    /// Create the [`Client`]
+    ///
+    /// # Errors
+    /// If the api can not be created, the shard range is empty or goes past the shard count,
+    /// the large threshold is not between 50 and 250 or the event buffer is `0`.
+    pub fn build(self) -> Result<Client<S>, ClientBuildError> {
+        if let Some((shards, shard_count)) = &self.shards {
+            if shards.is_empty() || shards.end > *shard_count {
+                return Err(ClientBuildError::InvalidShards {
+                    shards: shards.clone(),
+                    shard_count: *shard_count,
+                });
+            }
+        }
+        if let Some(large_threshold) = self.large_threshold {
+            if !LARGE_THRESHOLD_RANGE.contains(&large_threshold) {
+                return Err(ClientBuildError::InvalidLargeThreshold(large_threshold));
+            }
+        }
+        if self.event_buffer == 0 {
+            return Err(ClientBuildError::InvalidEventBuffer);
+        }
+
+        let config = ClientConfig {
+            intents: self.intents,
+            shards: self.shards,
+            presence: self.presence,
+            large_threshold: self.large_threshold,
+            gateway_url: self.gateway_url,
+        };
+        let gateways = config
+            .shard_ids()
+            .map(|_| Gateway::with_capacity(self.event_buffer))
+            .collect();
+
+        Ok(Client {
+            api: self.api.build()?,
+            cache: Cache::with_policy(self.cache),
+            state: self.state,
+            token: self.token,
+            gateways,
+            config: Arc::new(config),
+            handlers: Arc::new(Vec::new()),
+            middlewares: Arc::new(Vec::new()),
+        })
+    }
+
+    /// Same as [`build`][Self::build], but also asks discord if the privileged intents are enabled for the bot,
+    /// instead of the gateway closing the connection once the bot is running.
+    ///
+    /// # Errors
+    /// See [`build`][Self::build], and if the privileged intents are not enabled or getting the application failed.
+    pub async fn build_checked(self) -> Result<Client<S>, ClientBuildError> {
+        let client = self.build()?;
+
+        let privileged = client.config.intents & Intents::PRIVILEGED;
+        if !privileged.is_empty() {
+            let application = client
+                .api
+                .get_current_application()
+                .await
+                .map_err(ClientBuildError::Request)?;
+            let allowed = application
+                .flags
+                .map_or_else(Intents::empty, ApplicationFlags::privileged_intents);
+            let missing = privileged - allowed;
+            if !missing.is_empty() {
+                return Err(ClientBuildError::DisallowedIntents(missing));
+            }
+        }
+
+        Ok(client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builder() -> ClientBuilder<()> {
+        Client::builder("TOKEN".to_owned(), ())
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(matches!(
+            builder().shards(2..2, 4).build(),
+            Err(ClientBuildError::InvalidShards { .. })
+        ));
+        assert!(matches!(
+            builder().shards(2..5, 4).build(),
+            Err(ClientBuildError::InvalidShards { .. })
+        ));
+        assert!(matches!(
+            builder().large_threshold(251).build(),
+            Err(ClientBuildError::InvalidLargeThreshold(251))
+        ));
+        assert!(matches!(
+            builder().event_buffer(0).build(),
+            Err(ClientBuildError::InvalidEventBuffer)
+        ));
+        assert!(matches!(
+            builder().base_url("not a url").build(),
+            Err(ClientBuildError::Api(BuildError::InvalidBaseUrl(_)))
+        ));
+
+        let client = builder().shards(2..4, 4).build().unwrap();
+        assert_eq!(client.shards(), 2..4);
+        assert!(client.gateway(1).is_none());
+        assert!(client.gateway(3).is_some());
+        assert_eq!(client.context().shard_id, 2);
+    }
+
+    #[tokio::test]
+    async fn test_build_checked_without_privileged_intents() {
+        // no privileged intents, so the unreachable api is never asked
+        let client = builder()
+            .base_url("http://127.0.0.1:1")
+            .intents(Intents::GUILDS)
+            .build_checked()
+            .await;
+        assert!(client.is_ok());
+
+        let client = builder()
+            .base_url("http://127.0.0.1:1")
+            .intents(Intents::MESSAGE_CONTENT)
+            .build_checked()
+            .await;
+        assert!(matches!(client, Err(ClientBuildError::Request(_))));
+    }
+}
@@ -0,0 +1,106 @@
+//! Context given to event handlers
+
+use crate::api::Error;
+use crate::cache::Cache;
+use crate::datatypes::{EmojiRef, Message, Snowflake};
+use crate::{Api, CreateMessageParams, Gateway};
+
+/// Everything needed to handle a event, given to event handlers, middlewares and frameworks
+///
+/// Cloning is cheap, the api, gateway and cache are shared.
+///
+/// # Example
+/// ```no_run
+/// use vivcord::{Client, Context, EventData};
+///
+/// # tokio_test::block_on(async move {
+/// let intents = vivcord::Intents::GUILD_MESSAGES | vivcord::Intents::MESSAGE_CONTENT;
+/// Client::builder("TOKEN".to_owned(), ())
+///     .intents(intents)
+///     .build()
+///     .unwrap()
+///     .run(|event, ctx: Context<()>| async move {
+///         if let EventData::MessageCreate(msg) = event {
+///             if msg.content == "!ping" {
+///                 ctx.reply(&msg, "Pong!").await.unwrap();
+///             }
+///         }
+///     })
+///     .await;
+/// # });
+/// ```
+#[derive(Debug, Clone)]
+pub struct Context<S> {
+    /// REST api interface
+    pub api: Api,
+    /// Websocket interface of the shard that received the event
+    pub gateway: Gateway,
+    /// Data received from the gateway
+    pub cache: Cache,
+    /// Shard that received the event
+    pub shard_id: u32,
+    /// Holds the global client state
+    pub state: S,
+}
+
+impl<S> Context<S> {
+    /// Send a message in a channel
+    ///
+    /// # Errors
+    /// See [`Api::create_message`]
+    pub async fn say<C, M>(&self, channel_id: C, message: M) -> Result<Message, Error>
+    where
+        C: Into<Snowflake>,
+        M: Into<CreateMessageParams>,
+    {
+        self.api.create_message(channel_id, message.into()).await
+    }
+
+    /// Reply to a message
+    ///
+    /// # Errors
+    /// See [`Api::create_message`]
+    pub async fn reply<M: Into<CreateMessageParams>>(
+        &self,
+        message: &Message,
+        reply: M,
+    ) -> Result<Message, Error> {
+        let reply = CreateMessageParams {
+            message_reference: Some(message.into()),
+            ..reply.into()
+        };
+        self.api.create_message(message.channel_id, reply).await
+    }
+
+    /// React to a message
+    ///
+    /// # Errors
+    /// See [`Api::create_reaction`]
+    pub async fn react<E: Into<EmojiRef>>(&self, message: &Message, emoji: E) -> Result<(), Error> {
+        self.api
+            .create_reaction(message.channel_id, message.id, emoji)
+            .await
+    }
+
+    /// Send a direct message to a user
+    ///
+    /// # Errors
+    /// See [`Api::create_dm`] and [`Api::create_message`],
+    /// discord also refuses when the user does not share a guild with the bot or has dms disabled.
+    pub async fn dm<U, M>(&self, user_id: U, message: M) -> Result<Message, Error>
+    where
+        U: Into<Snowflake>,
+        M: Into<CreateMessageParams>,
+    {
+        let channel = self.api.create_dm(user_id).await?;
+        self.api.create_message(channel.id, message.into()).await
+    }
+
+    /// Show that the bot is typing in a channel
+    ///
+    /// # Errors
+    /// See [`Api::trigger_typing_indicator`]
+    pub async fn typing<C: Into<Snowflake>>(&self, channel_id: C) -> Result<(), Error> {
+        self.api.trigger_typing_indicator(channel_id).await
+    }
+}
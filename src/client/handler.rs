@@ -0,0 +1,332 @@
+//! Trait based event handling
+
+use async_trait::async_trait;
+
+use super::Context;
+use crate::datatypes::{
+    AutoModerationActionExecution, AutoModerationRule, Guild, GuildScheduledEvent, Integration,
+    Interaction, Invite, Message, Snowflake, StageInstance,
+};
+use crate::EventData;
+
+/// Handles events received by a [`Client`][crate::Client], register it with [`Client::event_handler`][crate::Client::event_handler].
+///
+/// Every method does nothing by default, so only the events you care about need to be implemented.
+/// Each event is handled in its own task, so slow handlers do not delay other events.
+/// The handlers of a event run concurrently, after the [`Middleware`][super::Middleware]s.
+///
+/// # Example
+/// ```no_run
+/// use vivcord::async_trait;
+/// use vivcord::client::EventHandler;
+/// use vivcord::datatypes::Message;
+/// use vivcord::{Client, Context};
+///
+/// struct Pong;
+///
+/// #[async_trait]
+/// impl EventHandler<()> for Pong {
+///     async fn message_create(&self, ctx: Context<()>, message: Message) {
+///         if message.content == "!ping" {
+///             if let Err(err) = ctx.reply(&message, "Pong!").await {
+///                 eprintln!("{err}");
+///             }
+///         }
+///     }
+/// }
+///
+/// # tokio_test::block_on(async move {
+/// let intents = vivcord::Intents::GUILD_MESSAGES | vivcord::Intents::MESSAGE_CONTENT;
+/// Client::builder("TOKEN".to_owned(), ())
+///     .intents(intents)
+///     .build()
+///     .unwrap()
+///     .event_handler(Pong)
+///     .start()
+///     .await;
+/// # });
+/// ```
+#[async_trait]
+#[allow(unused_variables)]
+pub trait EventHandler<S>: Send + Sync
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Called with every event before the specific method, including the internal gateway events
+    async fn event(&self, ctx: Context<S>, event: EventData) {}
+
+    /// See [`EventData::Ready`]
+    async fn ready(&self, ctx: Context<S>) {}
+
+    /// See [`EventData::GuildCreate`]
+    async fn guild_create(&self, ctx: Context<S>, guild: Guild) {}
+
+    /// See [`EventData::MessageCreate`]
+    async fn message_create(&self, ctx: Context<S>, message: Message) {}
+
+    /// See [`EventData::MessageUpdate`]
+    async fn message_update(&self, ctx: Context<S>, message: Message) {}
+
+    /// See [`EventData::MessageDelete`]
+    async fn message_delete(
+        &self,
+        ctx: Context<S>,
+        id: Snowflake,
+        channel_id: Snowflake,
+        guild_id: Option<Snowflake>,
+    ) {
+    }
+
+    /// See [`EventData::MessageDeleteBulk`]
+    async fn message_delete_bulk(
+        &self,
+        ctx: Context<S>,
+        ids: Vec<Snowflake>,
+        channel_id: Snowflake,
+        guild_id: Option<Snowflake>,
+    ) {
+    }
+
+    /// See [`EventData::InviteCreate`]
+    async fn invite_create(&self, ctx: Context<S>, invite: Invite) {}
+
+    /// See [`EventData::InviteDelete`]
+    async fn invite_delete(
+        &self,
+        ctx: Context<S>,
+        channel_id: Snowflake,
+        guild_id: Option<Snowflake>,
+        code: String,
+    ) {
+    }
+
+    /// See [`EventData::WebhooksUpdate`]
+    async fn webhooks_update(&self, ctx: Context<S>, guild_id: Snowflake, channel_id: Snowflake) {}
+
+    /// See [`EventData::IntegrationCreate`]
+    async fn integration_create(&self, ctx: Context<S>, integration: Integration) {}
+
+    /// See [`EventData::IntegrationUpdate`]
+    async fn integration_update(&self, ctx: Context<S>, integration: Integration) {}
+
+    /// See [`EventData::IntegrationDelete`]
+    async fn integration_delete(
+        &self,
+        ctx: Context<S>,
+        id: Snowflake,
+        guild_id: Snowflake,
+        application_id: Option<Snowflake>,
+    ) {
+    }
+
+    /// See [`EventData::StageInstanceCreate`]
+    async fn stage_instance_create(&self, ctx: Context<S>, stage: StageInstance) {}
+
+    /// See [`EventData::StageInstanceUpdate`]
+    async fn stage_instance_update(&self, ctx: Context<S>, stage: StageInstance) {}
+
+    /// See [`EventData::StageInstanceDelete`]
+    async fn stage_instance_delete(&self, ctx: Context<S>, stage: StageInstance) {}
+
+    /// See [`EventData::GuildScheduledEventCreate`]
+    async fn guild_scheduled_event_create(&self, ctx: Context<S>, event: GuildScheduledEvent) {}
+
+    /// See [`EventData::GuildScheduledEventUpdate`]
+    async fn guild_scheduled_event_update(&self, ctx: Context<S>, event: GuildScheduledEvent) {}
+
+    /// See [`EventData::GuildScheduledEventDelete`]
+    async fn guild_scheduled_event_delete(&self, ctx: Context<S>, event: GuildScheduledEvent) {}
+
+    /// See [`EventData::GuildScheduledEventUserAdd`]
+    async fn guild_scheduled_event_user_add(
+        &self,
+        ctx: Context<S>,
+        guild_scheduled_event_id: Snowflake,
+        user_id: Snowflake,
+        guild_id: Snowflake,
+    ) {
+    }
+
+    /// See [`EventData::GuildScheduledEventUserRemove`]
+    async fn guild_scheduled_event_user_remove(
+        &self,
+        ctx: Context<S>,
+        guild_scheduled_event_id: Snowflake,
+        user_id: Snowflake,
+        guild_id: Snowflake,
+    ) {
+    }
+
+    /// See [`EventData::AutoModerationRuleCreate`]
+    async fn auto_moderation_rule_create(&self, ctx: Context<S>, rule: AutoModerationRule) {}
+
+    /// See [`EventData::AutoModerationRuleUpdate`]
+    async fn auto_moderation_rule_update(&self, ctx: Context<S>, rule: AutoModerationRule) {}
+
+    /// See [`EventData::AutoModerationRuleDelete`]
+    async fn auto_moderation_rule_delete(&self, ctx: Context<S>, rule: AutoModerationRule) {}
+
+    /// See [`EventData::AutoModerationActionExecution`]
+    async fn auto_moderation_action_execution(
+        &self,
+        ctx: Context<S>,
+        execution: AutoModerationActionExecution,
+    ) {
+    }
+
+    /// See [`EventData::InteractionCreate`]
+    async fn interaction_create(&self, ctx: Context<S>, interaction: Interaction) {}
+}
+
+/// Call the methods of the handler matching the event
+#[allow(clippy::too_many_lines)] // one arm per event
+pub(crate) async fn dispatch<S>(handler: &dyn EventHandler<S>, ctx: Context<S>, event: EventData)
+where
+    S: Clone + Send + Sync + 'static,
+{
+    handler.event(ctx.clone(), event.clone()).await;
+
+    match event {
+        EventData::Hello { .. }
+        | EventData::HearthBeatAck
+        | EventData::HeartbeatRequest
+        | EventData::Unknown => {}
+        EventData::Ready {} => handler.ready(ctx).await,
+        EventData::GuildCreate(guild) => handler.guild_create(ctx, *guild).await,
+        EventData::MessageCreate(message) => handler.message_create(ctx, message).await,
+        EventData::MessageUpdate(message) => handler.message_update(ctx, message).await,
+        EventData::MessageDelete {
+            id,
+            channel_id,
+            guild_id,
+        } => handler.message_delete(ctx, id, channel_id, guild_id).await,
+        EventData::MessageDeleteBulk {
+            ids,
+            channel_id,
+            guild_id,
+        } => {
+            handler
+                .message_delete_bulk(ctx, ids, channel_id, guild_id)
+                .await;
+        }
+        EventData::InviteCreate(invite) => handler.invite_create(ctx, invite).await,
+        EventData::InviteDelete {
+            channel_id,
+            guild_id,
+            code,
+        } => {
+            handler.invite_delete(ctx, channel_id, guild_id, code).await;
+        }
+        EventData::WebhooksUpdate {
+            guild_id,
+            channel_id,
+        } => handler.webhooks_update(ctx, guild_id, channel_id).await,
+        EventData::IntegrationCreate(integration) => {
+            handler.integration_create(ctx, integration).await;
+        }
+        EventData::IntegrationUpdate(integration) => {
+            handler.integration_update(ctx, integration).await;
+        }
+        EventData::IntegrationDelete {
+            id,
+            guild_id,
+            application_id,
+        } => {
+            handler
+                .integration_delete(ctx, id, guild_id, application_id)
+                .await;
+        }
+        EventData::StageInstanceCreate(stage) => handler.stage_instance_create(ctx, stage).await,
+        EventData::StageInstanceUpdate(stage) => handler.stage_instance_update(ctx, stage).await,
+        EventData::StageInstanceDelete(stage) => handler.stage_instance_delete(ctx, stage).await,
+        EventData::GuildScheduledEventCreate(event) => {
+            handler.guild_scheduled_event_create(ctx, event).await;
+        }
+        EventData::GuildScheduledEventUpdate(event) => {
+            handler.guild_scheduled_event_update(ctx, event).await;
+        }
+        EventData::GuildScheduledEventDelete(event) => {
+            handler.guild_scheduled_event_delete(ctx, event).await;
+        }
+        EventData::GuildScheduledEventUserAdd {
+            guild_scheduled_event_id,
+            user_id,
+            guild_id,
+        } => {
+            handler
+                .guild_scheduled_event_user_add(ctx, guild_scheduled_event_id, user_id, guild_id)
+                .await;
+        }
+        EventData::GuildScheduledEventUserRemove {
+            guild_scheduled_event_id,
+            user_id,
+            guild_id,
+        } => {
+            handler
+                .guild_scheduled_event_user_remove(ctx, guild_scheduled_event_id, user_id, guild_id)
+                .await;
+        }
+        EventData::AutoModerationRuleCreate(rule) => {
+            handler.auto_moderation_rule_create(ctx, rule).await;
+        }
+        EventData::AutoModerationRuleUpdate(rule) => {
+            handler.auto_moderation_rule_update(ctx, rule).await;
+        }
+        EventData::AutoModerationRuleDelete(rule) => {
+            handler.auto_moderation_rule_delete(ctx, rule).await;
+        }
+        EventData::AutoModerationActionExecution(execution) => {
+            handler
+                .auto_moderation_action_execution(ctx, execution)
+                .await;
+        }
+        EventData::InteractionCreate(interaction) => {
+            handler.interaction_create(ctx, *interaction).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::Client;
+
+    /// Records the events it gets
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    #[async_trait]
+    impl EventHandler<()> for Recorder {
+        async fn event(&self, _ctx: Context<()>, _event: EventData) {
+            self.0.lock().unwrap().push("event".to_owned());
+        }
+
+        async fn invite_delete(
+            &self,
+            _ctx: Context<()>,
+            _channel_id: Snowflake,
+            _guild_id: Option<Snowflake>,
+            code: String,
+        ) {
+            self.0.lock().unwrap().push(code);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let handler = Recorder(calls.clone());
+        let ctx = Client::new("TOKEN".to_owned(), ()).context();
+
+        let event = EventData::InviteDelete {
+            channel_id: Snowflake(1),
+            guild_id: None,
+            code: "abc".to_owned(),
+        };
+        dispatch(&handler, ctx.clone(), event).await;
+        dispatch(&handler, ctx, EventData::HearthBeatAck).await;
+
+        assert_eq!(*calls.lock().unwrap(), ["event", "abc", "event"]);
+    }
+}
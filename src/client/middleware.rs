@@ -0,0 +1,136 @@
+//! Intercepting events before they reach the handlers
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+
+use super::Context;
+use crate::EventData;
+
+/// Runs the event handlers, at the end of the middleware chain
+pub(crate) type Endpoint<'a> = &'a (dyn Fn(EventData) -> BoxFuture<'static, ()> + Send + Sync);
+
+/// Wraps the dispatch of every event, register it with [`Client::middleware`][crate::Client::middleware].
+///
+/// A middleware can inspect or change the event before passing it on with [`Next::run`],
+/// or drop it by not calling `next` at all.
+/// Middlewares run in the order they were registered, and the event handlers run inside the last one,
+/// so timing `next.run` measures all the middlewares and handlers after it.
+///
+/// # Example
+/// ```
+/// use std::time::Instant;
+/// use vivcord::async_trait;
+/// use vivcord::client::{Middleware, Next};
+/// use vivcord::datatypes::Snowflake;
+/// use vivcord::{Client, Context, EventData};
+///
+/// /// Ignore messages from blocked guilds, and log how long the other events took
+/// struct Blocklist(Vec<Snowflake>);
+///
+/// #[async_trait]
+/// impl Middleware<()> for Blocklist {
+///     async fn handle(&self, _ctx: &Context<()>, event: EventData, next: Next<'_, ()>) {
+///         if let EventData::MessageCreate(message) = &event {
+///             if message.guild_id.is_some_and(|guild| self.0.contains(&guild)) {
+///                 return;
+///             }
+///         }
+///
+///         let start = Instant::now();
+///         next.run(event).await;
+///         println!("handled event in {:?}", start.elapsed());
+///     }
+/// }
+///
+/// let client = Client::new("TOKEN".to_owned(), ()).middleware(Blocklist(vec![Snowflake(1234)]));
+/// ```
+#[async_trait]
+pub trait Middleware<S>: Send + Sync
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Handle the event, call `next.run(event)` to pass it on
+    async fn handle(&self, ctx: &Context<S>, event: EventData, next: Next<'_, S>);
+}
+
+/// The rest of the middleware chain
+#[allow(missing_debug_implementations)]
+pub struct Next<'a, S> {
+    pub(crate) ctx: &'a Context<S>,
+    pub(crate) middlewares: &'a [Arc<dyn Middleware<S>>],
+    pub(crate) endpoint: Endpoint<'a>,
+}
+
+impl<S> Next<'_, S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Pass the event on to the next middleware, or to the handlers if this is the last one
+    pub async fn run(self, event: EventData) {
+        match self.middlewares.split_first() {
+            Some((middleware, rest)) => {
+                let next = Next {
+                    middlewares: rest,
+                    ..self
+                };
+                middleware.handle(self.ctx, event, next).await;
+            }
+            None => (self.endpoint)(event).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::datatypes::Snowflake;
+    use crate::Client;
+
+    /// Drops heartbeat acks and records the rest, tagged with `self.0`
+    struct Record(&'static str, Arc<Mutex<Vec<String>>>);
+
+    #[async_trait]
+    impl Middleware<()> for Record {
+        async fn handle(&self, _ctx: &Context<()>, event: EventData, next: Next<'_, ()>) {
+            if matches!(event, EventData::HearthBeatAck) {
+                return;
+            }
+            self.1.lock().unwrap().push(self.0.to_owned());
+            next.run(event).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chain() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let client = Client::new("TOKEN".to_owned(), ())
+            .middleware(Record("first", calls.clone()))
+            .middleware(Record("second", calls.clone()));
+
+        let endpoint_calls = calls.clone();
+        let endpoint = move |_event: EventData| -> BoxFuture<'static, ()> {
+            endpoint_calls.lock().unwrap().push("handlers".to_owned());
+            Box::pin(async {})
+        };
+        let ctx = client.context();
+        let next = || Next {
+            ctx: &ctx,
+            middlewares: &client.middlewares,
+            endpoint: &endpoint,
+        };
+
+        next().run(EventData::HearthBeatAck).await;
+        assert!(calls.lock().unwrap().is_empty());
+
+        let event = EventData::WebhooksUpdate {
+            guild_id: Snowflake(1),
+            channel_id: Snowflake(2),
+        };
+        next().run(event).await;
+        assert_eq!(*calls.lock().unwrap(), ["first", "second", "handlers"]);
+    }
+}
@@ -0,0 +1,227 @@
+//! The client ties together multiple parts of the crate into one common place.
+
+mod builder;
+mod context;
+mod handler;
+mod middleware;
+
+use futures::future::{join_all, try_join_all, BoxFuture};
+use futures::Future;
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::Duration;
+
+use builder::ClientConfig;
+pub use builder::{ClientBuildError, ClientBuilder};
+pub use context::Context;
+pub use handler::EventHandler;
+pub use middleware::{Middleware, Next};
+
+use crate::cache::Cache;
+use crate::gateway::ConnectOptions;
+use crate::{EventData, Gateway};
+
+/// Time to wait between identifying shards, discord only allows one identify every 5 seconds
+const IDENTIFY_DELAY: Duration = Duration::from_secs(5);
+
+/// The client holds a [`Api`][crate::Api] and a [`Gateway`] per shard
+///
+/// Cloning is cheap, clones share the api, gateways and cache.
+#[allow(missing_debug_implementations)]
+#[derive(Clone)]
+pub struct Client<S> {
+    /// REST api interface
+    pub api: crate::Api,
+
+    /// Data received from the gateway
+    pub cache: Cache,
+
+    /// Holds the global client state
+    pub state: S,
+
+    token: String,
+    gateways: Vec<Gateway>,
+    config: Arc<ClientConfig>,
+    handlers: Arc<Vec<Arc<dyn EventHandler<S>>>>,
+    middlewares: Arc<Vec<Arc<dyn Middleware<S>>>>,
+}
+
+impl<S> Client<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Create a instance of client with the default settings and no intents,
+    /// use [`Client::builder`] to configure it.
+    ///
+    /// # Panics
+    /// If the token is not a valid http header value
+    #[must_use]
+    pub fn new(token: String, state: S) -> Self {
+        Self::builder(token, state).build().expect("Invalid Token")
+    }
+
+    /// Create a [`ClientBuilder`] to configure the client
+    pub fn builder(token: String, state: S) -> ClientBuilder<S> {
+        ClientBuilder::new(token, state)
+    }
+
+    /// Ids of the shards this client runs
+    #[must_use]
+    pub fn shards(&self) -> Range<u32> {
+        self.config.shard_ids()
+    }
+
+    /// Websocket interface of a shard, [`None`] if this client does not run the shard
+    #[must_use]
+    pub fn gateway(&self, shard_id: u32) -> Option<&Gateway> {
+        let index = shard_id.checked_sub(self.shards().start)?;
+        self.gateways.get(usize::try_from(index).ok()?)
+    }
+
+    /// Register a [`EventHandler`], every registered handler receives each event.
+    #[must_use]
+    pub fn event_handler<H: EventHandler<S> + 'static>(mut self, handler: H) -> Self {
+        Arc::make_mut(&mut self.handlers).push(Arc::new(handler));
+        self
+    }
+
+    /// Register a [`Middleware`], events pass through the middlewares in the order they were registered
+    /// before reaching the event callback and handlers.
+    #[must_use]
+    pub fn middleware<M: Middleware<S> + 'static>(mut self, middleware: M) -> Self {
+        Arc::make_mut(&mut self.middlewares).push(Arc::new(middleware));
+        self
+    }
+
+    /// Context for handling events of the first shard, sharing the api, gateway, cache and state with the client
+    #[must_use]
+    pub fn context(&self) -> Context<S> {
+        self.shard_context(self.shards().start, &self.gateways[0])
+    }
+
+    /// Context for handling events of a shard
+    fn shard_context(&self, shard_id: u32, gateway: &Gateway) -> Context<S> {
+        Context {
+            api: self.api.clone(),
+            gateway: gateway.clone(),
+            cache: self.cache.clone(),
+            shard_id,
+            state: self.state.clone(),
+        }
+    }
+
+    /// Run the bot, giving each event to the registered [`EventHandler`]s
+    ///
+    /// # Panics
+    /// panics if bot fails to connect to discord, might happen due to invalid oauth token.
+    pub async fn start(self) {
+        self.run(|_, _| async {}).await;
+    }
+
+    /// Run the bot, giving each event to `event_callback` and the registered [`EventHandler`]s.
+    ///
+    /// The [`Cache`] is updated in the order events are received, before the event is given to the [`Middleware`]s.
+    /// Shards are connected one after the other, waiting 5 seconds between each.
+    ///
+    /// # Panics
+    /// panics if bot fails to connect to discord, might happen due to invalid oauth token.
+    pub async fn run<F, A>(self, event_callback: F)
+    where
+        F: Fn(EventData, Context<S>) -> A + Send + Sync + 'static,
+        A: Future<Output = ()> + Send + 'static,
+    {
+        let event_callback = Arc::new(event_callback);
+        let websocket_url = match &self.config.gateway_url {
+            Some(url) => url.clone(),
+            None => self.api.get_gateway_url().await.unwrap(),
+        };
+
+        let mut shards = Vec::new();
+        for (shard_id, gateway) in self.shards().zip(&self.gateways) {
+            if !shards.is_empty() {
+                tokio::time::sleep(IDENTIFY_DELAY).await;
+            }
+            gateway
+                .connect_with(&websocket_url, &self.token, &self.connect_options(shard_id))
+                .await;
+
+            let context = self.shard_context(shard_id, gateway);
+            let events = self.handle_events(gateway.clone(), context, event_callback.clone());
+            shards.push(tokio::spawn(events));
+        }
+
+        try_join_all(shards).await.unwrap();
+    }
+
+    /// What to identify a shard with
+    fn connect_options(&self, shard_id: u32) -> ConnectOptions {
+        let mut options = ConnectOptions::new(self.config.intents);
+        if let Some((_, shard_count)) = &self.config.shards {
+            options = options.shard(shard_id, *shard_count);
+        }
+        if let Some(presence) = &self.config.presence {
+            options = options.presence(presence.clone());
+        }
+        if let Some(large_threshold) = self.config.large_threshold {
+            options = options.large_threshold(large_threshold);
+        }
+        options
+    }
+
+    /// Give the events of a shard to the callback, middlewares and handlers forever
+    fn handle_events<F, A>(
+        &self,
+        gateway: Gateway,
+        context: Context<S>,
+        event_callback: Arc<F>,
+    ) -> impl Future<Output = ()> + Send + 'static
+    where
+        F: Fn(EventData, Context<S>) -> A + Send + Sync + 'static,
+        A: Future<Output = ()> + Send + 'static,
+    {
+        let handlers = self.handlers.clone();
+        let middlewares = self.middlewares.clone();
+        async move {
+            gateway
+                .on(context, move |event, ctx| {
+                    // updated here instead of in the spawned task, so the cache sees the events in order
+                    ctx.cache.update(&event);
+                    let event_callback = event_callback.clone();
+                    let handlers = handlers.clone();
+                    let middlewares = middlewares.clone();
+                    async move {
+                        let endpoint = |event: EventData| -> BoxFuture<'static, ()> {
+                            let callback = event_callback(event.clone(), ctx.clone());
+                            let handlers = dispatch(handlers.clone(), ctx.clone(), event);
+                            Box::pin(async move {
+                                futures::join!(callback, handlers);
+                            })
+                        };
+                        let next = Next {
+                            ctx: &ctx,
+                            middlewares: &middlewares,
+                            endpoint: &endpoint,
+                        };
+                        next.run(event).await;
+                    }
+                })
+                .await;
+        }
+    }
+}
+
+/// Give the event to every handler, running them concurrently
+async fn dispatch<S>(
+    handlers: Arc<Vec<Arc<dyn EventHandler<S>>>>,
+    ctx: Context<S>,
+    event: EventData,
+) where
+    S: Clone + Send + Sync + 'static,
+{
+    join_all(
+        handlers
+            .iter()
+            .map(|handler| handler::dispatch(&**handler, ctx.clone(), event.clone())),
+    )
+    .await;
+}
@@ -0,0 +1,71 @@
+use bitflags::bitflags;
+use serde::Deserialize;
+
+use super::{Intents, Snowflake, User};
+
+bitflags! {
+    /// Flags of a application, only the flags about gateway intents are included.
+    ///
+    /// Detailed description of what each flag does can be seen in the [discord docs](https://discord.com/developers/docs/resources/application#application-object-application-flags)
+    pub struct ApplicationFlags: u64 {
+        /// Can use [`Intents::GUILD_PRESENCES`] in over 100 guilds
+        const GATEWAY_PRESENCE = 1 << 12;
+        /// Can use [`Intents::GUILD_PRESENCES`] in up to 100 guilds
+        const GATEWAY_PRESENCE_LIMITED = 1 << 13;
+        /// Can use [`Intents::GUILD_MEMBERS`] in over 100 guilds
+        const GATEWAY_GUILD_MEMBERS = 1 << 14;
+        /// Can use [`Intents::GUILD_MEMBERS`] in up to 100 guilds
+        const GATEWAY_GUILD_MEMBERS_LIMITED = 1 << 15;
+        /// Can use [`Intents::MESSAGE_CONTENT`] in over 100 guilds
+        const GATEWAY_MESSAGE_CONTENT = 1 << 18;
+        /// Can use [`Intents::MESSAGE_CONTENT`] in up to 100 guilds
+        const GATEWAY_MESSAGE_CONTENT_LIMITED = 1 << 19;
+    }
+}
+
+impl ApplicationFlags {
+    /// Privileged intents the application is allowed to use
+    #[must_use]
+    pub fn privileged_intents(self) -> Intents {
+        let mut intents = Intents::empty();
+        if self.intersects(Self::GATEWAY_PRESENCE | Self::GATEWAY_PRESENCE_LIMITED) {
+            intents |= Intents::GUILD_PRESENCES;
+        }
+        if self.intersects(Self::GATEWAY_GUILD_MEMBERS | Self::GATEWAY_GUILD_MEMBERS_LIMITED) {
+            intents |= Intents::GUILD_MEMBERS;
+        }
+        if self.intersects(Self::GATEWAY_MESSAGE_CONTENT | Self::GATEWAY_MESSAGE_CONTENT_LIMITED) {
+            intents |= Intents::MESSAGE_CONTENT;
+        }
+        intents
+    }
+}
+
+impl<'de> Deserialize<'de> for ApplicationFlags {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u64::deserialize(deserializer).map(Self::from_bits_truncate)
+    }
+}
+
+/// Partial discord application
+///
+/// Discord only sends a few fields of the application in most places,
+/// such as in [`Invite`][super::Invite] and [`Integration`][super::Integration].
+#[derive(Deserialize, Clone, Debug)]
+pub struct PartialApplication {
+    /// Application id
+    pub id: Snowflake,
+    /// Name of the application
+    pub name: Option<String>,
+    /// [Icon hash](https://discord.com/developers/docs/reference#image-formatting)
+    pub icon: Option<String>,
+    /// Description of the application
+    pub description: Option<String>,
+    /// Bot user associated with this application
+    pub bot: Option<User>,
+    /// Flags of the application, only sent when getting the application directly
+    pub flags: Option<ApplicationFlags>,
+}
+
+to_snowflake_simple!(PartialApplication);
+comp_by_field!(PartialApplication, self.id);
@@ -0,0 +1,646 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{ChannelType, Snowflake};
+use crate::api::Error;
+
+/// Max options of a command or subcommand
+const MAX_OPTIONS: usize = 25;
+/// Max choices of a option
+const MAX_CHOICES: usize = 25;
+/// Max characters in a command or option name
+const MAX_NAME_LENGTH: usize = 32;
+/// Max characters in a command or option description
+const MAX_DESCRIPTION_LENGTH: usize = 100;
+/// Max characters in the name of a choice
+const MAX_CHOICE_NAME_LENGTH: usize = 100;
+
+/// Translations keyed by [locale](https://discord.com/developers/docs/reference#locales), such as `en-US` or `nl`
+pub type Localizations = HashMap<String, String>;
+
+int_enum! {
+    /// Where a application command is shown
+    #[derive(Default)]
+    pub enum ApplicationCommandType: u8 {
+        /// Slash command, shown when a user types `/`
+        #[default]
+        ChatInput = 1,
+        /// Shown when right clicking a user
+        User = 2,
+        /// Shown when right clicking a message
+        Message = 3,
+    }
+}
+
+int_enum! {
+    /// Kind of value a application command option takes
+    pub enum ApplicationCommandOptionType: u8 {
+        /// Subcommand, containing its own options
+        SubCommand = 1,
+        /// Group of subcommands
+        SubCommandGroup = 2,
+        /// Text
+        String = 3,
+        /// Whole number between `-2^53` and `2^53`
+        Integer = 4,
+        /// `true` or `false`
+        Boolean = 5,
+        /// User id
+        User = 6,
+        /// Channel id, includes all channel types and categories
+        Channel = 7,
+        /// Role id
+        Role = 8,
+        /// User or role id
+        Mentionable = 9,
+        /// Decimal number between `-2^53` and `2^53`
+        Number = 10,
+        /// Id of a uploaded [`Attachment`][super::Attachment]
+        Attachment = 11,
+    }
+}
+
+/// Value of a application command option, or of a choice
+///
+/// User, channel, role, mentionable and attachment options are sent as strings containing the id.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum CommandOptionValue {
+    /// [`ApplicationCommandOptionType::Boolean`] value
+    Boolean(bool),
+    /// [`ApplicationCommandOptionType::Integer`] value
+    Integer(i64),
+    /// [`ApplicationCommandOptionType::Number`] value
+    Number(f64),
+    /// String or id value, autocomplete interactions always send the partial input as a string
+    String(String),
+}
+
+impl CommandOptionValue {
+    /// Get the value if it is a string
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Get the value if it is a integer
+    #[must_use]
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Get the value if it is a number, integers are converted
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // discord limits integers to 2^53
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(value) => Some(*value),
+            Self::Integer(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    /// Get the value if it is a boolean
+    #[must_use]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Parse the id of a user, channel, role, mentionable or attachment option
+    #[must_use]
+    pub fn as_snowflake(&self) -> Option<Snowflake> {
+        self.as_str()?.parse().ok().map(Snowflake)
+    }
+}
+
+impl std::fmt::Display for CommandOptionValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Boolean(value) => write!(f, "{value}"),
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Number(value) => write!(f, "{value}"),
+            Self::String(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<&str> for CommandOptionValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for CommandOptionValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<i64> for CommandOptionValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<f64> for CommandOptionValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+/// Predefined value a user can pick for a option, also used for autocomplete results
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct ApplicationCommandOptionChoice {
+    /// Name shown to the user (1-100 characters)
+    pub name: String,
+    /// Translations of the name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<Localizations>,
+    /// Value sent to the bot, strings can be max 100 characters
+    pub value: CommandOptionValue,
+}
+
+impl ApplicationCommandOptionChoice {
+    /// Choice with a name and value
+    pub fn new<N: Into<String>, V: Into<CommandOptionValue>>(name: N, value: V) -> Self {
+        Self {
+            name: name.into(),
+            name_localizations: None,
+            value: value.into(),
+        }
+    }
+}
+
+int_enum! {
+    /// Where a application can be installed
+    pub enum ApplicationIntegrationType: u8 {
+        /// Installed to a guild
+        GuildInstall = 0,
+        /// Installed to a user
+        UserInstall = 1,
+    }
+}
+
+int_enum! {
+    /// Where a command can be used
+    pub enum InteractionContextType: u8 {
+        /// In guilds
+        Guild = 0,
+        /// In the dm with the bot
+        BotDm = 1,
+        /// In group dms and dms other than the bot dm, requires [`ApplicationIntegrationType::UserInstall`]
+        PrivateChannel = 2,
+    }
+}
+
+/// Option of a application command, or a subcommand
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct ApplicationCommandOption {
+    /// Kind of value the option takes
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandOptionType,
+    /// Name of the option (1-32 characters)
+    pub name: String,
+    /// Translations of the name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<Localizations>,
+    /// Description of the option (1-100 characters)
+    pub description: String,
+    /// Translations of the description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<Localizations>,
+    /// Must the option be filled in, required options must come before optional ones
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub required: bool,
+    /// Values the user must pick from (max 25), for string, integer and number options
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub choices: Vec<ApplicationCommandOptionChoice>,
+    /// Options of a subcommand, or subcommands of a subcommand group
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<ApplicationCommandOption>,
+    /// Channel types that can be picked in channel options
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_types: Vec<ChannelType>,
+    /// Min value of integer and number options
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<serde_json::Number>,
+    /// Max value of integer and number options
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<serde_json::Number>,
+    /// Min length of string options (0-6000)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u16>,
+    /// Max length of string options (1-6000)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u16>,
+    /// Are choices suggested by the bot while typing, can not be combined with [`choices`][Self::choices]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub autocomplete: bool,
+}
+
+impl ApplicationCommandOption {
+    /// Optional option without extra settings
+    pub fn new<N: Into<String>, D: Into<String>>(
+        kind: ApplicationCommandOptionType,
+        name: N,
+        description: D,
+    ) -> Self {
+        Self {
+            kind,
+            name: name.into(),
+            name_localizations: None,
+            description: description.into(),
+            description_localizations: None,
+            required: false,
+            choices: Vec::new(),
+            options: Vec::new(),
+            channel_types: Vec::new(),
+            min_value: None,
+            max_value: None,
+            min_length: None,
+            max_length: None,
+            autocomplete: false,
+        }
+    }
+
+    /// Set if the option must be filled in
+    #[must_use]
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Check the option and its sub options against discords limits
+    fn validate(&self) -> Result<(), Error> {
+        validate_name(&self.name, true)?;
+        validate_description(&self.description)?;
+
+        if self.choices.len() > MAX_CHOICES {
+            return Err(Error::ValidationErr(format!(
+                "option {} has {} choices, max is {MAX_CHOICES}",
+                self.name,
+                self.choices.len()
+            )));
+        }
+        for choice in &self.choices {
+            let length = choice.name.chars().count();
+            if length == 0 || length > MAX_CHOICE_NAME_LENGTH {
+                return Err(Error::ValidationErr(format!(
+                    "choice name is {length} characters, must be between 1 and {MAX_CHOICE_NAME_LENGTH}"
+                )));
+            }
+        }
+        if self.autocomplete && !self.choices.is_empty() {
+            return Err(Error::ValidationErr(format!(
+                "option {} can not have both choices and autocomplete",
+                self.name
+            )));
+        }
+        validate_options(&self.options)
+    }
+}
+
+/// Check a list of options, including the order of required options
+fn validate_options(options: &[ApplicationCommandOption]) -> Result<(), Error> {
+    if options.len() > MAX_OPTIONS {
+        return Err(Error::ValidationErr(format!(
+            "command has {} options, max is {MAX_OPTIONS}",
+            options.len()
+        )));
+    }
+    if let Some(index) = options.iter().position(|option| !option.required) {
+        if let Some(option) = options[index..].iter().find(|option| option.required) {
+            return Err(Error::ValidationErr(format!(
+                "required option {} comes after a optional option",
+                option.name
+            )));
+        }
+    }
+    options
+        .iter()
+        .try_for_each(ApplicationCommandOption::validate)
+}
+
+/// Check a command or option name, chat input names must be lowercase without spaces
+fn validate_name(name: &str, chat_input: bool) -> Result<(), Error> {
+    let length = name.chars().count();
+    if length == 0 || length > MAX_NAME_LENGTH {
+        return Err(Error::ValidationErr(format!(
+            "name {name:?} is {length} characters, must be between 1 and {MAX_NAME_LENGTH}"
+        )));
+    }
+    let invalid = |c: char| c.is_whitespace() || c.to_lowercase().ne(std::iter::once(c));
+    if chat_input && name.chars().any(invalid) {
+        return Err(Error::ValidationErr(format!(
+            "name {name:?} must be lowercase and can not contain spaces"
+        )));
+    }
+    Ok(())
+}
+
+/// Check a command or option description
+fn validate_description(description: &str) -> Result<(), Error> {
+    let length = description.chars().count();
+    if length == 0 || length > MAX_DESCRIPTION_LENGTH {
+        return Err(Error::ValidationErr(format!(
+            "description is {length} characters, must be between 1 and {MAX_DESCRIPTION_LENGTH}"
+        )));
+    }
+    Ok(())
+}
+
+/// Application command registered with discord
+#[derive(Deserialize, Clone, Debug)]
+pub struct ApplicationCommand {
+    /// Command id
+    pub id: Snowflake,
+    /// Kind of command
+    #[serde(rename = "type", default)]
+    pub kind: ApplicationCommandType,
+    /// Application the command belongs to
+    pub application_id: Snowflake,
+    /// Guild the command is registered in, missing for global commands
+    pub guild_id: Option<Snowflake>,
+    /// Name of the command
+    pub name: String,
+    /// Translations of the name, only included when requested
+    pub name_localizations: Option<Localizations>,
+    /// Description of chat input commands, empty for user and message commands
+    pub description: String,
+    /// Translations of the description, only included when requested
+    pub description_localizations: Option<Localizations>,
+    /// Options of chat input commands
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandOption>,
+    /// Permissions needed to use the command by default, as a bitset string
+    pub default_member_permissions: Option<String>,
+    /// Is the command age restricted
+    #[serde(default)]
+    pub nsfw: bool,
+    /// Where the command can be installed
+    pub integration_types: Option<Vec<ApplicationIntegrationType>>,
+    /// Where the command can be used
+    pub contexts: Option<Vec<InteractionContextType>>,
+    /// Changes every time the command is updated
+    pub version: Snowflake,
+}
+
+to_snowflake_simple!(ApplicationCommand);
+comp_by_field!(ApplicationCommand, self.id);
+
+/// Fields that can be passed to [`create_global_application_command`][crate::Api::create_global_application_command],
+/// [`create_guild_application_command`][crate::Api::create_guild_application_command] and the bulk overwrite endpoints.
+///
+/// Creating a command with the same name as a existing one updates it.
+///
+/// # Example
+/// ```
+/// # use vivcord::datatypes::{ApplicationCommandOption, ApplicationCommandOptionType, CreateApplicationCommandParams};
+/// let command = CreateApplicationCommandParams {
+///     options: vec![
+///         ApplicationCommandOption::new(ApplicationCommandOptionType::User, "user", "Who to ban").required(true),
+///     ],
+///     default_member_permissions: Some("4".to_owned()),
+///     ..CreateApplicationCommandParams::chat_input("ban", "Ban a user")
+/// };
+/// assert!(command.validate().is_ok());
+/// ```
+#[derive(Serialize, Default, Debug, Clone, PartialEq)]
+pub struct CreateApplicationCommandParams {
+    /// Kind of command, defaults to [`ApplicationCommandType::ChatInput`]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<ApplicationCommandType>,
+    /// Name of the command (1-32 characters), chat input commands must be lowercase
+    pub name: String,
+    /// Translations of the name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<Localizations>,
+    /// Description of chat input commands (1-100 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Translations of the description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<Localizations>,
+    /// Options of chat input commands (max 25)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<ApplicationCommandOption>,
+    /// Permissions needed to use the command by default, as a bitset string. `"0"` disables the command for everyone except admins.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_member_permissions: Option<String>,
+    /// Is the command age restricted
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub nsfw: bool,
+    /// Where the command can be installed, defaults to the settings of the application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration_types: Option<Vec<ApplicationIntegrationType>>,
+    /// Where the command can be used, defaults to everywhere
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contexts: Option<Vec<InteractionContextType>>,
+}
+
+impl CreateApplicationCommandParams {
+    /// Slash command
+    pub fn chat_input<N: Into<String>, D: Into<String>>(name: N, description: D) -> Self {
+        Self {
+            kind: Some(ApplicationCommandType::ChatInput),
+            name: name.into(),
+            description: Some(description.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Command shown when right clicking a user
+    pub fn user<N: Into<String>>(name: N) -> Self {
+        Self {
+            kind: Some(ApplicationCommandType::User),
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Command shown when right clicking a message
+    pub fn message<N: Into<String>>(name: N) -> Self {
+        Self {
+            kind: Some(ApplicationCommandType::Message),
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Check the command against discords limits
+    ///
+    /// # Errors
+    /// [`Error::ValidationErr`] describing the first limit that was exceeded.
+    pub fn validate(&self) -> Result<(), Error> {
+        let chat_input = self.kind.unwrap_or_default() == ApplicationCommandType::ChatInput;
+        validate_name(&self.name, chat_input)?;
+
+        if chat_input {
+            validate_description(self.description.as_deref().unwrap_or_default())?;
+            validate_options(&self.options)
+        } else if self.options.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::ValidationErr(format!(
+                "only chat input commands can have options, {} has {}",
+                self.name,
+                self.options.len()
+            )))
+        }
+    }
+}
+
+/// Fields that can be passed to [`edit_global_application_command`][crate::Api::edit_global_application_command]
+/// and [`edit_guild_application_command`][crate::Api::edit_guild_application_command]
+///
+/// Fields left as [`None`] are not changed.
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct EditApplicationCommandParams {
+    /// New name (1-32 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// New translations of the name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<Localizations>,
+    /// New description (1-100 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// New translations of the description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<Localizations>,
+    /// New options, replacing all existing options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<ApplicationCommandOption>>,
+    /// New default permissions, as a bitset string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_member_permissions: Option<String>,
+    /// Is the command age restricted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nsfw: Option<bool>,
+    /// Where the command can be installed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration_types: Option<Vec<ApplicationIntegrationType>>,
+    /// Where the command can be used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contexts: Option<Vec<InteractionContextType>>,
+}
+
+int_enum! {
+    /// What a [`ApplicationCommandPermission`] applies to
+    pub enum ApplicationCommandPermissionType: u8 {
+        /// Role, the `@everyone` role has the same id as the guild
+        Role = 1,
+        /// User
+        User = 2,
+        /// Channel, all channels are represented by the guild id minus `1`
+        Channel = 3,
+    }
+}
+
+/// Allow or deny using a command for a role, user or channel
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ApplicationCommandPermission {
+    /// Id of the role, user or channel
+    pub id: Snowflake,
+    /// What the id refers to
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandPermissionType,
+    /// `true` to allow, `false` to deny
+    pub permission: bool,
+}
+
+/// Permissions of a command in a guild
+#[derive(Deserialize, Clone, Debug)]
+pub struct GuildApplicationCommandPermissions {
+    /// Id of the command, or of the application when the permissions apply to all its commands
+    pub id: Snowflake,
+    /// Application the command belongs to
+    pub application_id: Snowflake,
+    /// Guild the permissions apply in
+    pub guild_id: Snowflake,
+    /// Permission overwrites (max 100)
+    pub permissions: Vec<ApplicationCommandPermission>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_name() {
+        assert!(validate_name("ban", true).is_ok());
+        assert!(validate_name("Ban", true).is_err());
+        assert!(validate_name("ban user", true).is_err());
+        assert!(validate_name("Ban User", false).is_ok());
+        assert!(validate_name(&"a".repeat(33), false).is_err());
+    }
+
+    #[test]
+    fn test_validate_required_order() {
+        let command = CreateApplicationCommandParams {
+            options: vec![
+                ApplicationCommandOption::new(
+                    ApplicationCommandOptionType::String,
+                    "reason",
+                    "Why",
+                ),
+                ApplicationCommandOption::new(ApplicationCommandOptionType::User, "user", "Who")
+                    .required(true),
+            ],
+            ..CreateApplicationCommandParams::chat_input("ban", "Ban a user")
+        };
+
+        assert!(command.validate().is_err());
+    }
+
+    #[test]
+    fn test_serialize_command() {
+        let command = CreateApplicationCommandParams {
+            options: vec![ApplicationCommandOption {
+                min_value: Some(1.into()),
+                ..ApplicationCommandOption::new(
+                    ApplicationCommandOptionType::Integer,
+                    "sides",
+                    "Sides of the dice",
+                )
+            }],
+            ..CreateApplicationCommandParams::chat_input("roll", "Roll a dice")
+        };
+
+        assert_eq!(
+            serde_json::to_value(&command).unwrap(),
+            serde_json::json!({
+                "type": 1,
+                "name": "roll",
+                "description": "Roll a dice",
+                "options": [{"type": 4, "name": "sides", "description": "Sides of the dice", "min_value": 1}]
+            })
+        );
+    }
+
+    #[test]
+    fn test_deserialize_command() {
+        let command: ApplicationCommand = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "application_id": "2",
+            "name": "ping",
+            "description": "Pong!",
+            "default_member_permissions": null,
+            "version": "3"
+        }))
+        .unwrap();
+
+        assert_eq!(command.kind, ApplicationCommandType::ChatInput);
+        assert!(command.options.is_empty());
+    }
+}
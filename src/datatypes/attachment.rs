@@ -0,0 +1,231 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use reqwest::multipart::Part;
+use serde::Deserialize;
+use tokio::io::AsyncRead;
+use tokio::sync::Mutex;
+use tokio_util::io::ReaderStream;
+
+use super::Snowflake;
+
+/// File attached to a message
+#[derive(Deserialize, Clone, Debug)]
+pub struct Attachment {
+    /// Attachment id
+    pub id: Snowflake,
+    /// Name of the file
+    pub filename: String,
+    /// Description (alt text) of the file
+    pub description: Option<String>,
+    /// [Media type](https://en.wikipedia.org/wiki/Media_type) of the file
+    pub content_type: Option<String>,
+    /// Size of the file in bytes
+    pub size: u64,
+    /// Url to download the file
+    pub url: String,
+    /// Proxied url to download the file
+    pub proxy_url: String,
+    /// Height of images
+    pub height: Option<u32>,
+    /// Width of images
+    pub width: Option<u32>,
+    /// Is the attachment removed after a while, used for ephemeral messages and slash command options
+    #[serde(default)]
+    pub ephemeral: bool,
+}
+
+to_snowflake_simple!(Attachment);
+comp_by_field!(Attachment, self.id);
+
+/// Reader that can be streamed to discord
+type BoxedReader = Box<dyn AsyncRead + Send + Sync + Unpin>;
+
+/// Where the content of a [`AttachmentFile`] comes from
+#[derive(Clone)]
+enum AttachmentSource {
+    /// Data already in memory
+    Bytes(Bytes),
+    /// File on disk, opened when the request is sent
+    Path(PathBuf),
+    /// Reader that is streamed, this can only be sent once
+    Reader(Arc<Mutex<Option<BoxedReader>>>),
+}
+
+/// File to upload with a message
+///
+/// Files read from a path or reader are streamed to discord, without loading them fully in memory.
+///
+/// # Example
+/// ```
+/// # use vivcord::{CreateMessageParams, datatypes::AttachmentFile};
+/// let msg = CreateMessageParams {
+///     files: vec![
+///         AttachmentFile::from_bytes("hello.txt", "hello world")
+///             .description("A friendly greeting"),
+///         AttachmentFile::from_path("./cat.png").spoiler(true),
+///     ],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone)]
+pub struct AttachmentFile {
+    /// Name of the file, including the extension
+    filename: String,
+    /// Description (alt text) of the file
+    description: Option<String>,
+    /// Should the file be hidden until clicked
+    spoiler: bool,
+    /// Content of the file
+    source: AttachmentSource,
+}
+
+impl AttachmentFile {
+    /// File with content already in memory
+    pub fn from_bytes<N: Into<String>, B: Into<Bytes>>(filename: N, data: B) -> Self {
+        Self::new(filename.into(), AttachmentSource::Bytes(data.into()))
+    }
+
+    /// File on disk, the filename is taken from the path
+    ///
+    /// The file is only opened when the request is sent.
+    pub fn from_path<P: Into<PathBuf>>(path: P) -> Self {
+        let path = path.into();
+        let filename = path
+            .file_name()
+            .map_or_else(|| "file".to_owned(), |name| name.to_string_lossy().into_owned());
+
+        Self::new(filename, AttachmentSource::Path(path))
+    }
+
+    /// File streamed from a reader
+    ///
+    /// The reader can only be consumed once, so requests uploading it are never retried.
+    pub fn from_reader<N, R>(filename: N, reader: R) -> Self
+    where
+        N: Into<String>,
+        R: AsyncRead + Send + Sync + Unpin + 'static,
+    {
+        let reader: BoxedReader = Box::new(reader);
+        Self::new(
+            filename.into(),
+            AttachmentSource::Reader(Arc::new(Mutex::new(Some(reader)))),
+        )
+    }
+
+    /// Create the file with default settings
+    fn new(filename: String, source: AttachmentSource) -> Self {
+        Self {
+            filename,
+            description: None,
+            spoiler: false,
+            source,
+        }
+    }
+
+    /// Set the description (alt text) of the file
+    #[must_use]
+    pub fn description<D: Into<String>>(mut self, description: D) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Hide the file until it is clicked
+    #[must_use]
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = spoiler;
+        self
+    }
+
+    /// Filename sent to discord, spoilers are marked by a `SPOILER_` prefix
+    pub(crate) fn upload_filename(&self) -> String {
+        if self.spoiler && !self.filename.starts_with("SPOILER_") {
+            format!("SPOILER_{}", self.filename)
+        } else {
+            self.filename.clone()
+        }
+    }
+
+    /// Can the file be sent more than once, files streamed from a reader can not.
+    pub(crate) fn is_reusable(&self) -> bool {
+        !matches!(self.source, AttachmentSource::Reader(_))
+    }
+
+    /// Description of the file
+    pub(crate) fn upload_description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Create the multipart part holding the content of the file
+    pub(crate) async fn to_part(&self) -> Result<Part, crate::api::Error> {
+        let part = match &self.source {
+            AttachmentSource::Bytes(data) => {
+                Part::stream_with_length(data.clone(), data.len() as u64)
+            }
+            AttachmentSource::Path(path) => {
+                let file = tokio::fs::File::open(path).await?;
+                let length = file.metadata().await?.len();
+                Part::stream_with_length(
+                    reqwest::Body::wrap_stream(ReaderStream::new(file)),
+                    length,
+                )
+            }
+            AttachmentSource::Reader(reader) => {
+                let reader = reader.lock().await.take().ok_or_else(|| {
+                    crate::api::Error::ValidationErr(format!(
+                        "attachment {} was read from a reader that has already been sent",
+                        self.filename
+                    ))
+                })?;
+                Part::stream(reqwest::Body::wrap_stream(ReaderStream::new(reader)))
+            }
+        };
+
+        Ok(part.file_name(self.upload_filename()))
+    }
+}
+
+impl std::fmt::Debug for AttachmentFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let source = match &self.source {
+            AttachmentSource::Bytes(data) => format!("{} bytes", data.len()),
+            AttachmentSource::Path(path) => path.display().to_string(),
+            AttachmentSource::Reader(_) => "reader".to_owned(),
+        };
+
+        f.debug_struct("AttachmentFile")
+            .field("filename", &self.filename)
+            .field("description", &self.description)
+            .field("spoiler", &self.spoiler)
+            .field("source", &source)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filename_from_path() {
+        let file = AttachmentFile::from_path("/tmp/images/cat.png");
+
+        assert_eq!(file.upload_filename(), "cat.png");
+    }
+
+    #[test]
+    fn test_spoiler_filename() {
+        let file = AttachmentFile::from_bytes("cat.png", "meow").spoiler(true);
+
+        assert_eq!(file.upload_filename(), "SPOILER_cat.png");
+    }
+
+    #[tokio::test]
+    async fn test_reader_only_sent_once() {
+        let file = AttachmentFile::from_reader("data.bin", &b"data"[..]);
+
+        assert!(file.to_part().await.is_ok());
+        assert!(file.to_part().await.is_err());
+    }
+}
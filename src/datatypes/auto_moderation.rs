@@ -0,0 +1,216 @@
+use serde::{Deserialize, Serialize};
+
+use super::Snowflake;
+
+int_enum! {
+    /// When a [`AutoModerationRule`] is checked
+    pub enum AutoModerationEventType: u8 {
+        /// When a member sends or edits a message
+        MessageSend = 1,
+        /// When a member edits their profile
+        MemberUpdate = 2,
+    }
+}
+
+int_enum! {
+    /// What kind of content triggers a [`AutoModerationRule`]
+    pub enum AutoModerationTriggerType: u8 {
+        /// Content contains words from [`AutoModerationTriggerMetadata::keyword_filter`]
+        Keyword = 1,
+        /// Content is generic spam
+        Spam = 3,
+        /// Content contains words from discords predefined [`AutoModerationTriggerMetadata::presets`]
+        KeywordPreset = 4,
+        /// Content contains more unique mentions than [`AutoModerationTriggerMetadata::mention_total_limit`]
+        MentionSpam = 5,
+        /// Member profile contains words from [`AutoModerationTriggerMetadata::keyword_filter`]
+        MemberProfile = 6,
+    }
+}
+
+int_enum! {
+    /// Predefined word lists maintained by discord
+    pub enum KeywordPresetType: u8 {
+        /// Swearing or cursing
+        Profanity = 1,
+        /// Sexually explicit language
+        SexualContent = 2,
+        /// Personal insults or hate speech
+        Slurs = 3,
+    }
+}
+
+int_enum! {
+    /// What happens when a [`AutoModerationRule`] is triggered
+    pub enum AutoModerationActionType: u8 {
+        /// Block the message from being sent
+        BlockMessage = 1,
+        /// Log the content to [`AutoModerationActionMetadata::channel_id`]
+        SendAlertMessage = 2,
+        /// Timeout the user for [`AutoModerationActionMetadata::duration_seconds`]
+        Timeout = 3,
+        /// Prevent the member from using text, voice or other interactions
+        BlockMemberInteraction = 4,
+    }
+}
+
+/// Extra data used to decide if a [`AutoModerationRule`] is triggered,
+/// which fields are used depends on the [`AutoModerationTriggerType`].
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct AutoModerationTriggerMetadata {
+    /// Words to match, supports `*` wildcards (max 1000)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keyword_filter: Vec<String>,
+    /// Rust flavored regex patterns to match (max 10)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub regex_patterns: Vec<String>,
+    /// Discord word lists to match
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub presets: Vec<KeywordPresetType>,
+    /// Words that should never trigger the rule
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow_list: Vec<String>,
+    /// Max amount of unique role and user mentions in a message (max 50)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mention_total_limit: Option<u8>,
+    /// Should mention raids be detected automatically
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mention_raid_protection_enabled: Option<bool>,
+}
+
+/// Extra data for a [`AutoModerationAction`], which fields are used depends on the [`AutoModerationActionType`].
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct AutoModerationActionMetadata {
+    /// Channel to log content to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Snowflake>,
+    /// Timeout duration in seconds (max 4 weeks)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_seconds: Option<u32>,
+    /// Message shown to the member when their message is blocked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_message: Option<String>,
+}
+
+/// Action taken when a [`AutoModerationRule`] is triggered
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct AutoModerationAction {
+    /// What to do
+    #[serde(rename = "type")]
+    pub kind: AutoModerationActionType,
+    /// Extra data for the action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<AutoModerationActionMetadata>,
+}
+
+/// Auto moderation rule of a guild
+#[derive(Deserialize, Clone, Debug)]
+pub struct AutoModerationRule {
+    /// Rule id
+    pub id: Snowflake,
+    /// Guild the rule belongs to
+    pub guild_id: Snowflake,
+    /// Name of the rule
+    pub name: String,
+    /// User that created the rule
+    pub creator_id: Snowflake,
+    /// When the rule is checked
+    pub event_type: AutoModerationEventType,
+    /// What kind of content triggers the rule
+    pub trigger_type: AutoModerationTriggerType,
+    /// Extra data used to decide if the rule is triggered
+    pub trigger_metadata: AutoModerationTriggerMetadata,
+    /// What happens when the rule is triggered
+    pub actions: Vec<AutoModerationAction>,
+    /// Is the rule enabled
+    pub enabled: bool,
+    /// Roles that are not affected by the rule
+    pub exempt_roles: Vec<Snowflake>,
+    /// Channels that are not affected by the rule
+    pub exempt_channels: Vec<Snowflake>,
+}
+
+to_snowflake_simple!(AutoModerationRule);
+comp_by_field!(AutoModerationRule, self.id);
+
+/// Sent in [`AutoModerationActionExecution`][crate::EventData::AutoModerationActionExecution] events
+/// when a rule is triggered and a action is executed.
+#[derive(Deserialize, Clone, Debug)]
+pub struct AutoModerationActionExecution {
+    /// Guild where the action was executed
+    pub guild_id: Snowflake,
+    /// The action that was executed
+    pub action: AutoModerationAction,
+    /// Rule that was triggered
+    pub rule_id: Snowflake,
+    /// Trigger type of the rule
+    pub rule_trigger_type: AutoModerationTriggerType,
+    /// User that triggered the rule
+    pub user_id: Snowflake,
+    /// Channel the content was posted in
+    pub channel_id: Option<Snowflake>,
+    /// Message that triggered the rule, missing if the message was blocked
+    pub message_id: Option<Snowflake>,
+    /// Alert message sent by [`AutoModerationActionType::SendAlertMessage`] actions
+    pub alert_system_message_id: Option<Snowflake>,
+    /// Content that triggered the rule, requires [`Intents::MESSAGE_CONTENT`][crate::Intents::MESSAGE_CONTENT]
+    #[serde(default)]
+    pub content: String,
+    /// Keyword or pattern that matched
+    pub matched_keyword: Option<String>,
+    /// Part of the content that matched, requires [`Intents::MESSAGE_CONTENT`][crate::Intents::MESSAGE_CONTENT]
+    pub matched_content: Option<String>,
+}
+
+/// Fields that can be passed to [`create_auto_moderation_rule`][crate::Api::create_auto_moderation_rule]
+#[derive(Serialize, Debug, Clone)]
+pub struct CreateAutoModerationRuleParams {
+    /// Name of the rule
+    pub name: String,
+    /// When the rule is checked
+    pub event_type: AutoModerationEventType,
+    /// What kind of content triggers the rule
+    pub trigger_type: AutoModerationTriggerType,
+    /// Extra data used to decide if the rule is triggered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_metadata: Option<AutoModerationTriggerMetadata>,
+    /// What happens when the rule is triggered
+    pub actions: Vec<AutoModerationAction>,
+    /// Is the rule enabled, defaults to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Roles that are not affected by the rule (max 20)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub exempt_roles: Vec<Snowflake>,
+    /// Channels that are not affected by the rule (max 50)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub exempt_channels: Vec<Snowflake>,
+}
+
+/// Fields that can be passed to [`modify_auto_moderation_rule`][crate::Api::modify_auto_moderation_rule]
+///
+/// Fields left as [`None`] are not changed.
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct ModifyAutoModerationRuleParams {
+    /// Name of the rule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// When the rule is checked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<AutoModerationEventType>,
+    /// Extra data used to decide if the rule is triggered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_metadata: Option<AutoModerationTriggerMetadata>,
+    /// What happens when the rule is triggered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<AutoModerationAction>>,
+    /// Is the rule enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Roles that are not affected by the rule (max 20)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exempt_roles: Option<Vec<Snowflake>>,
+    /// Channels that are not affected by the rule (max 50)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exempt_channels: Option<Vec<Snowflake>>,
+}
@@ -0,0 +1,57 @@
+use serde::Deserialize;
+
+use super::Snowflake;
+
+int_enum! {
+    /// Kind of channel
+    pub enum ChannelType: u8 {
+        /// Text channel in a guild
+        GuildText = 0,
+        /// Direct message between users
+        Dm = 1,
+        /// Voice channel in a guild
+        GuildVoice = 2,
+        /// Direct message between multiple users
+        GroupDm = 3,
+        /// Category containing up to 50 channels
+        GuildCategory = 4,
+        /// Channel users can follow and crosspost into their own guild
+        GuildAnnouncement = 5,
+        /// Thread in a [`ChannelType::GuildAnnouncement`] channel
+        AnnouncementThread = 10,
+        /// Public thread in a [`ChannelType::GuildText`] or [`ChannelType::GuildForum`] channel
+        PublicThread = 11,
+        /// Private thread in a [`ChannelType::GuildText`] channel
+        PrivateThread = 12,
+        /// Voice channel for hosting events with an audience
+        GuildStageVoice = 13,
+        /// Channel in a student hub containing the listed servers
+        GuildDirectory = 14,
+        /// Channel that can only contain threads
+        GuildForum = 15,
+        /// Channel that can only contain threads, shown in a gallery
+        GuildMedia = 16,
+    }
+}
+
+/// Partial channel, as sent in [`ResolvedData`][super::ResolvedData] and [`Guild`][super::Guild]
+#[derive(Deserialize, Clone, Debug)]
+pub struct PartialChannel {
+    /// Channel id
+    pub id: Snowflake,
+    /// Kind of channel
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    /// Name of the channel, missing for dms
+    pub name: Option<String>,
+    /// Category or parent channel of a thread
+    pub parent_id: Option<Snowflake>,
+    /// Is the channel age restricted
+    #[serde(default)]
+    pub nsfw: bool,
+    /// Permissions of the user in the channel, as a bitset string
+    pub permissions: Option<String>,
+}
+
+to_snowflake_simple!(PartialChannel);
+comp_by_field!(PartialChannel, self.id);
@@ -0,0 +1,870 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{ChannelType, PartialEmoji, Snowflake};
+use crate::api::Error;
+
+/// Max action rows in a message
+const MAX_ACTION_ROWS: usize = 5;
+/// Max buttons in a action row
+const MAX_BUTTONS_PER_ROW: usize = 5;
+/// Max characters in a custom id
+const MAX_CUSTOM_ID_LENGTH: usize = 100;
+/// Max characters in a button label
+const MAX_BUTTON_LABEL_LENGTH: usize = 80;
+/// Max options in a string select, and max values that can be selected
+const MAX_SELECT_OPTIONS: usize = 25;
+/// Max characters in the label, value and description of a select option
+const MAX_SELECT_OPTION_LENGTH: usize = 100;
+/// Max characters in a select placeholder
+const MAX_SELECT_PLACEHOLDER_LENGTH: usize = 150;
+/// Max characters in a text input label
+const MAX_TEXT_INPUT_LABEL_LENGTH: usize = 45;
+/// Max characters in a text input value
+const MAX_TEXT_INPUT_LENGTH: usize = 4000;
+/// Max characters in a text input placeholder
+const MAX_TEXT_INPUT_PLACEHOLDER_LENGTH: usize = 100;
+
+int_enum! {
+    /// Kind of [`Component`]
+    pub enum ComponentType: u8 {
+        /// [`ActionRow`] containing other components
+        ActionRow = 1,
+        /// [`Button`]
+        Button = 2,
+        /// [`SelectMenu`] with options chosen by the bot
+        StringSelect = 3,
+        /// [`TextInput`] in a modal
+        TextInput = 4,
+        /// [`SelectMenu`] of users
+        UserSelect = 5,
+        /// [`SelectMenu`] of roles
+        RoleSelect = 6,
+        /// [`SelectMenu`] of users and roles
+        MentionableSelect = 7,
+        /// [`SelectMenu`] of channels
+        ChannelSelect = 8,
+    }
+}
+
+impl ComponentType {
+    /// Is this one of the select menu types
+    #[must_use]
+    pub fn is_select(self) -> bool {
+        matches!(
+            self,
+            Self::StringSelect
+                | Self::UserSelect
+                | Self::RoleSelect
+                | Self::MentionableSelect
+                | Self::ChannelSelect
+        )
+    }
+}
+
+/// Interactive component of a message or modal
+///
+/// Messages and modals contain [`ActionRow`]s at the top level, which contain the other components.
+///
+/// # Example
+/// ```
+/// # use vivcord::{CreateMessageParams, datatypes::{ActionRow, Button, ButtonStyle}};
+/// let msg = CreateMessageParams {
+///     content: Some("Are you sure?".to_owned()),
+///     components: vec![ActionRow::new([
+///         Button::new(ButtonStyle::Danger, "confirm", "Yes"),
+///         Button::new(ButtonStyle::Secondary, "cancel", "No"),
+///     ])
+///     .into()],
+///     ..Default::default()
+/// };
+/// assert!(msg.validate().is_ok());
+/// ```
+#[derive(Clone, Debug)]
+pub enum Component {
+    /// Row containing other components
+    ActionRow(ActionRow),
+    /// Clickable button
+    Button(Button),
+    /// Dropdown menu, see [`SelectMenu::kind`] for what can be selected
+    SelectMenu(SelectMenu),
+    /// Text field in a modal
+    TextInput(TextInput),
+    /// Component not known by `vivcord`
+    Unknown(serde_json::Value),
+}
+
+impl Component {
+    /// Kind of the component
+    #[must_use]
+    pub fn kind(&self) -> ComponentType {
+        match self {
+            Self::ActionRow(_) => ComponentType::ActionRow,
+            Self::Button(_) => ComponentType::Button,
+            Self::SelectMenu(select) => select.kind,
+            Self::TextInput(_) => ComponentType::TextInput,
+            Self::Unknown(value) => value
+                .get("type")
+                .and_then(serde_json::Value::as_u64)
+                .and_then(|kind| u8::try_from(kind).ok())
+                .map_or(ComponentType::Unknown(0), ComponentType::from),
+        }
+    }
+
+    /// Custom id of the component, action rows and link buttons have none
+    #[must_use]
+    pub fn custom_id(&self) -> Option<&str> {
+        match self {
+            Self::Button(button) => button.custom_id.as_deref(),
+            Self::SelectMenu(select) => Some(&select.custom_id),
+            Self::TextInput(input) => Some(&input.custom_id),
+            Self::ActionRow(_) | Self::Unknown(_) => None,
+        }
+    }
+
+    /// Check the component against discords limits
+    ///
+    /// # Errors
+    /// [`Error::ValidationErr`] describing the first limit that was exceeded.
+    pub fn validate(&self) -> Result<(), Error> {
+        if let Some(custom_id) = self.custom_id() {
+            check_length("custom id", custom_id, 1, MAX_CUSTOM_ID_LENGTH)?;
+        }
+
+        match self {
+            Self::ActionRow(row) => row.validate(),
+            Self::Button(button) => button.validate(),
+            Self::SelectMenu(select) => select.validate(),
+            Self::TextInput(input) => input.validate(),
+            Self::Unknown(_) => Ok(()),
+        }
+    }
+
+    /// Check top level components of a message.
+    ///
+    /// There can be at most 5 rows, custom ids must be unique and text inputs are not allowed.
+    ///
+    /// # Errors
+    /// [`Error::ValidationErr`] describing the first limit that was exceeded.
+    pub fn validate_rows(rows: &[Component]) -> Result<(), Error> {
+        Self::validate_rows_in(rows, false)
+    }
+
+    /// Check top level components of a modal.
+    ///
+    /// There can be at most 5 rows, and custom ids must be unique.
+    ///
+    /// # Errors
+    /// [`Error::ValidationErr`] describing the first limit that was exceeded.
+    pub fn validate_modal_rows(rows: &[Component]) -> Result<(), Error> {
+        Self::validate_rows_in(rows, true)
+    }
+
+    /// Check top level components, text inputs are only allowed in modals
+    fn validate_rows_in(rows: &[Component], modal: bool) -> Result<(), Error> {
+        if rows.len() > MAX_ACTION_ROWS {
+            return Err(Error::ValidationErr(format!(
+                "message has {} action rows, max is {MAX_ACTION_ROWS}",
+                rows.len()
+            )));
+        }
+
+        let mut custom_ids = std::collections::HashSet::new();
+        for row in rows {
+            let Component::ActionRow(inner) = row else {
+                return Err(Error::ValidationErr(format!(
+                    "top level components must be action rows, got {:?}",
+                    row.kind()
+                )));
+            };
+            row.validate()?;
+            let text_input = |component: &Component| matches!(component, Component::TextInput(_));
+            if !modal && inner.components.iter().any(text_input) {
+                return Err(Error::ValidationErr(
+                    "text inputs can only be used in modals".to_owned(),
+                ));
+            }
+
+            for custom_id in inner.components.iter().filter_map(Component::custom_id) {
+                if !custom_ids.insert(custom_id) {
+                    return Err(Error::ValidationErr(format!(
+                        "custom id {custom_id:?} is used more than once"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error if `value` is not between `min` and `max` characters
+fn check_length(name: &str, value: &str, min: usize, max: usize) -> Result<(), Error> {
+    let length = value.chars().count();
+    if length < min || length > max {
+        Err(Error::ValidationErr(format!(
+            "{name} is {length} characters, must be between {min} and {max}"
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+impl Serialize for Component {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = match self {
+            Self::ActionRow(row) => serde_json::to_value(row),
+            Self::Button(button) => serde_json::to_value(button),
+            Self::SelectMenu(select) => serde_json::to_value(select),
+            Self::TextInput(input) => serde_json::to_value(input),
+            Self::Unknown(value) => return value.serialize(serializer),
+        };
+        let mut value = value.map_err(serde::ser::Error::custom)?;
+
+        if let serde_json::Value::Object(fields) = &mut value {
+            fields.insert("type".to_owned(), u8::from(self.kind()).into());
+        }
+        value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let kind = value
+            .get("type")
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|kind| u8::try_from(kind).ok())
+            .map(ComponentType::from)
+            .ok_or_else(|| serde::de::Error::missing_field("type"))?;
+
+        let component = match kind {
+            ComponentType::ActionRow => serde_json::from_value(value).map(Self::ActionRow),
+            ComponentType::Button => serde_json::from_value(value).map(Self::Button),
+            ComponentType::TextInput => serde_json::from_value(value).map(Self::TextInput),
+            kind if kind.is_select() => serde_json::from_value(value).map(Self::SelectMenu),
+            _ => Ok(Self::Unknown(value)),
+        };
+        component.map_err(serde::de::Error::custom)
+    }
+}
+
+/// Row of components
+///
+/// Contains either up to 5 [`Button`]s, a single [`SelectMenu`] or a single [`TextInput`].
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct ActionRow {
+    /// Components in the row
+    pub components: Vec<Component>,
+}
+
+impl ActionRow {
+    /// Row containing `components`
+    pub fn new<I, C>(components: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Component>,
+    {
+        Self {
+            components: components.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Check the row against discords limits
+    fn validate(&self) -> Result<(), Error> {
+        let error = |reason: String| Err(Error::ValidationErr(reason));
+
+        if self.components.is_empty() {
+            return error("action row is empty".to_owned());
+        }
+        let buttons = self
+            .components
+            .iter()
+            .filter(|component| matches!(component, Component::Button(_)))
+            .count();
+        if buttons == 0 && self.components.len() > 1 {
+            return error(format!(
+                "action row with a select menu or text input must contain only that, got {} components",
+                self.components.len()
+            ));
+        }
+        if buttons != 0 && buttons != self.components.len() {
+            return error(
+                "buttons can not be mixed with other components in a action row".to_owned(),
+            );
+        }
+        if buttons > MAX_BUTTONS_PER_ROW {
+            return error(format!(
+                "action row has {buttons} buttons, max is {MAX_BUTTONS_PER_ROW}"
+            ));
+        }
+
+        for component in &self.components {
+            if let Component::ActionRow(_) = component {
+                return error("action rows can not contain other action rows".to_owned());
+            }
+            component.validate()?;
+        }
+        Ok(())
+    }
+}
+
+impl From<ActionRow> for Component {
+    fn from(value: ActionRow) -> Self {
+        Self::ActionRow(value)
+    }
+}
+
+int_enum! {
+    /// Look and behavior of a [`Button`]
+    pub enum ButtonStyle: u8 {
+        /// Blurple button
+        Primary = 1,
+        /// Grey button
+        Secondary = 2,
+        /// Green button
+        Success = 3,
+        /// Red button
+        Danger = 4,
+        /// Grey button navigating to [`Button::url`], does not send a interaction
+        Link = 5,
+        /// Button to purchase [`Button::sku_id`], does not send a interaction
+        Premium = 6,
+    }
+}
+
+/// Clickable button
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct Button {
+    /// Look and behavior of the button
+    pub style: ButtonStyle,
+    /// Text on the button (max 80 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Emoji shown on the button
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<PartialEmoji>,
+    /// Id sent with the interaction, required for all styles except link and premium buttons
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_id: Option<String>,
+    /// Url of a [`ButtonStyle::Link`] button
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Sku of a [`ButtonStyle::Premium`] button
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sku_id: Option<Snowflake>,
+    /// Is the button greyed out
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+}
+
+impl Button {
+    /// Button sending a interaction with `custom_id` when clicked
+    pub fn new<I: Into<String>, L: Into<String>>(
+        style: ButtonStyle,
+        custom_id: I,
+        label: L,
+    ) -> Self {
+        Self {
+            style,
+            label: Some(label.into()),
+            emoji: None,
+            custom_id: Some(custom_id.into()),
+            url: None,
+            sku_id: None,
+            disabled: false,
+        }
+    }
+
+    /// Button opening `url` when clicked
+    pub fn link<U: Into<String>, L: Into<String>>(url: U, label: L) -> Self {
+        Self {
+            style: ButtonStyle::Link,
+            label: Some(label.into()),
+            emoji: None,
+            custom_id: None,
+            url: Some(url.into()),
+            sku_id: None,
+            disabled: false,
+        }
+    }
+
+    /// Button for purchasing a sku, this can not have a label or emoji
+    #[must_use]
+    pub fn premium(sku_id: Snowflake) -> Self {
+        Self {
+            style: ButtonStyle::Premium,
+            label: None,
+            emoji: None,
+            custom_id: None,
+            url: None,
+            sku_id: Some(sku_id),
+            disabled: false,
+        }
+    }
+
+    /// Show a emoji on the button
+    #[must_use]
+    pub fn emoji<E: Into<super::EmojiRef>>(mut self, emoji: E) -> Self {
+        self.emoji = Some(PartialEmoji::from(emoji.into()));
+        self
+    }
+
+    /// Grey out the button
+    #[must_use]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Check the button against discords limits
+    fn validate(&self) -> Result<(), Error> {
+        let error = |reason: &str| Err(Error::ValidationErr(reason.to_owned()));
+
+        if let Some(label) = &self.label {
+            check_length("button label", label, 0, MAX_BUTTON_LABEL_LENGTH)?;
+        }
+        match self.style {
+            ButtonStyle::Link if self.url.is_none() || self.custom_id.is_some() => {
+                error("link buttons must have a url and no custom id")
+            }
+            ButtonStyle::Premium
+                if self.sku_id.is_none()
+                    || self.custom_id.is_some()
+                    || self.label.is_some()
+                    || self.emoji.is_some() =>
+            {
+                error("premium buttons must have a sku id and no custom id, label or emoji")
+            }
+            ButtonStyle::Link | ButtonStyle::Premium => Ok(()),
+            _ if self.custom_id.is_none() || self.url.is_some() => {
+                error("non link buttons must have a custom id and no url")
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl From<Button> for Component {
+    fn from(value: Button) -> Self {
+        Self::Button(value)
+    }
+}
+
+/// Option of a [`ComponentType::StringSelect`] menu
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SelectOption {
+    /// Text shown to the user (max 100 characters)
+    pub label: String,
+    /// Value sent with the interaction (max 100 characters)
+    pub value: String,
+    /// Extra text shown below the label (max 100 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Emoji shown next to the label
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<PartialEmoji>,
+    /// Is this option selected by default
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub default: bool,
+}
+
+impl SelectOption {
+    /// Option with a label and value
+    pub fn new<L: Into<String>, V: Into<String>>(label: L, value: V) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+            description: None,
+            emoji: None,
+            default: false,
+        }
+    }
+
+    /// Set the description shown below the label
+    #[must_use]
+    pub fn description<D: Into<String>>(mut self, description: D) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// Kind of value in [`SelectMenu::default_values`]
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SelectDefaultValueType {
+    /// Value is a user id
+    User,
+    /// Value is a role id
+    Role,
+    /// Value is a channel id
+    Channel,
+}
+
+/// Value selected by default in user, role, mentionable and channel selects
+#[derive(Deserialize, Serialize, Clone, Copy, Debug)]
+pub struct SelectDefaultValue {
+    /// Id of the user, role or channel
+    pub id: Snowflake,
+    /// What the id refers to
+    #[serde(rename = "type")]
+    pub kind: SelectDefaultValueType,
+}
+
+/// Dropdown menu
+///
+/// [`options`][Self::options] are only used by [`ComponentType::StringSelect`] menus,
+/// the other kinds are filled in by discord.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SelectMenu {
+    /// Kind of select menu, one of the `*Select` [`ComponentType`]s
+    #[serde(rename = "type", skip_serializing)]
+    pub kind: ComponentType,
+    /// Id sent with the interaction (max 100 characters)
+    pub custom_id: String,
+    /// Options to choose from (max 25)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<SelectOption>,
+    /// Channel types that can be selected in a [`ComponentType::ChannelSelect`] menu
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_types: Vec<ChannelType>,
+    /// Text shown when nothing is selected (max 150 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+    /// Users, roles or channels selected by default
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub default_values: Vec<SelectDefaultValue>,
+    /// Min amount of values to select (0-25), defaults to `1`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_values: Option<u8>,
+    /// Max amount of values to select (1-25), defaults to `1`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_values: Option<u8>,
+    /// Is the menu greyed out
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+}
+
+impl SelectMenu {
+    /// Select menu of `kind`, which should be one of the `*Select` [`ComponentType`]s
+    pub fn new<I: Into<String>>(kind: ComponentType, custom_id: I) -> Self {
+        Self {
+            kind,
+            custom_id: custom_id.into(),
+            options: Vec::new(),
+            channel_types: Vec::new(),
+            placeholder: None,
+            default_values: Vec::new(),
+            min_values: None,
+            max_values: None,
+            disabled: false,
+        }
+    }
+
+    /// Select menu with options chosen by the bot
+    pub fn string<I: Into<String>>(custom_id: I, options: Vec<SelectOption>) -> Self {
+        Self {
+            options,
+            ..Self::new(ComponentType::StringSelect, custom_id)
+        }
+    }
+
+    /// Set the text shown when nothing is selected
+    #[must_use]
+    pub fn placeholder<P: Into<String>>(mut self, placeholder: P) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set how many values can be selected
+    #[must_use]
+    pub fn values(mut self, min: u8, max: u8) -> Self {
+        self.min_values = Some(min);
+        self.max_values = Some(max);
+        self
+    }
+
+    /// Check the select menu against discords limits
+    fn validate(&self) -> Result<(), Error> {
+        let error = |reason: String| Err(Error::ValidationErr(reason));
+
+        if !self.kind.is_select() {
+            return error(format!("{:?} is not a select menu type", self.kind));
+        }
+        if self.kind == ComponentType::StringSelect {
+            if self.options.is_empty() || self.options.len() > MAX_SELECT_OPTIONS {
+                return error(format!(
+                    "select menu has {} options, must be between 1 and {MAX_SELECT_OPTIONS}",
+                    self.options.len()
+                ));
+            }
+            for option in &self.options {
+                check_length("option label", &option.label, 1, MAX_SELECT_OPTION_LENGTH)?;
+                check_length("option value", &option.value, 1, MAX_SELECT_OPTION_LENGTH)?;
+                if let Some(description) = &option.description {
+                    check_length(
+                        "option description",
+                        description,
+                        0,
+                        MAX_SELECT_OPTION_LENGTH,
+                    )?;
+                }
+            }
+        } else if !self.options.is_empty() {
+            return error(format!("{:?} menus can not have options", self.kind));
+        }
+        if let Some(placeholder) = &self.placeholder {
+            check_length("placeholder", placeholder, 0, MAX_SELECT_PLACEHOLDER_LENGTH)?;
+        }
+
+        let min = usize::from(self.min_values.unwrap_or(1));
+        let max = usize::from(self.max_values.unwrap_or(1));
+        if min > MAX_SELECT_OPTIONS || max == 0 || max > MAX_SELECT_OPTIONS || min > max {
+            return error(format!(
+                "select menu allows {min} to {max} values, must be between 0 and {MAX_SELECT_OPTIONS} with min <= max"
+            ));
+        }
+        if self.kind == ComponentType::StringSelect && max > self.options.len() {
+            return error(format!(
+                "select menu allows {max} values, but only has {} options",
+                self.options.len()
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl From<SelectMenu> for Component {
+    fn from(value: SelectMenu) -> Self {
+        Self::SelectMenu(value)
+    }
+}
+
+int_enum! {
+    /// Size of a [`TextInput`]
+    #[derive(Default)]
+    pub enum TextInputStyle: u8 {
+        /// Single line
+        #[default]
+        Short = 1,
+        /// Multiple lines
+        Paragraph = 2,
+    }
+}
+
+/// Text field in a modal
+///
+/// When received in a modal submit only [`custom_id`][Self::custom_id] and [`value`][Self::value] are set.
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct TextInput {
+    /// Id sent with the modal submit (max 100 characters)
+    pub custom_id: String,
+    /// Size of the text field
+    #[serde(default)]
+    pub style: TextInputStyle,
+    /// Text shown above the field (max 45 characters)
+    #[serde(default)]
+    pub label: String,
+    /// Min amount of characters the user must enter (0-4000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u16>,
+    /// Max amount of characters the user can enter (1-4000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u16>,
+    /// Must the field be filled in, defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+    /// Prefilled text, or the text entered by the user in a modal submit (max 4000 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Text shown when the field is empty (max 100 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+}
+
+impl TextInput {
+    /// Text field with a label
+    pub fn new<I: Into<String>, L: Into<String>>(
+        style: TextInputStyle,
+        custom_id: I,
+        label: L,
+    ) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            style,
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the min amount of characters (0-4000)
+    #[must_use]
+    pub fn min_length(mut self, min_length: u16) -> Self {
+        self.min_length = Some(min_length);
+        self
+    }
+
+    /// Set the max amount of characters (1-4000)
+    #[must_use]
+    pub fn max_length(mut self, max_length: u16) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Set if the field must be filled in
+    #[must_use]
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+
+    /// Prefill the field
+    #[must_use]
+    pub fn value<V: Into<String>>(mut self, value: V) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Set the text shown when the field is empty
+    #[must_use]
+    pub fn placeholder<P: Into<String>>(mut self, placeholder: P) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Check the text input against discords limits
+    fn validate(&self) -> Result<(), Error> {
+        check_length(
+            "text input label",
+            &self.label,
+            1,
+            MAX_TEXT_INPUT_LABEL_LENGTH,
+        )?;
+        if let Some(value) = &self.value {
+            check_length("text input value", value, 0, MAX_TEXT_INPUT_LENGTH)?;
+        }
+        if let Some(placeholder) = &self.placeholder {
+            check_length(
+                "text input placeholder",
+                placeholder,
+                0,
+                MAX_TEXT_INPUT_PLACEHOLDER_LENGTH,
+            )?;
+        }
+
+        let min = self.min_length.unwrap_or(0);
+        let max = self.max_length.unwrap_or(4000);
+        if usize::from(max) > MAX_TEXT_INPUT_LENGTH || max == 0 || min > max {
+            return Err(Error::ValidationErr(format!(
+                "text input allows {min} to {max} characters, must be between 0 and {MAX_TEXT_INPUT_LENGTH} with min <= max"
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl From<TextInput> for Component {
+    fn from(value: TextInput) -> Self {
+        Self::TextInput(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_row() {
+        let row: Component = ActionRow::new([Button::link("https://example.com", "Open")]).into();
+
+        assert_eq!(
+            serde_json::to_value(&row).unwrap(),
+            serde_json::json!({
+                "type": 1,
+                "components": [{"type": 2, "style": 5, "label": "Open", "url": "https://example.com"}]
+            })
+        );
+    }
+
+    #[test]
+    fn test_deserialize_select() {
+        let component: Component = serde_json::from_value(serde_json::json!({
+            "type": 8,
+            "custom_id": "channel",
+            "channel_types": [0, 15],
+        }))
+        .unwrap();
+
+        let Component::SelectMenu(select) = component else {
+            panic!("expected select menu");
+        };
+        assert_eq!(select.kind, ComponentType::ChannelSelect);
+        assert_eq!(
+            select.channel_types,
+            [ChannelType::GuildText, ChannelType::GuildForum]
+        );
+    }
+
+    #[test]
+    fn test_deserialize_unknown() {
+        let component: Component =
+            serde_json::from_value(serde_json::json!({"type": 99, "thing": true})).unwrap();
+
+        assert_eq!(component.kind(), ComponentType::Unknown(99));
+        assert_eq!(
+            serde_json::to_value(&component).unwrap(),
+            serde_json::json!({"type": 99, "thing": true})
+        );
+    }
+
+    #[test]
+    fn test_validate_custom_id_length() {
+        let rows = [
+            ActionRow::new([Button::new(ButtonStyle::Primary, "a".repeat(101), "Click")]).into(),
+        ];
+
+        assert!(Component::validate_rows(&rows).is_err());
+    }
+
+    #[test]
+    fn test_validate_text_input_only_in_modals() {
+        let rows = [ActionRow::new([TextInput::new(TextInputStyle::Short, "name", "Name")]).into()];
+
+        assert!(Component::validate_rows(&rows).is_err());
+        assert!(Component::validate_modal_rows(&rows).is_ok());
+    }
+
+    #[test]
+    fn test_validate_duplicate_custom_id() {
+        let rows = [
+            ActionRow::new([Button::new(ButtonStyle::Primary, "same", "A")]).into(),
+            ActionRow::new([Button::new(ButtonStyle::Primary, "same", "B")]).into(),
+        ];
+
+        assert!(Component::validate_rows(&rows).is_err());
+    }
+
+    #[test]
+    fn test_validate_row_limits() {
+        let buttons = (0..6).map(|index| Button::new(ButtonStyle::Primary, index.to_string(), "A"));
+        let mixed: Vec<Component> = vec![
+            Button::new(ButtonStyle::Primary, "button", "A").into(),
+            SelectMenu::new(ComponentType::UserSelect, "users").into(),
+        ];
+
+        assert!(Component::validate_rows(&[ActionRow::new(buttons).into()]).is_err());
+        assert!(Component::validate_rows(&[ActionRow::new(mixed).into()]).is_err());
+    }
+
+    #[test]
+    fn test_validate_select_options() {
+        let options = (0..26)
+            .map(|index| SelectOption::new(index.to_string(), index.to_string()))
+            .collect();
+        let too_many = SelectMenu::string("many", options);
+        let empty = SelectMenu::string("empty", Vec::new());
+        let valid = SelectMenu::string("valid", vec![SelectOption::new("A", "a")]);
+
+        assert!(Component::from(too_many).validate().is_err());
+        assert!(Component::from(empty).validate().is_err());
+        assert!(Component::from(valid).validate().is_ok());
+    }
+}
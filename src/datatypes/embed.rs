@@ -0,0 +1,413 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::Error;
+
+/// Max characters in the title of a embed
+const MAX_TITLE_LENGTH: usize = 256;
+/// Max characters in the description of a embed
+const MAX_DESCRIPTION_LENGTH: usize = 4096;
+/// Max fields in a embed
+const MAX_FIELDS: usize = 25;
+/// Max characters in the name of a field
+const MAX_FIELD_NAME_LENGTH: usize = 256;
+/// Max characters in the value of a field
+const MAX_FIELD_VALUE_LENGTH: usize = 1024;
+/// Max characters in the footer text
+const MAX_FOOTER_LENGTH: usize = 2048;
+/// Max characters in the author name
+const MAX_AUTHOR_LENGTH: usize = 256;
+/// Max characters in a single embed, this limit is also shared by all embeds in a message
+pub(crate) const MAX_TOTAL_LENGTH: usize = 6000;
+
+/// Footer of a [`Embed`]
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct EmbedFooter {
+    /// Footer text
+    pub text: String,
+    /// Url of the footer icon, only http(s) and attachments are supported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    /// Proxied url of the footer icon, only set in received embeds
+    #[serde(skip_serializing)]
+    pub proxy_icon_url: Option<String>,
+}
+
+/// Image or thumbnail of a [`Embed`]
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct EmbedImage {
+    /// Url of the image, only http(s) and attachments are supported
+    pub url: String,
+    /// Proxied url of the image, only set in received embeds
+    #[serde(skip_serializing)]
+    pub proxy_url: Option<String>,
+    /// Height of the image, only set in received embeds
+    #[serde(skip_serializing)]
+    pub height: Option<u32>,
+    /// Width of the image, only set in received embeds
+    #[serde(skip_serializing)]
+    pub width: Option<u32>,
+}
+
+impl EmbedImage {
+    /// Image with the given url
+    #[must_use]
+    pub fn new<U: Into<String>>(url: U) -> Self {
+        Self {
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Video of a [`Embed`], can not be set when sending embeds
+#[derive(Deserialize, Default, Clone, Debug)]
+pub struct EmbedVideo {
+    /// Url of the video
+    pub url: Option<String>,
+    /// Proxied url of the video
+    pub proxy_url: Option<String>,
+    /// Height of the video
+    pub height: Option<u32>,
+    /// Width of the video
+    pub width: Option<u32>,
+}
+
+/// Website that provided a [`Embed`], can not be set when sending embeds
+#[derive(Deserialize, Default, Clone, Debug)]
+pub struct EmbedProvider {
+    /// Name of the provider
+    pub name: Option<String>,
+    /// Url of the provider
+    pub url: Option<String>,
+}
+
+/// Author of a [`Embed`]
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct EmbedAuthor {
+    /// Name of the author
+    pub name: String,
+    /// Url of the author, makes the name a link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Url of the author icon, only http(s) and attachments are supported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    /// Proxied url of the author icon, only set in received embeds
+    #[serde(skip_serializing)]
+    pub proxy_icon_url: Option<String>,
+}
+
+/// Field of a [`Embed`]
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct EmbedField {
+    /// Name of the field
+    pub name: String,
+    /// Value of the field
+    pub value: String,
+    /// Should this field be displayed on the same line as other inline fields
+    #[serde(default)]
+    pub inline: bool,
+}
+
+/// Rich embed shown below the content of a message
+///
+/// Use [`EmbedBuilder`] to create embeds that are checked against discords limits.
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct Embed {
+    /// Type of embed, always `rich` for embeds sent by bots
+    #[serde(rename = "type", skip_serializing)]
+    pub kind: Option<String>,
+    /// Title of the embed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Description of the embed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Url of the embed, makes the title a link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// ISO8601 timestamp shown in the footer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    /// Color of the left border, as `0xRRGGBB`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<u32>,
+    /// Footer information
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<EmbedFooter>,
+    /// Large image
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<EmbedImage>,
+    /// Small image in the top right corner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<EmbedImage>,
+    /// Video, only in received embeds
+    #[serde(skip_serializing)]
+    pub video: Option<EmbedVideo>,
+    /// Website that provided the embed, only in received embeds
+    #[serde(skip_serializing)]
+    pub provider: Option<EmbedProvider>,
+    /// Author information
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<EmbedAuthor>,
+    /// Fields shown in the embed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<EmbedField>,
+}
+
+impl Embed {
+    /// Amount of characters that count towards the 6000 character limit shared by all embeds in a message.
+    #[must_use]
+    pub fn total_length(&self) -> usize {
+        let length = |text: &Option<String>| text.as_ref().map_or(0, |text| text.chars().count());
+
+        length(&self.title)
+            + length(&self.description)
+            + self
+                .footer
+                .as_ref()
+                .map_or(0, |footer| footer.text.chars().count())
+            + self
+                .author
+                .as_ref()
+                .map_or(0, |author| author.name.chars().count())
+            + self
+                .fields
+                .iter()
+                .map(|field| field.name.chars().count() + field.value.chars().count())
+                .sum::<usize>()
+    }
+
+    /// Check the embed against discords length limits.
+    ///
+    /// # Errors
+    /// [`Error::ValidationErr`] describing the first limit that was exceeded.
+    pub fn validate(&self) -> Result<(), Error> {
+        let check = |name: &str, text: Option<&str>, max: usize| {
+            let length = text.map_or(0, |text| text.chars().count());
+            if length > max {
+                Err(Error::ValidationErr(format!(
+                    "embed {name} is {length} characters, max is {max}"
+                )))
+            } else {
+                Ok(())
+            }
+        };
+
+        check("title", self.title.as_deref(), MAX_TITLE_LENGTH)?;
+        check(
+            "description",
+            self.description.as_deref(),
+            MAX_DESCRIPTION_LENGTH,
+        )?;
+        check(
+            "footer text",
+            self.footer.as_ref().map(|footer| footer.text.as_str()),
+            MAX_FOOTER_LENGTH,
+        )?;
+        check(
+            "author name",
+            self.author.as_ref().map(|author| author.name.as_str()),
+            MAX_AUTHOR_LENGTH,
+        )?;
+
+        if self.fields.len() > MAX_FIELDS {
+            return Err(Error::ValidationErr(format!(
+                "embed has {} fields, max is {MAX_FIELDS}",
+                self.fields.len()
+            )));
+        }
+        for field in &self.fields {
+            check("field name", Some(&field.name), MAX_FIELD_NAME_LENGTH)?;
+            check("field value", Some(&field.value), MAX_FIELD_VALUE_LENGTH)?;
+        }
+
+        let total = self.total_length();
+        if total > MAX_TOTAL_LENGTH {
+            return Err(Error::ValidationErr(format!(
+                "embed is {total} characters, max is {MAX_TOTAL_LENGTH}"
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Builder for [`Embed`]s, checking discords length limits when building.
+///
+/// # Example
+/// ```
+/// # use vivcord::datatypes::EmbedBuilder;
+/// let embed = EmbedBuilder::new()
+///     .title("Server stats")
+///     .color(0x00FF_AA00)
+///     .field("Members", "1234", true)
+///     .field("Online", "567", true)
+///     .footer("Updated every hour")
+///     .build()?;
+/// # Ok::<(), vivcord::api::Error>(())
+/// ```
+#[derive(Default, Clone, Debug)]
+#[must_use]
+pub struct EmbedBuilder {
+    embed: Embed,
+}
+
+impl EmbedBuilder {
+    /// Start building a empty embed
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the title (max 256 characters)
+    pub fn title<T: Into<String>>(mut self, title: T) -> Self {
+        self.embed.title = Some(title.into());
+        self
+    }
+
+    /// Set the description (max 4096 characters)
+    pub fn description<T: Into<String>>(mut self, description: T) -> Self {
+        self.embed.description = Some(description.into());
+        self
+    }
+
+    /// Make the title a link
+    pub fn url<T: Into<String>>(mut self, url: T) -> Self {
+        self.embed.url = Some(url.into());
+        self
+    }
+
+    /// Set the ISO8601 timestamp shown in the footer
+    pub fn timestamp<T: Into<String>>(mut self, timestamp: T) -> Self {
+        self.embed.timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Set the color of the left border, as `0xRRGGBB`
+    pub fn color(mut self, color: u32) -> Self {
+        self.embed.color = Some(color);
+        self
+    }
+
+    /// Set the footer text (max 2048 characters)
+    pub fn footer<T: Into<String>>(mut self, text: T) -> Self {
+        self.embed.footer = Some(EmbedFooter {
+            text: text.into(),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Set the footer text (max 2048 characters) and icon
+    pub fn footer_with_icon<T: Into<String>, U: Into<String>>(
+        mut self,
+        text: T,
+        icon_url: U,
+    ) -> Self {
+        self.embed.footer = Some(EmbedFooter {
+            text: text.into(),
+            icon_url: Some(icon_url.into()),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Set the author name (max 256 characters)
+    pub fn author<T: Into<String>>(mut self, name: T) -> Self {
+        self.embed.author = Some(EmbedAuthor {
+            name: name.into(),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Set the full author information
+    pub fn author_full(mut self, author: EmbedAuthor) -> Self {
+        self.embed.author = Some(author);
+        self
+    }
+
+    /// Set the large image
+    pub fn image<U: Into<String>>(mut self, url: U) -> Self {
+        self.embed.image = Some(EmbedImage::new(url));
+        self
+    }
+
+    /// Set the thumbnail in the top right corner
+    pub fn thumbnail<U: Into<String>>(mut self, url: U) -> Self {
+        self.embed.thumbnail = Some(EmbedImage::new(url));
+        self
+    }
+
+    /// Add a field (max 25), name is max 256 and value max 1024 characters.
+    pub fn field<N: Into<String>, V: Into<String>>(
+        mut self,
+        name: N,
+        value: V,
+        inline: bool,
+    ) -> Self {
+        self.embed.fields.push(EmbedField {
+            name: name.into(),
+            value: value.into(),
+            inline,
+        });
+        self
+    }
+
+    /// Create the embed
+    ///
+    /// # Errors
+    /// [`Error::ValidationErr`] if any length limit is exceeded, see [`Embed::validate`].
+    pub fn build(self) -> Result<Embed, Error> {
+        self.embed.validate()?;
+        Ok(self.embed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_valid() {
+        let embed = EmbedBuilder::new()
+            .title("title")
+            .description("description")
+            .field("name", "value", false)
+            .build()
+            .unwrap();
+
+        assert_eq!(embed.total_length(), 5 + 11 + 4 + 5);
+    }
+
+    #[test]
+    fn test_builder_title_too_long() {
+        let result = EmbedBuilder::new().title("a".repeat(257)).build();
+
+        assert!(matches!(result, Err(Error::ValidationErr(_))));
+    }
+
+    #[test]
+    fn test_builder_too_many_fields() {
+        let builder = (0..26).fold(EmbedBuilder::new(), |builder, index| {
+            builder.field(index.to_string(), "value", true)
+        });
+
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn test_serialize_skips_received_fields() {
+        let embed: Embed = serde_json::from_value(serde_json::json!({
+            "type": "rich",
+            "title": "hi",
+            "image": {"url": "https://example.com/a.png", "proxy_url": "https://proxy/a.png", "width": 10, "height": 10},
+            "provider": {"name": "example"}
+        }))
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&embed).unwrap(),
+            serde_json::json!({"title": "hi", "image": {"url": "https://example.com/a.png"}})
+        );
+    }
+}
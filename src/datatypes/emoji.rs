@@ -0,0 +1,173 @@
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use super::Snowflake;
+
+/// Reference to a emoji, used when reacting to messages.
+///
+/// Can be created from a string, which is either a unicode emoji,
+/// or a custom emoji in the `name:id` or `<:name:id>` format.
+///
+/// # Example
+/// ```
+/// # use vivcord::datatypes::{EmojiRef, Snowflake};
+/// assert_eq!(EmojiRef::from("👍"), EmojiRef::Unicode("👍".to_owned()));
+/// assert_eq!(
+///     EmojiRef::from("<:vivax:123>"),
+///     EmojiRef::Custom { name: "vivax".to_owned(), id: Snowflake(123) }
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EmojiRef {
+    /// Unicode emoji, such as `👍`
+    Unicode(String),
+    /// Custom guild emoji
+    Custom {
+        /// Name of the emoji
+        name: String,
+        /// Emoji id
+        id: Snowflake,
+    },
+}
+
+impl EmojiRef {
+    /// Encode the emoji for use in a url path
+    #[must_use]
+    pub fn url_encoded(&self) -> String {
+        match self {
+            Self::Unicode(emoji) => percent_encode(emoji),
+            Self::Custom { name, id } => format!("{}:{id}", percent_encode(name)),
+        }
+    }
+}
+
+/// Percent encode everything except unreserved characters
+fn percent_encode(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() * 3);
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            result.push(char::from(byte));
+        } else {
+            // writing to a string never fails
+            let _ = write!(result, "%{byte:02X}");
+        }
+    }
+    result
+}
+
+impl From<&str> for EmojiRef {
+    fn from(value: &str) -> Self {
+        // <:name:id> and <a:name:id> are how custom emojis appear in message content
+        let trimmed = value
+            .strip_prefix('<')
+            .and_then(|value| value.strip_suffix('>'))
+            .map_or(value, |value| value.strip_prefix('a').unwrap_or(value));
+        let trimmed = trimmed.strip_prefix(':').unwrap_or(trimmed);
+
+        if let Some((name, id)) = trimmed.rsplit_once(':') {
+            if let Ok(id) = id.parse() {
+                return Self::Custom {
+                    name: name.to_owned(),
+                    id: Snowflake(id),
+                };
+            }
+        }
+        Self::Unicode(value.to_owned())
+    }
+}
+
+impl From<String> for EmojiRef {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl From<char> for EmojiRef {
+    fn from(value: char) -> Self {
+        Self::Unicode(value.to_string())
+    }
+}
+
+/// Emoji shown on components such as buttons and select options
+#[derive(Deserialize, Serialize, Default, Clone, Debug, PartialEq, Eq)]
+pub struct PartialEmoji {
+    /// Emoji id, [`None`] for unicode emojis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Snowflake>,
+    /// Name of a custom emoji, or the unicode emoji itself
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Is the emoji animated
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub animated: bool,
+}
+
+impl From<EmojiRef> for PartialEmoji {
+    fn from(value: EmojiRef) -> Self {
+        match value {
+            EmojiRef::Unicode(emoji) => Self {
+                name: Some(emoji),
+                ..Default::default()
+            },
+            EmojiRef::Custom { name, id } => Self {
+                id: Some(id),
+                name: Some(name),
+                animated: false,
+            },
+        }
+    }
+}
+
+int_enum! {
+    /// Kind of reaction
+    pub enum ReactionType: u8 {
+        /// Normal reaction
+        Normal = 0,
+        /// Super reaction, only available with nitro
+        Burst = 1,
+    }
+}
+
+/// Query parameters for [`get_reactions`][crate::Api::get_reactions]
+#[derive(Serialize, Default, Debug, Clone, Copy)]
+pub struct GetReactionsParams {
+    /// Kind of reactions to get, defaults to [`ReactionType::Normal`]
+    #[serde(rename = "type")]
+    pub kind: Option<ReactionType>,
+    /// Only get users with a id after this one
+    pub after: Option<Snowflake>,
+    /// Max amount of users to return (1-100), defaults to `25`
+    pub limit: Option<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unicode_encoding() {
+        assert_eq!(EmojiRef::from('👍').url_encoded(), "%F0%9F%91%8D");
+    }
+
+    #[test]
+    fn test_custom_encoding() {
+        assert_eq!(EmojiRef::from("blob:123").url_encoded(), "blob:123");
+    }
+
+    #[test]
+    fn test_parse_animated() {
+        assert_eq!(
+            EmojiRef::from("<a:dance:456>"),
+            EmojiRef::Custom {
+                name: "dance".to_owned(),
+                id: Snowflake(456)
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_not_custom() {
+        assert_eq!(EmojiRef::from(":)"), EmojiRef::Unicode(":)".to_owned()));
+    }
+}
@@ -0,0 +1,59 @@
+use serde::Deserialize;
+
+use super::{
+    GuildMember, GuildScheduledEvent, PartialChannel, PartialEmoji, Role, Snowflake, StageInstance,
+};
+
+/// Guild, as sent in [`EventData::GuildCreate`][crate::EventData::GuildCreate]
+///
+/// Guilds that are unavailable because of a discord outage only have `id` and `unavailable` set.
+#[derive(Deserialize, Clone, Debug)]
+pub struct Guild {
+    /// Guild id
+    pub id: Snowflake,
+    /// Name of the guild, empty when unavailable
+    #[serde(default)]
+    pub name: String,
+    /// [Icon hash](https://discord.com/developers/docs/reference#image-formatting)
+    pub icon: Option<String>,
+    /// User that owns the guild
+    pub owner_id: Option<Snowflake>,
+    /// Roles of the guild
+    #[serde(default)]
+    pub roles: Vec<Role>,
+    /// Custom emojis of the guild
+    #[serde(default)]
+    pub emojis: Vec<PartialEmoji>,
+    /// Enabled guild features, such as `COMMUNITY`
+    #[serde(default)]
+    pub features: Vec<String>,
+    /// ISO8601 timestamp of when the bot joined the guild
+    pub joined_at: Option<String>,
+    /// Is the guild considered large, see [`ClientBuilder::large_threshold`][crate::client::ClientBuilder::large_threshold]
+    #[serde(default)]
+    pub large: bool,
+    /// Is the guild unavailable because of a outage
+    #[serde(default)]
+    pub unavailable: bool,
+    /// Total members in the guild
+    pub member_count: Option<u64>,
+    /// Members of the guild, only the bot itself and members in voice channels
+    /// without [`Intents::GUILD_PRESENCES`][crate::Intents::GUILD_PRESENCES]
+    #[serde(default)]
+    pub members: Vec<GuildMember>,
+    /// Channels of the guild
+    #[serde(default)]
+    pub channels: Vec<PartialChannel>,
+    /// Active threads in the guild that the bot can see
+    #[serde(default)]
+    pub threads: Vec<PartialChannel>,
+    /// Live stages in the guild
+    #[serde(default)]
+    pub stage_instances: Vec<StageInstance>,
+    /// Scheduled events of the guild
+    #[serde(default)]
+    pub guild_scheduled_events: Vec<GuildScheduledEvent>,
+}
+
+to_snowflake_simple!(Guild);
+comp_by_field!(Guild, self.id);
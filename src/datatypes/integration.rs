@@ -0,0 +1,67 @@
+use serde::Deserialize;
+
+use super::{PartialApplication, Snowflake, User};
+
+int_enum! {
+    /// What happens to a user when their subscription to a integration expires
+    pub enum IntegrationExpireBehavior: u8 {
+        /// Remove the subscriber role
+        RemoveRole = 0,
+        /// Kick the user from the guild
+        Kick = 1,
+    }
+}
+
+/// Account information for a [`Integration`]
+#[derive(Deserialize, Clone, Debug)]
+pub struct IntegrationAccount {
+    /// Id of the account, this is *not* always a snowflake
+    pub id: String,
+    /// Name of the account
+    pub name: String,
+}
+
+/// Guild integration, such as twitch, youtube or a bot.
+#[derive(Deserialize, Clone, Debug)]
+pub struct Integration {
+    /// Integration id
+    pub id: Snowflake,
+    /// Guild this integration belongs to,
+    /// only sent in [`IntegrationCreate`][crate::EventData::IntegrationCreate] and [`IntegrationUpdate`][crate::EventData::IntegrationUpdate] events.
+    pub guild_id: Option<Snowflake>,
+    /// Integration name
+    pub name: String,
+    /// Integration type, `twitch`, `youtube`, `discord` or `guild_subscription`
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Is this integration enabled
+    pub enabled: bool,
+    /// Is this integration syncing
+    pub syncing: Option<bool>,
+    /// Role that this integration uses for subscribers
+    pub role_id: Option<Snowflake>,
+    /// Should emoticons be synced for this integration (twitch only)
+    pub enable_emoticons: Option<bool>,
+    /// What happens to users when their subscription expires
+    pub expire_behavior: Option<IntegrationExpireBehavior>,
+    /// Grace period in days before expiring subscribers
+    pub expire_grace_period: Option<u32>,
+    /// User for this integration
+    pub user: Option<User>,
+    /// Account information for the integration
+    pub account: IntegrationAccount,
+    /// ISO8601 timestamp of when this integration was last synced
+    pub synced_at: Option<String>,
+    /// How many subscribers this integration has
+    pub subscriber_count: Option<u32>,
+    /// Has this integration been revoked
+    pub revoked: Option<bool>,
+    /// Bot/oauth2 application for discord integrations
+    pub application: Option<PartialApplication>,
+    /// oauth2 scopes the application has been authorized for
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
+to_snowflake_simple!(Integration);
+comp_by_field!(Integration, self.id);
@@ -6,7 +6,7 @@ use bitflags::bitflags;
 bitflags! {
     /// Intents tells discord what events you want to be passed. <br>
     /// You can use bitwise operations to combine flags. <br>
-    /// These should be passed to [`Gateway::connect`][crate::Gateway::connect]
+    /// These should be passed to [`ClientBuilder::intents`][crate::client::ClientBuilder::intents]
     /// 
     /// Detailed description of what each flag does can be seen in the [discord docs](https://discord.com/developers/docs/topics/gateway#list-of-intents)
     /// 
@@ -37,4 +37,11 @@ bitflags! {
         const AUTO_MODERATION_CONFIGURATION = 1 << 20;
         const AUTO_MODERATION_EXECUTION = 1 << 21;
     }
-}
\ No newline at end of file
+}
+
+impl Intents {
+    /// Intents that must be enabled for the bot in the developer portal before they can be used
+    pub const PRIVILEGED: Self = Self::GUILD_MEMBERS
+        .union(Self::GUILD_PRESENCES)
+        .union(Self::MESSAGE_CONTENT);
+}
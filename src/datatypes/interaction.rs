@@ -0,0 +1,714 @@
+use std::collections::HashMap;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::message::validate_message;
+use super::{
+    ActionRow, AllowedMentions, ApplicationCommandOptionChoice, ApplicationCommandOptionType,
+    ApplicationCommandType, Attachment, AttachmentFile, CommandOptionValue, Component,
+    ComponentType, Embed, GuildMember, Message, MessageFlags, PartialAttachment, PartialChannel,
+    PollCreateRequest, Role, Snowflake, TextInput, TextInputStyle, User,
+};
+use crate::api::Error;
+
+/// Max characters in a modal title
+const MAX_MODAL_TITLE_LENGTH: usize = 45;
+/// Max choices in a autocomplete result
+pub(crate) const MAX_AUTOCOMPLETE_CHOICES: usize = 25;
+
+int_enum! {
+    /// What caused a [`Interaction`]
+    pub enum InteractionType: u8 {
+        /// Discord checking that a http interactions endpoint works
+        Ping = 1,
+        /// User used a application command
+        ApplicationCommand = 2,
+        /// User clicked a button or used a select menu
+        MessageComponent = 3,
+        /// User is typing in a application command option with autocomplete enabled
+        ApplicationCommandAutocomplete = 4,
+        /// User submitted a modal
+        ModalSubmit = 5,
+    }
+}
+
+/// Users, members, roles, channels, messages and attachments referenced by a interaction, by id.
+#[derive(Deserialize, Default, Clone, Debug)]
+pub struct ResolvedData {
+    /// Referenced users
+    #[serde(default)]
+    pub users: HashMap<Snowflake, User>,
+    /// Referenced members, these do not include [`GuildMember::user`], see [`users`][Self::users]
+    #[serde(default)]
+    pub members: HashMap<Snowflake, GuildMember>,
+    /// Referenced roles
+    #[serde(default)]
+    pub roles: HashMap<Snowflake, Role>,
+    /// Referenced channels
+    #[serde(default)]
+    pub channels: HashMap<Snowflake, PartialChannel>,
+    /// Referenced messages
+    #[serde(default)]
+    pub messages: HashMap<Snowflake, Message>,
+    /// Uploaded attachments
+    #[serde(default)]
+    pub attachments: HashMap<Snowflake, Attachment>,
+}
+
+/// Option the user filled in when using a application command
+#[derive(Deserialize, Clone, Debug)]
+pub struct CommandDataOption {
+    /// Name of the option
+    pub name: String,
+    /// Kind of value
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandOptionType,
+    /// Value of the option, missing for subcommands and subcommand groups
+    pub value: Option<CommandOptionValue>,
+    /// Options of a subcommand, or the subcommand of a subcommand group
+    #[serde(default)]
+    pub options: Vec<CommandDataOption>,
+    /// Is the user currently typing in this option, only set in autocomplete interactions
+    #[serde(default)]
+    pub focused: bool,
+}
+
+/// Data of [`InteractionType::ApplicationCommand`] and [`InteractionType::ApplicationCommandAutocomplete`] interactions
+#[derive(Deserialize, Clone, Debug)]
+pub struct ApplicationCommandData {
+    /// Id of the command
+    pub id: Snowflake,
+    /// Name of the command
+    pub name: String,
+    /// Kind of command
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandType,
+    /// Users, roles, channels and attachments referenced by the options
+    #[serde(default)]
+    pub resolved: ResolvedData,
+    /// Options the user filled in
+    #[serde(default)]
+    pub options: Vec<CommandDataOption>,
+    /// Guild the command is registered in, missing for global commands
+    pub guild_id: Option<Snowflake>,
+    /// User or message that was right clicked, for [`ApplicationCommandType::User`] and [`ApplicationCommandType::Message`] commands
+    pub target_id: Option<Snowflake>,
+}
+
+impl ApplicationCommandData {
+    /// Option the user is typing in, for autocomplete interactions.
+    ///
+    /// Options of subcommands are searched as well.
+    #[must_use]
+    pub fn focused(&self) -> Option<&CommandDataOption> {
+        fn find(options: &[CommandDataOption]) -> Option<&CommandDataOption> {
+            options.iter().find_map(|option| {
+                if option.focused {
+                    Some(option)
+                } else {
+                    find(&option.options)
+                }
+            })
+        }
+        find(&self.options)
+    }
+}
+
+/// Data of [`InteractionType::MessageComponent`] interactions
+#[derive(Deserialize, Clone, Debug)]
+pub struct MessageComponentData {
+    /// Custom id of the component
+    pub custom_id: String,
+    /// Kind of component
+    pub component_type: ComponentType,
+    /// Values the user selected in a select menu
+    #[serde(default)]
+    pub values: Vec<String>,
+    /// Users, roles and channels selected in a select menu
+    #[serde(default)]
+    pub resolved: ResolvedData,
+}
+
+/// Data of [`InteractionType::ModalSubmit`] interactions
+#[derive(Deserialize, Clone, Debug)]
+pub struct ModalSubmitData {
+    /// Custom id of the modal
+    pub custom_id: String,
+    /// Action rows containing the submitted [`TextInput`][super::TextInput]s
+    pub components: Vec<Component>,
+}
+
+impl ModalSubmitData {
+    /// Value the user entered in the text input with `custom_id`
+    #[must_use]
+    pub fn value(&self, custom_id: &str) -> Option<&str> {
+        self.components
+            .iter()
+            .filter_map(|row| match row {
+                Component::ActionRow(row) => Some(&row.components),
+                _ => None,
+            })
+            .flatten()
+            .find_map(|component| match component {
+                Component::TextInput(input) if input.custom_id == custom_id => {
+                    input.value.as_deref()
+                }
+                _ => None,
+            })
+    }
+}
+
+/// Data of a [`Interaction`], which variant is used depends on [`Interaction::kind`]
+#[derive(Clone, Debug)]
+pub enum InteractionData {
+    /// Data of [`InteractionType::MessageComponent`] interactions
+    MessageComponent(MessageComponentData),
+    /// Data of [`InteractionType::ModalSubmit`] interactions
+    ModalSubmit(ModalSubmitData),
+    /// Data of [`InteractionType::ApplicationCommand`] and [`InteractionType::ApplicationCommandAutocomplete`] interactions
+    ApplicationCommand(ApplicationCommandData),
+    /// Data not known by `vivcord`
+    Unknown(serde_json::Value),
+}
+
+impl InteractionData {
+    /// Parse the data of a interaction of the given type
+    fn from_value(kind: InteractionType, data: serde_json::Value) -> serde_json::Result<Self> {
+        match kind {
+            InteractionType::ApplicationCommand
+            | InteractionType::ApplicationCommandAutocomplete => {
+                serde_json::from_value(data).map(Self::ApplicationCommand)
+            }
+            InteractionType::MessageComponent => {
+                serde_json::from_value(data).map(Self::MessageComponent)
+            }
+            InteractionType::ModalSubmit => serde_json::from_value(data).map(Self::ModalSubmit),
+            InteractionType::Ping | InteractionType::Unknown(_) => Ok(Self::Unknown(data)),
+        }
+    }
+}
+
+/// Sent when a user uses a application command, clicks a component or submits a modal
+///
+/// The bot must respond within 3 seconds using [`create_interaction_response`][crate::Api::create_interaction_response],
+/// after which the [`token`][Self::token] can be used for followup messages for 15 minutes.
+#[derive(Deserialize, Clone, Debug)]
+#[serde(remote = "Self")]
+pub struct Interaction {
+    /// Interaction id
+    pub id: Snowflake,
+    /// Application the interaction is for
+    pub application_id: Snowflake,
+    /// What caused the interaction
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// Data of the interaction, missing for [`InteractionType::Ping`]
+    #[serde(skip)]
+    pub data: Option<InteractionData>,
+    /// Guild the interaction was sent from
+    pub guild_id: Option<Snowflake>,
+    /// Channel the interaction was sent from
+    pub channel_id: Option<Snowflake>,
+    /// Partial channel the interaction was sent from
+    pub channel: Option<PartialChannel>,
+    /// Member that caused the interaction, when sent from a guild
+    pub member: Option<GuildMember>,
+    /// User that caused the interaction, when sent from a dm
+    pub user: Option<User>,
+    /// Token used to respond to the interaction
+    pub token: String,
+    /// Always `1`
+    pub version: u8,
+    /// Message the component was attached to, for [`InteractionType::MessageComponent`] interactions
+    pub message: Option<Message>,
+    /// Permissions of the bot in the channel, as a bitset string
+    pub app_permissions: Option<String>,
+    /// Language of the user, missing for pings
+    pub locale: Option<String>,
+    /// Language of the guild
+    pub guild_locale: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for Interaction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = serde_json::Value::deserialize(deserializer)?;
+        let data = value
+            .get_mut("data")
+            .map(serde_json::Value::take)
+            .filter(|data| !data.is_null());
+
+        // the derived implementation, which skips the data
+        let mut interaction = Interaction::deserialize(value).map_err(D::Error::custom)?;
+        interaction.data = data
+            .map(|data| InteractionData::from_value(interaction.kind, data))
+            .transpose()
+            .map_err(D::Error::custom)?;
+        Ok(interaction)
+    }
+}
+
+impl Interaction {
+    /// User that caused the interaction, both in guilds and dms
+    #[must_use]
+    pub fn author(&self) -> Option<&User> {
+        self.member
+            .as_ref()
+            .and_then(|member| member.user.as_ref())
+            .or(self.user.as_ref())
+    }
+
+    /// Data of application command and autocomplete interactions
+    #[must_use]
+    pub fn command_data(&self) -> Option<&ApplicationCommandData> {
+        match &self.data {
+            Some(InteractionData::ApplicationCommand(data)) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Data of message component interactions
+    #[must_use]
+    pub fn component_data(&self) -> Option<&MessageComponentData> {
+        match &self.data {
+            Some(InteractionData::MessageComponent(data)) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Data of modal submit interactions
+    #[must_use]
+    pub fn modal_data(&self) -> Option<&ModalSubmitData> {
+        match &self.data {
+            Some(InteractionData::ModalSubmit(data)) => Some(data),
+            _ => None,
+        }
+    }
+}
+
+to_snowflake_simple!(Interaction);
+comp_by_field!(Interaction, self.id);
+
+int_enum! {
+    /// Kind of [`InteractionResponse`]
+    pub enum InteractionCallbackType: u8 {
+        /// Acknowledge a [`InteractionType::Ping`]
+        Pong = 1,
+        /// Respond with a message
+        ChannelMessageWithSource = 4,
+        /// Show a loading state, and respond later by editing the original response
+        DeferredChannelMessageWithSource = 5,
+        /// Acknowledge a component interaction, and edit the message later
+        DeferredUpdateMessage = 6,
+        /// Edit the message the component was attached to
+        UpdateMessage = 7,
+        /// Respond to a autocomplete interaction with choices
+        ApplicationCommandAutocompleteResult = 8,
+        /// Show a modal
+        Modal = 9,
+        /// Launch the activity of the application
+        LaunchActivity = 12,
+    }
+}
+
+/// Message sent in response to a interaction, or as a followup message
+///
+/// # Example
+/// ```
+/// # use vivcord::datatypes::InteractionMessage;
+/// let msg = InteractionMessage::content("Only you can see this").ephemeral();
+/// ```
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct InteractionMessage {
+    /// Content to send (max 2000 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Is this a text to speech message
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub tts: bool,
+    /// Embeds to show (max 10, with at most 6000 characters combined)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub embeds: Vec<Embed>,
+    /// Which mentions notify people
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+    /// Action rows of interactive components (max 5)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+    /// Attachments to keep when updating a message, a entry for each of [`files`][Self::files] is added when sending
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<PartialAttachment>,
+    /// Files to upload
+    #[serde(skip)]
+    pub files: Vec<AttachmentFile>,
+    /// Flags to set, such as [`MessageFlags::EPHEMERAL`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<MessageFlags>,
+    /// Poll to create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<PollCreateRequest>,
+}
+
+impl InteractionMessage {
+    /// Message with only text content
+    pub fn content<C: Into<String>>(content: C) -> Self {
+        Self {
+            content: Some(content.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Only show the message to the user that caused the interaction
+    #[must_use]
+    pub fn ephemeral(mut self) -> Self {
+        self.flags = Some(self.flags.unwrap_or(MessageFlags::empty()) | MessageFlags::EPHEMERAL);
+        self
+    }
+
+    /// Check the message against discords limits
+    ///
+    /// # Errors
+    /// [`Error::ValidationErr`] describing the first limit that was exceeded.
+    pub fn validate(&self) -> Result<(), Error> {
+        validate_message(self.content.as_deref(), &self.embeds, &self.components)
+    }
+}
+
+impl From<String> for InteractionMessage {
+    fn from(content: String) -> Self {
+        Self::content(content)
+    }
+}
+
+impl From<&str> for InteractionMessage {
+    fn from(content: &str) -> Self {
+        Self::content(content)
+    }
+}
+
+/// Popup with text inputs, shown in response to a interaction
+#[derive(Serialize, Debug, Clone)]
+pub struct Modal {
+    /// Id sent with the [`InteractionType::ModalSubmit`] interaction (max 100 characters)
+    pub custom_id: String,
+    /// Title of the popup (max 45 characters)
+    pub title: String,
+    /// Action rows containing a single [`TextInput`][super::TextInput] each (1-5)
+    pub components: Vec<Component>,
+}
+
+impl Modal {
+    /// Check the modal against discords limits
+    ///
+    /// # Errors
+    /// [`Error::ValidationErr`] describing the first limit that was exceeded.
+    pub fn validate(&self) -> Result<(), Error> {
+        let custom_id_length = self.custom_id.chars().count();
+        if custom_id_length == 0 || custom_id_length > 100 {
+            return Err(Error::ValidationErr(format!(
+                "modal custom id is {custom_id_length} characters, must be between 1 and 100"
+            )));
+        }
+        let title_length = self.title.chars().count();
+        if title_length == 0 || title_length > MAX_MODAL_TITLE_LENGTH {
+            return Err(Error::ValidationErr(format!(
+                "modal title is {title_length} characters, must be between 1 and {MAX_MODAL_TITLE_LENGTH}"
+            )));
+        }
+        if self.components.is_empty() {
+            return Err(Error::ValidationErr("modal has no components".to_owned()));
+        }
+        Component::validate_modal_rows(&self.components)
+    }
+}
+
+/// Builder for [`Modal`]s, each text input is placed in its own row.
+///
+/// # Example
+/// ```
+/// # use vivcord::datatypes::{ModalBuilder, TextInput, TextInputStyle};
+/// let modal = ModalBuilder::new("feedback", "Send feedback")
+///     .short("topic", "Topic")
+///     .text_input(
+///         TextInput::new(TextInputStyle::Paragraph, "details", "Details")
+///             .max_length(1000)
+///             .placeholder("What happened?")
+///             .required(false),
+///     )
+///     .build()?;
+/// assert_eq!(modal.components.len(), 2);
+/// # Ok::<(), vivcord::api::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct ModalBuilder {
+    modal: Modal,
+}
+
+impl ModalBuilder {
+    /// Start building a modal without inputs
+    pub fn new<I: Into<String>, T: Into<String>>(custom_id: I, title: T) -> Self {
+        Self {
+            modal: Modal {
+                custom_id: custom_id.into(),
+                title: title.into(),
+                components: Vec::new(),
+            },
+        }
+    }
+
+    /// Add a text input in a new row
+    pub fn text_input(mut self, input: TextInput) -> Self {
+        self.modal.components.push(ActionRow::new([input]).into());
+        self
+    }
+
+    /// Add a single line text input
+    pub fn short<I: Into<String>, L: Into<String>>(self, custom_id: I, label: L) -> Self {
+        self.text_input(TextInput::new(TextInputStyle::Short, custom_id, label))
+    }
+
+    /// Add a multi line text input
+    pub fn paragraph<I: Into<String>, L: Into<String>>(self, custom_id: I, label: L) -> Self {
+        self.text_input(TextInput::new(TextInputStyle::Paragraph, custom_id, label))
+    }
+
+    /// Check the modal against discords limits and return it
+    ///
+    /// # Errors
+    /// [`Error::ValidationErr`] describing the first limit that was exceeded.
+    pub fn build(self) -> Result<Modal, Error> {
+        self.modal.validate()?;
+        Ok(self.modal)
+    }
+}
+
+/// Response to a interaction, sent with [`create_interaction_response`][crate::Api::create_interaction_response]
+#[derive(Debug, Clone)]
+pub enum InteractionResponse {
+    /// Acknowledge a [`InteractionType::Ping`]
+    Pong,
+    /// Respond with a message
+    ChannelMessageWithSource(InteractionMessage),
+    /// Show a loading state, and respond later by editing the original response
+    DeferredChannelMessageWithSource {
+        /// Should the response only be shown to the user that caused the interaction
+        ephemeral: bool,
+    },
+    /// Acknowledge a component interaction, and edit the message later
+    DeferredUpdateMessage,
+    /// Edit the message the component was attached to
+    UpdateMessage(InteractionMessage),
+    /// Respond to a autocomplete interaction with choices (max 25)
+    ApplicationCommandAutocompleteResult(Vec<ApplicationCommandOptionChoice>),
+    /// Show a modal, can not be used in response to [`InteractionType::ModalSubmit`] interactions
+    Modal(Modal),
+    /// Launch the activity of the application
+    LaunchActivity,
+}
+
+impl InteractionResponse {
+    /// Kind of response
+    #[must_use]
+    pub fn kind(&self) -> InteractionCallbackType {
+        match self {
+            Self::Pong => InteractionCallbackType::Pong,
+            Self::ChannelMessageWithSource(_) => InteractionCallbackType::ChannelMessageWithSource,
+            Self::DeferredChannelMessageWithSource { .. } => {
+                InteractionCallbackType::DeferredChannelMessageWithSource
+            }
+            Self::DeferredUpdateMessage => InteractionCallbackType::DeferredUpdateMessage,
+            Self::UpdateMessage(_) => InteractionCallbackType::UpdateMessage,
+            Self::ApplicationCommandAutocompleteResult(_) => {
+                InteractionCallbackType::ApplicationCommandAutocompleteResult
+            }
+            Self::Modal(_) => InteractionCallbackType::Modal,
+            Self::LaunchActivity => InteractionCallbackType::LaunchActivity,
+        }
+    }
+
+    /// Check the response against discords limits
+    ///
+    /// # Errors
+    /// [`Error::ValidationErr`] describing the first limit that was exceeded.
+    pub fn validate(&self) -> Result<(), Error> {
+        match self {
+            Self::ChannelMessageWithSource(msg) | Self::UpdateMessage(msg) => msg.validate(),
+            Self::ApplicationCommandAutocompleteResult(choices)
+                if choices.len() > MAX_AUTOCOMPLETE_CHOICES =>
+            {
+                Err(Error::ValidationErr(format!(
+                    "autocomplete result has {} choices, max is {MAX_AUTOCOMPLETE_CHOICES}",
+                    choices.len()
+                )))
+            }
+            Self::Modal(modal) => modal.validate(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Take the files to upload out of the response
+    pub(crate) fn take_files(&mut self) -> Vec<AttachmentFile> {
+        match self {
+            Self::ChannelMessageWithSource(msg) | Self::UpdateMessage(msg) => {
+                std::mem::take(&mut msg.files)
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl Serialize for InteractionResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        /// Body sent to discord
+        #[derive(Serialize)]
+        struct Body<T: Serialize> {
+            #[serde(rename = "type")]
+            kind: InteractionCallbackType,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            data: Option<T>,
+        }
+
+        /// Data of [`InteractionResponse::ApplicationCommandAutocompleteResult`]
+        #[derive(Serialize)]
+        struct Choices<'a> {
+            choices: &'a [ApplicationCommandOptionChoice],
+        }
+
+        let kind = self.kind();
+        let data = match self {
+            Self::ChannelMessageWithSource(msg) | Self::UpdateMessage(msg) => {
+                serde_json::to_value(msg)
+            }
+            Self::DeferredChannelMessageWithSource { ephemeral: true } => {
+                serde_json::to_value(InteractionMessage::default().ephemeral())
+            }
+            Self::ApplicationCommandAutocompleteResult(choices) => {
+                serde_json::to_value(Choices { choices })
+            }
+            Self::Modal(modal) => serde_json::to_value(modal),
+            _ => return Body::<()> { kind, data: None }.serialize(serializer),
+        };
+        let data = data.map_err(serde::ser::Error::custom)?;
+
+        Body {
+            kind,
+            data: Some(data),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_command() {
+        let interaction: Interaction = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 2,
+            "token": "token",
+            "version": 1,
+            "member": {"user": {"id": "3", "username": "viv", "discriminator": "0"}, "roles": []},
+            "data": {
+                "id": "4",
+                "name": "ban",
+                "type": 1,
+                "options": [{"name": "user", "type": 6, "value": "3"}],
+                "resolved": {"users": {"3": {"id": "3", "username": "viv", "discriminator": "0"}}}
+            }
+        }))
+        .unwrap();
+
+        let data = interaction.command_data().unwrap();
+        let user_id = data.options[0]
+            .value
+            .as_ref()
+            .unwrap()
+            .as_snowflake()
+            .unwrap();
+        assert_eq!(user_id, Snowflake(3));
+        assert!(data.resolved.users.contains_key(&user_id));
+        assert_eq!(interaction.author().unwrap().id, Snowflake(3));
+    }
+
+    #[test]
+    fn test_deserialize_component() {
+        let interaction: Interaction = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 3,
+            "token": "token",
+            "version": 1,
+            "user": {"id": "3", "username": "viv", "discriminator": "0"},
+            "data": {"custom_id": "colors", "component_type": 3, "values": ["red"]}
+        }))
+        .unwrap();
+
+        assert_eq!(interaction.component_data().unwrap().values, ["red"]);
+    }
+
+    #[test]
+    fn test_invalid_data_is_error() {
+        // missing the command name, this must not be mistaken for unknown data
+        let result = serde_json::from_value::<Interaction>(serde_json::json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 2,
+            "token": "token",
+            "version": 1,
+            "data": {"id": "4", "type": 1}
+        }));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_modal_submit_value() {
+        let data: ModalSubmitData = serde_json::from_value(serde_json::json!({
+            "custom_id": "feedback",
+            "components": [{"type": 1, "components": [{"type": 4, "custom_id": "text", "value": "great"}]}]
+        }))
+        .unwrap();
+
+        assert_eq!(data.value("text"), Some("great"));
+        assert_eq!(data.value("missing"), None);
+    }
+
+    #[test]
+    fn test_focused_option() {
+        let data: ApplicationCommandData = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "name": "tag",
+            "type": 1,
+            "options": [{"name": "get", "type": 1, "options": [
+                {"name": "name", "type": 3, "value": "fo", "focused": true}
+            ]}]
+        }))
+        .unwrap();
+
+        assert_eq!(data.focused().unwrap().name, "name");
+    }
+
+    #[test]
+    fn test_serialize_responses() {
+        let deferred = InteractionResponse::DeferredChannelMessageWithSource { ephemeral: true };
+        let message =
+            InteractionResponse::ChannelMessageWithSource(InteractionMessage::content("hi"));
+
+        assert_eq!(
+            serde_json::to_value(&deferred).unwrap(),
+            serde_json::json!({"type": 5, "data": {"flags": 64}})
+        );
+        assert_eq!(
+            serde_json::to_value(&message).unwrap(),
+            serde_json::json!({"type": 4, "data": {"content": "hi"}})
+        );
+        assert_eq!(
+            serde_json::to_value(&InteractionResponse::Pong).unwrap(),
+            serde_json::json!({"type": 1})
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use serde::Deserialize;
+
+use super::{PartialApplication, Snowflake, User};
+
+int_enum! {
+    /// What a invite points to, when it is not just a channel
+    pub enum InviteTargetType: u8 {
+        /// Invite to watch a users stream in a voice channel
+        Stream = 1,
+        /// Invite to a embedded application (activity) in a voice channel
+        EmbeddedApplication = 2,
+    }
+}
+
+/// Discord Invite, as sent in [`InviteCreate`][crate::EventData::InviteCreate] events.
+#[derive(Deserialize, Clone, Debug)]
+pub struct Invite {
+    /// Unique invite code, `discord.gg/{code}`
+    pub code: String,
+    /// Channel the invite is for
+    pub channel_id: Snowflake,
+    /// Guild of the invite, missing for group dm invites
+    pub guild_id: Option<Snowflake>,
+    /// User that created the invite
+    pub inviter: Option<User>,
+    /// Type of target for voice channel invites
+    pub target_type: Option<InviteTargetType>,
+    /// User whose stream to display for [`InviteTargetType::Stream`] invites
+    pub target_user: Option<User>,
+    /// Application to open for [`InviteTargetType::EmbeddedApplication`] invites
+    pub target_application: Option<PartialApplication>,
+    /// ISO8601 timestamp of when the invite was created
+    pub created_at: String,
+    /// How long the invite is valid for in seconds, `0` means forever
+    pub max_age: u32,
+    /// Maximum amount of times the invite can be used, `0` means unlimited
+    pub max_uses: u32,
+    /// How many times the invite has been used, always `0` for new invites
+    #[serde(default)]
+    pub uses: u32,
+    /// Does this invite only grant temporary membership
+    pub temporary: bool,
+}
@@ -0,0 +1,30 @@
+use serde::Deserialize;
+
+use super::{Snowflake, User};
+
+/// Member of a guild
+///
+/// Most places discord sends members from, such as [`Interaction::member`][super::Interaction::member],
+/// only include some of the fields.
+#[derive(Deserialize, Clone, Debug)]
+pub struct GuildMember {
+    /// User this member represents, missing when the user is already known from the surrounding object
+    pub user: Option<User>,
+    /// Guild specific nickname
+    pub nick: Option<String>,
+    /// Guild specific [avatar hash](https://discord.com/developers/docs/reference#image-formatting)
+    pub avatar: Option<String>,
+    /// Ids of the roles the member has
+    #[serde(default)]
+    pub roles: Vec<Snowflake>,
+    /// ISO8601 timestamp of when the member joined the guild
+    pub joined_at: Option<String>,
+    /// Has the member not yet passed the membership screening
+    #[serde(default)]
+    pub pending: bool,
+    /// Permissions of the member in the channel, as a bitset string.
+    /// Only included in interactions.
+    pub permissions: Option<String>,
+    /// ISO8601 timestamp of when the members timeout ends
+    pub communication_disabled_until: Option<String>,
+}
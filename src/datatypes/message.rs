@@ -1,6 +1,16 @@
 use serde::{Deserialize, Serialize};
 
-use super::Snowflake;
+use super::embed::MAX_TOTAL_LENGTH as MAX_EMBED_LENGTH;
+use super::{
+    AttachmentFile, Component, Embed, GuildMember, MessageFlags, PollCreateRequest, Snowflake, User,
+};
+
+/// Max characters in the content of a message
+const MAX_CONTENT_LENGTH: usize = 2000;
+/// Max embeds in a message
+const MAX_EMBEDS: usize = 10;
+/// Max stickers in a message
+const MAX_STICKERS: usize = 3;
 
 /// Discord Message
 /// 
@@ -13,8 +23,21 @@ pub struct Message {
     pub id: Snowflake,
     /// Id of channel where this message was sent
     pub channel_id: Snowflake,
+    /// Guild the message was sent in, only set in gateway events
+    pub guild_id: Option<Snowflake>,
+    /// User that sent the message, for webhook messages this is the webhook
+    pub author: User,
+    /// Member that sent the message, only set in gateway events for guild messages.
+    /// This does not include [`GuildMember::user`], see [`author`][Self::author]
+    pub member: Option<GuildMember>,
     /// Text content of message
     pub content: String,
+    /// Embeds shown in the message
+    #[serde(default)]
+    pub embeds: Vec<Embed>,
+    /// Interactive components of the message
+    #[serde(default)]
+    pub components: Vec<Component>,
     // TODO: Implement all fields
 }
 
@@ -25,17 +48,315 @@ impl std::fmt::Debug for Message {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // Messages have way to many fields
         // lets only show the important stuff
-        // TODO: add author field once that is added
         f.debug_struct("Message")
+            .field("author", &self.author.username)
             .field("content", &self.content)
             .finish_non_exhaustive()
     }
 }
 
+/// Kind of mention that can be allowed by [`AllowedMentions`]
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AllowedMentionType {
+    /// Role mentions
+    Roles,
+    /// User mentions
+    Users,
+    /// `@everyone` and `@here`
+    Everyone,
+}
+
+/// Controls which mentions in a message actually notify people
+///
+/// The default value doesn't allow any mentions.
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct AllowedMentions {
+    /// Kinds of mentions that are parsed from the content
+    pub parse: Vec<AllowedMentionType>,
+    /// Roles that can be mentioned (max 100), don't combine with [`AllowedMentionType::Roles`]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<Snowflake>,
+    /// Users that can be mentioned (max 100), don't combine with [`AllowedMentionType::Users`]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<Snowflake>,
+    /// Should the author of the message being replied to be mentioned
+    pub replied_user: bool,
+}
+
+/// Reference to another message, used to reply to it
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Copy)]
+pub struct MessageReference {
+    /// Message being referenced
+    pub message_id: Option<Snowflake>,
+    /// Channel of the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Snowflake>,
+    /// Guild of the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Snowflake>,
+    /// Should sending fail if the message doesn't exist, defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fail_if_not_exists: Option<bool>,
+}
+
+impl From<&Message> for MessageReference {
+    fn from(message: &Message) -> Self {
+        Self {
+            message_id: Some(message.id),
+            channel_id: Some(message.channel_id),
+            ..Default::default()
+        }
+    }
+}
+
+/// Attachment to keep or upload when sending a message
+#[derive(Serialize, Debug, Clone)]
+pub struct PartialAttachment {
+    /// Id of a existing attachment, or the index of a file being uploaded
+    pub id: Snowflake,
+    /// Name of the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    /// Description (alt text) of the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
 /// Fields that can be passed to the discord api to create message
 /// This could be the [`create_message`][crate::Api::create_message] endpoint, or `TODO: MORE ENDPOINTS`
-#[derive(Serialize, Default, Debug)]
+///
+/// # Example
+/// ```
+/// # use vivcord::{CreateMessageParams, datatypes::AllowedMentions};
+/// let msg = CreateMessageParams {
+///     content: Some("hello @everyone".to_owned()),
+///     allowed_mentions: Some(AllowedMentions::default()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Serialize, Default, Debug, Clone)]
 pub struct CreateMessageParams {
-    /// Content to send
-    pub content: Option<String>
-}
\ No newline at end of file
+    /// Content to send (max 2000 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Used to check if the message was sent, will be included in the [`MessageCreate`][crate::EventData::MessageCreate] event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    /// Don't send the message if a message with the same nonce was sent recently
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforce_nonce: Option<bool>,
+    /// Is this a text to speech message
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub tts: bool,
+    /// Embeds to show (max 10, with at most 6000 characters combined), see [`EmbedBuilder`][super::EmbedBuilder]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub embeds: Vec<Embed>,
+    /// Which mentions notify people, by default all mentions in the content do
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+    /// Message to reply to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_reference: Option<MessageReference>,
+    /// Action rows of interactive components (max 5)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+    /// Stickers to send (max 3)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sticker_ids: Vec<Snowflake>,
+    /// Attachments to list besides the uploaded files, a entry for each of [`files`][Self::files] is added when sending
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<PartialAttachment>,
+    /// Files to upload
+    #[serde(skip)]
+    pub files: Vec<AttachmentFile>,
+    /// Flags to set, such as [`MessageFlags::SUPPRESS_NOTIFICATIONS`] for silent messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<MessageFlags>,
+    /// Poll to create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<PollCreateRequest>,
+}
+
+impl From<String> for CreateMessageParams {
+    fn from(content: String) -> Self {
+        Self {
+            content: Some(content),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for CreateMessageParams {
+    fn from(content: &str) -> Self {
+        content.to_owned().into()
+    }
+}
+
+impl CreateMessageParams {
+    /// Check the message against discords limits, this is done by [`create_message`][crate::Api::create_message] before sending.
+    ///
+    /// # Errors
+    /// [`Error::ValidationErr`][crate::api::Error::ValidationErr] describing the first limit that was exceeded.
+    pub fn validate(&self) -> Result<(), crate::api::Error> {
+        validate_message(self.content.as_deref(), &self.embeds, &self.components)?;
+        if self.sticker_ids.len() > MAX_STICKERS {
+            return Err(crate::api::Error::ValidationErr(format!(
+                "message has {} stickers, max is {MAX_STICKERS}",
+                self.sticker_ids.len()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Check the parts shared by all ways of sending a message against discords limits
+pub(crate) fn validate_message(
+    content: Option<&str>,
+    embeds: &[Embed],
+    components: &[Component],
+) -> Result<(), crate::api::Error> {
+    let error = |reason: String| Err(crate::api::Error::ValidationErr(reason));
+
+    let content_length = content.map_or(0, |content| content.chars().count());
+    if content_length > MAX_CONTENT_LENGTH {
+        return error(format!(
+            "content is {content_length} characters, max is {MAX_CONTENT_LENGTH}"
+        ));
+    }
+    if embeds.len() > MAX_EMBEDS {
+        return error(format!(
+            "message has {} embeds, max is {MAX_EMBEDS}",
+            embeds.len()
+        ));
+    }
+    for embed in embeds {
+        embed.validate()?;
+    }
+    let embed_length: usize = embeds.iter().map(Embed::total_length).sum();
+    if embed_length > MAX_EMBED_LENGTH {
+        return error(format!(
+            "embeds have {embed_length} characters combined, max is {MAX_EMBED_LENGTH}"
+        ));
+    }
+    Component::validate_rows(components)
+}
+
+/// Fields that can be passed to [`edit_message`][crate::Api::edit_message],
+/// also used to edit interaction responses and followup messages.
+///
+/// Fields left as [`None`] are not changed.
+#[derive(Serialize, Default, Debug)]
+pub struct EditMessageParams {
+    /// New content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// New embeds, use a empty list to remove all embeds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<Embed>>,
+    /// Which mentions in the new content notify people
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+    /// Attachments to keep, existing attachments not in this list are removed.
+    /// A entry for each of [`files`][Self::files] is added when sending,
+    /// when [`None`] the new files are added next to the existing attachments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<PartialAttachment>>,
+    /// New action rows of interactive components (max 5), use a empty list to remove all components
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Component>>,
+    /// New files to upload
+    #[serde(skip)]
+    pub files: Vec<AttachmentFile>,
+}
+
+impl EditMessageParams {
+    /// Check the new values against discords limits, this is done by [`edit_message`][crate::Api::edit_message] before sending.
+    ///
+    /// # Errors
+    /// [`Error::ValidationErr`][crate::api::Error::ValidationErr] describing the first limit that was exceeded.
+    pub fn validate(&self) -> Result<(), crate::api::Error> {
+        validate_message(
+            self.content.as_deref(),
+            self.embeds.as_deref().unwrap_or_default(),
+            self.components.as_deref().unwrap_or_default(),
+        )
+    }
+}
+
+/// Query parameters for [`get_channel_messages`][crate::Api::get_channel_messages]
+///
+/// Only one of `around`, `before` and `after` can be used at once.
+#[derive(Serialize, Default, Debug, Clone, Copy)]
+pub struct GetChannelMessagesParams {
+    /// Get messages around this message
+    pub around: Option<Snowflake>,
+    /// Get messages before this message
+    pub before: Option<Snowflake>,
+    /// Get messages after this message
+    pub after: Option<Snowflake>,
+    /// Max amount of messages to return (1-100), defaults to `50`
+    pub limit: Option<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::EmbedField;
+
+    #[test]
+    fn test_validate_content_length() {
+        let msg = CreateMessageParams {
+            content: Some("a".repeat(2001)),
+            ..Default::default()
+        };
+
+        assert!(msg.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_content_counts_characters() {
+        let msg = CreateMessageParams {
+            content: Some("🦀".repeat(2000)),
+            ..Default::default()
+        };
+
+        assert!(msg.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_embed_length() {
+        let embed = Embed {
+            fields: vec![
+                EmbedField {
+                    name: "a".repeat(256),
+                    value: "b".repeat(1024),
+                    inline: false,
+                };
+                4
+            ],
+            ..Default::default()
+        };
+        let msg = CreateMessageParams {
+            embeds: vec![embed; 2],
+            ..Default::default()
+        };
+
+        assert!(msg.validate().is_err());
+    }
+
+    #[test]
+    fn test_serialize_skips_empty() {
+        let msg = CreateMessageParams {
+            content: Some("hello".to_owned()),
+            flags: Some(MessageFlags::SUPPRESS_NOTIFICATIONS),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_value(&msg).unwrap(),
+            serde_json::json!({"content": "hello", "flags": 4096})
+        );
+    }
+}
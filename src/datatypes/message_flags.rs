@@ -0,0 +1,36 @@
+#![allow(missing_docs)] // we don't want to write docs for each constant
+
+use bitflags::bitflags;
+use serde::{Deserialize, Serialize};
+
+bitflags! {
+    /// Flags of a message, only [`SUPPRESS_EMBEDS`][Self::SUPPRESS_EMBEDS] and
+    /// [`SUPPRESS_NOTIFICATIONS`][Self::SUPPRESS_NOTIFICATIONS] can be set when sending a message.
+    ///
+    /// Detailed description of what each flag does can be seen in the [discord docs](https://discord.com/developers/docs/resources/message#message-object-message-flags)
+    pub struct MessageFlags: u32 {
+        const CROSSPOSTED = 1 << 0;
+        const IS_CROSSPOST = 1 << 1;
+        const SUPPRESS_EMBEDS = 1 << 2;
+        const SOURCE_MESSAGE_DELETED = 1 << 3;
+        const URGENT = 1 << 4;
+        const HAS_THREAD = 1 << 5;
+        const EPHEMERAL = 1 << 6;
+        const LOADING = 1 << 7;
+        const FAILED_TO_MENTION_SOME_ROLES_IN_THREAD = 1 << 8;
+        const SUPPRESS_NOTIFICATIONS = 1 << 12;
+        const IS_VOICE_MESSAGE = 1 << 13;
+    }
+}
+
+impl Serialize for MessageFlags {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bits().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageFlags {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(Self::from_bits_truncate)
+    }
+}
@@ -1,9 +1,82 @@
 //! Types used by discord
 
+mod application;
+mod application_command;
+mod attachment;
+mod auto_moderation;
+mod channel;
+mod component;
+mod embed;
+mod emoji;
+mod guild;
+mod integration;
 mod intents;
+mod interaction;
+mod invite;
+mod member;
 mod message;
+mod message_flags;
+mod permissions;
+mod poll;
+mod presence;
+mod role;
+mod scheduled_event;
 mod snowflake;
+mod stage_instance;
+mod user;
+mod webhook;
 
-pub use message::{Message, CreateMessageParams};
+pub use application::{ApplicationFlags, PartialApplication};
+pub use application_command::{
+    ApplicationCommand, ApplicationCommandOption, ApplicationCommandOptionChoice,
+    ApplicationCommandOptionType, ApplicationCommandPermission, ApplicationCommandPermissionType,
+    ApplicationCommandType, ApplicationIntegrationType, CommandOptionValue,
+    CreateApplicationCommandParams, EditApplicationCommandParams,
+    GuildApplicationCommandPermissions, InteractionContextType, Localizations,
+};
+pub use attachment::{Attachment, AttachmentFile};
+pub use auto_moderation::{
+    AutoModerationAction, AutoModerationActionExecution, AutoModerationActionMetadata,
+    AutoModerationActionType, AutoModerationEventType, AutoModerationRule,
+    AutoModerationTriggerMetadata, AutoModerationTriggerType, CreateAutoModerationRuleParams,
+    KeywordPresetType, ModifyAutoModerationRuleParams,
+};
+pub use channel::{ChannelType, PartialChannel};
+pub use component::{
+    ActionRow, Button, ButtonStyle, Component, ComponentType, SelectDefaultValue,
+    SelectDefaultValueType, SelectMenu, SelectOption, TextInput, TextInputStyle,
+};
+pub use embed::{
+    Embed, EmbedAuthor, EmbedBuilder, EmbedField, EmbedFooter, EmbedImage, EmbedProvider,
+    EmbedVideo,
+};
+pub use emoji::{EmojiRef, GetReactionsParams, PartialEmoji, ReactionType};
+pub use guild::Guild;
+pub use integration::{Integration, IntegrationAccount, IntegrationExpireBehavior};
+pub use interaction::{
+    ApplicationCommandData, CommandDataOption, Interaction, InteractionCallbackType,
+    InteractionData, InteractionMessage, InteractionResponse, InteractionType,
+    MessageComponentData, Modal, ModalBuilder, ModalSubmitData, ResolvedData,
+};
+pub(crate) use interaction::MAX_AUTOCOMPLETE_CHOICES;
+pub use invite::{Invite, InviteTargetType};
+pub use member::GuildMember;
+pub use message::{
+    AllowedMentionType, AllowedMentions, CreateMessageParams, EditMessageParams,
+    GetChannelMessagesParams, Message, MessageReference, PartialAttachment,
+};
+pub use message_flags::MessageFlags;
+pub use permissions::Permissions;
+pub use poll::{PollAnswer, PollCreateRequest, PollEmoji, PollMedia};
+pub use presence::{Activity, ActivityType, Presence, Status};
+pub use role::Role;
+pub use scheduled_event::{
+    CreateGuildScheduledEventParams, GetGuildScheduledEventUsersParams, GuildScheduledEvent, GuildScheduledEventEntityMetadata, GuildScheduledEventEntityType,
+    GuildScheduledEventPrivacyLevel, GuildScheduledEventStatus, GuildScheduledEventUser,
+    ModifyGuildScheduledEventParams,
+};
 pub use snowflake::Snowflake;
+pub use stage_instance::{StageInstance, StagePrivacyLevel};
+pub use user::User;
+pub use webhook::ExecuteWebhookParams;
 pub use intents::Intents;
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use super::Snowflake;
+
+/// Emoji shown next to a poll answer
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct PollEmoji {
+    /// Id of a custom emoji
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Snowflake>,
+    /// Unicode emoji
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Content of a poll question or answer
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct PollMedia {
+    /// Text of the question (max 300 characters) or answer (max 55 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Emoji of a answer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<PollEmoji>,
+}
+
+/// Answer that can be voted for
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct PollAnswer {
+    /// Id of the answer, only set in received polls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer_id: Option<u32>,
+    /// Content of the answer
+    pub poll_media: PollMedia,
+}
+
+/// Poll to send with [`CreateMessageParams::poll`][super::CreateMessageParams::poll]
+#[derive(Serialize, Default, Clone, Debug)]
+pub struct PollCreateRequest {
+    /// The question
+    pub question: PollMedia,
+    /// Answers that can be voted for (max 10)
+    pub answers: Vec<PollAnswer>,
+    /// How many hours the poll is open for (max 768), defaults to `24`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u32>,
+    /// Can users vote for multiple answers
+    pub allow_multiselect: bool,
+}
@@ -0,0 +1,163 @@
+use serde::Serialize;
+
+int_enum! {
+    /// Kind of activity shown in a presence
+    pub enum ActivityType: u8 {
+        /// `Playing {name}`
+        Playing = 0,
+        /// `Streaming {name}`, with a twitch or youtube url
+        Streaming = 1,
+        /// `Listening to {name}`
+        Listening = 2,
+        /// `Watching {name}`
+        Watching = 3,
+        /// `{emoji} {state}`
+        Custom = 4,
+        /// `Competing in {name}`
+        Competing = 5,
+    }
+}
+
+/// Activity shown in the presence of the bot
+///
+/// # Example
+/// ```
+/// # use vivcord::datatypes::Activity;
+/// let activity = Activity::watching("the server");
+/// ```
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Activity {
+    /// Name of the activity
+    pub name: String,
+    /// Kind of activity
+    #[serde(rename = "type")]
+    pub kind: ActivityType,
+    /// Stream url, only used with [`ActivityType::Streaming`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Status text, used with [`ActivityType::Custom`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+impl Activity {
+    /// Activity of the given kind
+    pub fn new<N: Into<String>>(kind: ActivityType, name: N) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            url: None,
+            state: None,
+        }
+    }
+
+    /// `Playing {name}`
+    pub fn playing<N: Into<String>>(name: N) -> Self {
+        Self::new(ActivityType::Playing, name)
+    }
+
+    /// `Streaming {name}`, the url must be a twitch or youtube url
+    pub fn streaming<N: Into<String>, U: Into<String>>(name: N, url: U) -> Self {
+        Self {
+            url: Some(url.into()),
+            ..Self::new(ActivityType::Streaming, name)
+        }
+    }
+
+    /// `Listening to {name}`
+    pub fn listening<N: Into<String>>(name: N) -> Self {
+        Self::new(ActivityType::Listening, name)
+    }
+
+    /// `Watching {name}`
+    pub fn watching<N: Into<String>>(name: N) -> Self {
+        Self::new(ActivityType::Watching, name)
+    }
+
+    /// `Competing in {name}`
+    pub fn competing<N: Into<String>>(name: N) -> Self {
+        Self::new(ActivityType::Competing, name)
+    }
+
+    /// Custom status text
+    pub fn custom<S: Into<String>>(state: S) -> Self {
+        Self {
+            state: Some(state.into()),
+            ..Self::new(ActivityType::Custom, "Custom Status")
+        }
+    }
+}
+
+/// Online status of the bot
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    /// Online
+    #[default]
+    Online,
+    /// Do not disturb
+    Dnd,
+    /// Away
+    Idle,
+    /// Shown as offline
+    Invisible,
+    /// Offline
+    Offline,
+}
+
+/// Presence of the bot, sent when connecting to the gateway
+///
+/// # Example
+/// ```
+/// # use vivcord::datatypes::{Activity, Presence, Status};
+/// let presence = Presence::new(Status::Idle).activity(Activity::playing("with the api"));
+/// ```
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Presence {
+    /// Unix time in milliseconds the bot went idle
+    pub since: Option<u64>,
+    /// Activities shown
+    pub activities: Vec<Activity>,
+    /// Online status
+    pub status: Status,
+    /// Is the bot away
+    pub afk: bool,
+}
+
+impl Presence {
+    /// Presence with the given status and no activities
+    #[must_use]
+    pub fn new(status: Status) -> Self {
+        Self {
+            status,
+            ..Default::default()
+        }
+    }
+
+    /// Add a activity
+    #[must_use]
+    pub fn activity(mut self, activity: Activity) -> Self {
+        self.activities.push(activity);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_presence() {
+        let presence = Presence::new(Status::Dnd).activity(Activity::custom("busy"));
+
+        assert_eq!(
+            serde_json::to_value(&presence).unwrap(),
+            serde_json::json!({
+                "since": null,
+                "activities": [{"name": "Custom Status", "type": 4, "state": "busy"}],
+                "status": "dnd",
+                "afk": false
+            })
+        );
+    }
+}
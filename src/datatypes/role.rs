@@ -0,0 +1,27 @@
+use serde::Deserialize;
+
+use super::Snowflake;
+
+/// Role in a guild
+#[derive(Deserialize, Clone, Debug)]
+pub struct Role {
+    /// Role id, the `@everyone` role has the same id as the guild
+    pub id: Snowflake,
+    /// Name of the role
+    pub name: String,
+    /// Color as `0xRRGGBB`, `0` means no color
+    pub color: u32,
+    /// Is the role shown separately in the member list
+    pub hoist: bool,
+    /// Position in the role list, higher roles are shown first
+    pub position: i32,
+    /// Permissions of the role, as a bitset string
+    pub permissions: String,
+    /// Is the role managed by a integration
+    pub managed: bool,
+    /// Can the role be mentioned by everyone
+    pub mentionable: bool,
+}
+
+to_snowflake_simple!(Role);
+comp_by_field!(Role, self.id);
@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+
+use super::{GuildMember, Snowflake, User};
+
+int_enum! {
+    /// Who can see a [`GuildScheduledEvent`]
+    pub enum GuildScheduledEventPrivacyLevel: u8 {
+        /// Only visible to guild members
+        GuildOnly = 2,
+    }
+}
+
+int_enum! {
+    /// Where a [`GuildScheduledEvent`] takes place
+    pub enum GuildScheduledEventEntityType: u8 {
+        /// In a stage channel
+        StageInstance = 1,
+        /// In a voice channel
+        Voice = 2,
+        /// Somewhere outside of discord, [`GuildScheduledEventEntityMetadata::location`] is required
+        External = 3,
+    }
+}
+
+int_enum! {
+    /// Status of a [`GuildScheduledEvent`]
+    ///
+    /// Events can only go from `Scheduled` to `Active` to `Completed`, or from `Scheduled` to `Canceled`.
+    pub enum GuildScheduledEventStatus: u8 {
+        /// Event has not started yet
+        Scheduled = 1,
+        /// Event is happening right now
+        Active = 2,
+        /// Event has ended
+        Completed = 3,
+        /// Event was canceled before it started
+        Canceled = 4,
+    }
+}
+
+/// Extra information about where a [`GuildScheduledEvent`] takes place
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct GuildScheduledEventEntityMetadata {
+    /// Location of a [`External`][GuildScheduledEventEntityType::External] event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+/// Event scheduled in a guild
+#[derive(Deserialize, Clone, Debug)]
+pub struct GuildScheduledEvent {
+    /// Event id
+    pub id: Snowflake,
+    /// Guild the event belongs to
+    pub guild_id: Snowflake,
+    /// Channel the event takes place in, [`None`] for [`External`][GuildScheduledEventEntityType::External] events
+    pub channel_id: Option<Snowflake>,
+    /// Id of the user that created the event
+    pub creator_id: Option<Snowflake>,
+    /// Name of the event
+    pub name: String,
+    /// Description of the event
+    pub description: Option<String>,
+    /// ISO8601 timestamp of when the event starts
+    pub scheduled_start_time: String,
+    /// ISO8601 timestamp of when the event ends, always set for [`External`][GuildScheduledEventEntityType::External] events
+    pub scheduled_end_time: Option<String>,
+    /// Who can see the event
+    pub privacy_level: GuildScheduledEventPrivacyLevel,
+    /// Status of the event
+    pub status: GuildScheduledEventStatus,
+    /// Where the event takes place
+    pub entity_type: GuildScheduledEventEntityType,
+    /// Id of the entity (such as the stage instance) associated with the event
+    pub entity_id: Option<Snowflake>,
+    /// Extra information about where the event takes place
+    pub entity_metadata: Option<GuildScheduledEventEntityMetadata>,
+    /// User that created the event
+    pub creator: Option<User>,
+    /// Amount of users subscribed to the event, only included when requested
+    pub user_count: Option<u32>,
+    /// [Cover image hash](https://discord.com/developers/docs/reference#image-formatting)
+    pub image: Option<String>,
+}
+
+to_snowflake_simple!(GuildScheduledEvent);
+comp_by_field!(GuildScheduledEvent, self.id);
+
+/// User subscribed to a [`GuildScheduledEvent`]
+#[derive(Deserialize, Clone, Debug)]
+pub struct GuildScheduledEventUser {
+    /// Event the user is subscribed to
+    pub guild_scheduled_event_id: Snowflake,
+    /// The subscribed user
+    pub user: User,
+    /// Guild member data of the user, only set when requested with [`GetGuildScheduledEventUsersParams::with_member`]
+    pub member: Option<GuildMember>,
+}
+
+/// Fields that can be passed to [`create_guild_scheduled_event`][crate::Api::create_guild_scheduled_event]
+#[derive(Serialize, Debug, Clone)]
+pub struct CreateGuildScheduledEventParams {
+    /// Channel to host the event in, required unless the event is [`External`][GuildScheduledEventEntityType::External]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Snowflake>,
+    /// Location of the event, required for [`External`][GuildScheduledEventEntityType::External] events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_metadata: Option<GuildScheduledEventEntityMetadata>,
+    /// Name of the event
+    pub name: String,
+    /// Who can see the event
+    pub privacy_level: GuildScheduledEventPrivacyLevel,
+    /// ISO8601 timestamp of when the event starts
+    pub scheduled_start_time: String,
+    /// ISO8601 timestamp of when the event ends, required for [`External`][GuildScheduledEventEntityType::External] events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_end_time: Option<String>,
+    /// Description of the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Where the event takes place
+    pub entity_type: GuildScheduledEventEntityType,
+    /// Cover image as a [data uri](https://discord.com/developers/docs/reference#image-data)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+}
+
+/// Fields that can be passed to [`modify_guild_scheduled_event`][crate::Api::modify_guild_scheduled_event]
+///
+/// Fields left as [`None`] are not changed.
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct ModifyGuildScheduledEventParams {
+    /// Channel to host the event in, use `Some(None)` when changing the event to [`External`][GuildScheduledEventEntityType::External]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Option<Snowflake>>,
+    /// Location of the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_metadata: Option<GuildScheduledEventEntityMetadata>,
+    /// Name of the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Who can see the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacy_level: Option<GuildScheduledEventPrivacyLevel>,
+    /// ISO8601 timestamp of when the event starts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_start_time: Option<String>,
+    /// ISO8601 timestamp of when the event ends
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_end_time: Option<String>,
+    /// Description of the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Where the event takes place
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_type: Option<GuildScheduledEventEntityType>,
+    /// New status, used to start, end or cancel the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<GuildScheduledEventStatus>,
+    /// Cover image as a [data uri](https://discord.com/developers/docs/reference#image-data)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+}
+
+/// Query parameters for [`get_guild_scheduled_event_users`][crate::Api::get_guild_scheduled_event_users]
+#[derive(Serialize, Default, Debug, Clone, Copy)]
+pub struct GetGuildScheduledEventUsersParams {
+    /// Amount of users to return, max `100`
+    pub limit: Option<u8>,
+    /// Only return users with a id before this one
+    pub before: Option<Snowflake>,
+    /// Only return users with a id after this one
+    pub after: Option<Snowflake>,
+    /// Include the guild member data of each user
+    pub with_member: Option<bool>,
+}
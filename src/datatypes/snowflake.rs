@@ -1,13 +1,46 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use serde::{Deserialize, Serialize};
 
+/// First millisecond of 2015, all snowflake timestamps are relative to this.
+const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
 /// Holds a discord id
 /// 
 /// Discord ids actually contain a timestamp of creation.
-#[derive(Deserialize, Serialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Deserialize, Serialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 // Discord api gives the number id as a string
-#[serde(from = "String")]
+#[serde(from = "String", into = "String")]
 pub struct Snowflake(pub u64);
-// TODO: Add support for timestamp
+
+impl Snowflake {
+    /// Milliseconds since the unix epoch when this id was created
+    #[must_use]
+    pub fn timestamp(self) -> u64 {
+        (self.0 >> 22) + DISCORD_EPOCH
+    }
+
+    /// Time when this id was created
+    #[must_use]
+    pub fn created_at(self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.timestamp())
+    }
+
+    /// Lowest id created at `time`, useful for the `before` and `after` filters of the api.
+    /// Times before 2015 give the id of the start of 2015.
+    #[must_use]
+    pub fn from_time(time: SystemTime) -> Self {
+        let millis = time.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+        let millis = u64::try_from(millis).unwrap_or(u64::MAX);
+        Self(millis.saturating_sub(DISCORD_EPOCH) << 22)
+    }
+}
+
+impl std::fmt::Display for Snowflake {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
 
 
 impl From<String> for Snowflake {
@@ -22,6 +55,12 @@ impl From<u64> for Snowflake {
     }
 }
 
+impl From<Snowflake> for String {
+    fn from(snow: Snowflake) -> Self {
+       snow.0.to_string()
+    }
+}
+
 impl From<Snowflake> for u64 {
     fn from(snow: Snowflake) -> Self {
        snow.0 
@@ -40,6 +79,15 @@ mod tests {
         assert_eq!(snow.0, 123);
     }
 
+    #[test]
+    fn test_from_time() {
+        let time = std::time::UNIX_EPOCH + std::time::Duration::from_millis(1_700_000_000_123);
+        let snow = Snowflake::from_time(time);
+
+        assert_eq!(snow.created_at(), time);
+        assert_eq!(Snowflake::from_time(std::time::UNIX_EPOCH), Snowflake(0));
+    }
+
     #[test]
     fn test_from_u64() {
         let snow = Snowflake::from(123);
@@ -59,4 +107,17 @@ mod tests {
 
         assert_eq!(snow.0, 123);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_serialize_as_string() {
+        assert_eq!(serde_json::to_string(&Snowflake(123)).unwrap(), "\"123\"");
+    }
+
+    #[test]
+    fn test_timestamp() {
+        // example from the discord docs
+        let snow = Snowflake(175_928_847_299_117_063);
+
+        assert_eq!(snow.timestamp(), 1_462_015_105_796);
+    }
+}
@@ -0,0 +1,33 @@
+use serde::Deserialize;
+
+use super::Snowflake;
+
+int_enum! {
+    /// Who can see a stage instance
+    pub enum StagePrivacyLevel: u8 {
+        /// Visible publicly (deprecated by discord)
+        Public = 1,
+        /// Only visible to guild members
+        GuildOnly = 2,
+    }
+}
+
+/// Live stage in a stage channel
+#[derive(Deserialize, Clone, Debug)]
+pub struct StageInstance {
+    /// Stage instance id
+    pub id: Snowflake,
+    /// Guild of the stage channel
+    pub guild_id: Snowflake,
+    /// Stage channel id
+    pub channel_id: Snowflake,
+    /// Topic of the stage
+    pub topic: String,
+    /// Who can see the stage
+    pub privacy_level: StagePrivacyLevel,
+    /// Scheduled event this stage was started from
+    pub guild_scheduled_event_id: Option<Snowflake>,
+}
+
+to_snowflake_simple!(StageInstance);
+comp_by_field!(StageInstance, self.id);
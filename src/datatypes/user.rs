@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+use super::Snowflake;
+
+/// Discord User
+///
+/// This can be both a normal user and a bot account.
+#[derive(Deserialize, Clone, Debug)]
+pub struct User {
+    /// User id
+    pub id: Snowflake,
+    /// Username, not unique across the platform
+    pub username: String,
+    /// Legacy 4 digit tag, `"0"` for users that have migrated to the new username system
+    pub discriminator: String,
+    /// Display name, if set by the user
+    pub global_name: Option<String>,
+    /// [Avatar hash](https://discord.com/developers/docs/reference#image-formatting)
+    pub avatar: Option<String>,
+    /// Is this user a bot account
+    #[serde(default)]
+    pub bot: bool,
+    /// Is this user an official discord system user
+    #[serde(default)]
+    pub system: bool,
+}
+
+to_snowflake_simple!(User);
+comp_by_field!(User, self.id);
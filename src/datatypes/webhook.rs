@@ -0,0 +1,92 @@
+use serde::Serialize;
+
+use super::message::validate_message;
+use super::{
+    AllowedMentions, AttachmentFile, Component, Embed, MessageFlags, PartialAttachment, PollCreateRequest,
+};
+
+/// Fields that can be passed to [`execute_webhook`][crate::Api::execute_webhook]
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct ExecuteWebhookParams {
+    /// Content to send (max 2000 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Override the name of the webhook
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Override the avatar of the webhook
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    /// Is this a text to speech message
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub tts: bool,
+    /// Embeds to show (max 10)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub embeds: Vec<Embed>,
+    /// Which mentions notify people
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+    /// Action rows of interactive components (max 5),
+    /// only webhooks owned by a application can send interactive components
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+    /// Attachments to list besides the uploaded files, a entry for each of [`files`][Self::files] is added when sending
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<PartialAttachment>,
+    /// Files to upload
+    #[serde(skip)]
+    pub files: Vec<AttachmentFile>,
+    /// Flags to set, such as [`MessageFlags::SUPPRESS_EMBEDS`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<MessageFlags>,
+    /// Name of the thread to create, when the webhook is in a forum channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_name: Option<String>,
+    /// Poll to create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<PollCreateRequest>,
+}
+
+impl ExecuteWebhookParams {
+    /// Check the message against discords limits, this is done by [`execute_webhook`][crate::Api::execute_webhook] before sending.
+    ///
+    /// # Errors
+    /// [`Error::ValidationErr`][crate::api::Error::ValidationErr] describing the first limit that was exceeded.
+    pub fn validate(&self) -> Result<(), crate::api::Error> {
+        validate_message(self.content.as_deref(), &self.embeds, &self.components)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::{ActionRow, EmbedBuilder, TextInput, TextInputStyle};
+
+    #[test]
+    fn test_validate() {
+        let valid = ExecuteWebhookParams {
+            content: Some("hello".to_owned()),
+            ..Default::default()
+        };
+        assert!(valid.validate().is_ok());
+
+        let long = ExecuteWebhookParams {
+            content: Some("a".repeat(2001)),
+            ..Default::default()
+        };
+        assert!(long.validate().is_err());
+
+        let embeds = ExecuteWebhookParams {
+            embeds: vec![EmbedBuilder::new().title("hi").build().unwrap(); 11],
+            ..Default::default()
+        };
+        assert!(embeds.validate().is_err());
+
+        let row = ActionRow::new([TextInput::new(TextInputStyle::Short, "name", "Name")]);
+        let text_input = ExecuteWebhookParams {
+            components: vec![row.into()],
+            ..Default::default()
+        };
+        assert!(text_input.validate().is_err());
+    }
+}
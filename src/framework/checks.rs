@@ -0,0 +1,459 @@
+//! Preconditions of slash and text commands
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::datatypes::{Interaction, Message, Permissions, Snowflake};
+
+/// What a invocation of a command is checked against
+#[derive(Debug, Clone, Default)]
+pub struct CheckData {
+    /// Guild the command was used in, `None` in dms
+    pub guild_id: Option<Snowflake>,
+    /// Channel the command was used in
+    pub channel_id: Option<Snowflake>,
+    /// User that used the command
+    pub user_id: Option<Snowflake>,
+    /// Roles of the user
+    pub roles: Vec<Snowflake>,
+    /// Permissions of the user in the channel, `None` if not known
+    pub permissions: Option<Permissions>,
+    /// Permissions of the bot in the channel, `None` if not known
+    pub bot_permissions: Option<Permissions>,
+    /// Is the channel age restricted, `None` if not known
+    pub nsfw: Option<bool>,
+}
+
+impl From<&Interaction> for CheckData {
+    fn from(interaction: &Interaction) -> Self {
+        let member = interaction.member.as_ref();
+        Self {
+            guild_id: interaction.guild_id,
+            channel_id: interaction.channel_id,
+            user_id: interaction.author().map(|user| user.id),
+            roles: member
+                .map(|member| member.roles.clone())
+                .unwrap_or_default(),
+            permissions: member
+                .and_then(|member| member.permissions.as_deref())
+                .and_then(Permissions::parse),
+            bot_permissions: interaction
+                .app_permissions
+                .as_deref()
+                .and_then(Permissions::parse),
+            nsfw: interaction.channel.as_ref().map(|channel| channel.nsfw),
+        }
+    }
+}
+
+/// Messages do not include permissions or if the channel is age restricted,
+/// see [`PrefixFramework::check_data`][super::prefix::PrefixFramework::check_data] for filling them in.
+impl From<&Message> for CheckData {
+    fn from(message: &Message) -> Self {
+        Self {
+            guild_id: message.guild_id,
+            channel_id: Some(message.channel_id),
+            user_id: Some(message.author.id),
+            roles: message
+                .member
+                .as_ref()
+                .map(|member| member.roles.clone())
+                .unwrap_or_default(),
+            permissions: None,
+            bot_permissions: None,
+            nsfw: None,
+        }
+    }
+}
+
+/// Precondition that must pass before a command runs
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Check {
+    /// Only usable in guilds
+    GuildOnly,
+    /// Only usable in dms
+    DmOnly,
+    /// Only usable by the owners set on the framework
+    OwnerOnly,
+    /// The user needs all of these permissions, always passes in dms
+    UserPermissions(Permissions),
+    /// The bot needs all of these permissions, always passes in dms
+    BotPermissions(Permissions),
+    /// The user needs at least one of these roles
+    AnyRole(Vec<Snowflake>),
+    /// Only usable in age restricted channels
+    NsfwOnly,
+    /// Limit how often the command can be used
+    Cooldown(Cooldown),
+}
+
+impl Check {
+    /// Check everything except cooldowns
+    fn evaluate(&self, data: &CheckData, owners: &[Snowflake]) -> Result<(), CheckFailure> {
+        let missing = |required: Permissions, actual: Option<Permissions>| match actual {
+            _ if data.guild_id.is_none() => Ok(()),
+            Some(actual) if actual.contains(Permissions::ADMINISTRATOR) => Ok(()),
+            Some(actual) if actual.contains(required) => Ok(()),
+            Some(actual) => Err(required - actual),
+            None => Err(required),
+        };
+
+        match self {
+            Self::GuildOnly if data.guild_id.is_none() => Err(CheckFailure::GuildOnly),
+            Self::DmOnly if data.guild_id.is_some() => Err(CheckFailure::DmOnly),
+            Self::OwnerOnly if !data.user_id.is_some_and(|user| owners.contains(&user)) => {
+                Err(CheckFailure::NotOwner)
+            }
+            Self::UserPermissions(required) => {
+                missing(*required, data.permissions).map_err(|missing| {
+                    CheckFailure::MissingPermissions {
+                        missing,
+                        known: data.permissions.is_some(),
+                    }
+                })
+            }
+            Self::BotPermissions(required) => {
+                missing(*required, data.bot_permissions).map_err(|missing| {
+                    CheckFailure::BotMissingPermissions {
+                        missing,
+                        known: data.bot_permissions.is_some(),
+                    }
+                })
+            }
+            Self::AnyRole(roles) if !roles.iter().any(|role| data.roles.contains(role)) => {
+                Err(CheckFailure::MissingRole(roles.clone()))
+            }
+            Self::NsfwOnly if data.nsfw != Some(true) => Err(CheckFailure::NsfwOnly),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Run all checks, cooldowns are only used when every other check passes and all of them allow the use
+pub(crate) fn run_checks<'a, I>(
+    checks: I,
+    data: &CheckData,
+    owners: &[Snowflake],
+) -> Result<(), CheckFailure>
+where
+    I: IntoIterator<Item = &'a Check>,
+    I::IntoIter: Clone,
+{
+    let checks = checks.into_iter();
+    for check in checks.clone() {
+        check.evaluate(data, owners)?;
+    }
+
+    let now = Instant::now();
+    let mut used: Vec<&Cooldown> = Vec::new();
+    for check in checks {
+        let Check::Cooldown(cooldown) = check else {
+            continue;
+        };
+        if let Err(retry_after) = cooldown.try_use(data, now) {
+            for cooldown in used {
+                cooldown.undo(data, now);
+            }
+            return Err(CheckFailure::Cooldown { retry_after });
+        }
+        used.push(cooldown);
+    }
+    Ok(())
+}
+
+/// What a [`Cooldown`] is counted per
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CooldownBucket {
+    /// Each user has their own limit
+    User,
+    /// Each channel has its own limit
+    Channel,
+    /// Each guild has its own limit, dms are limited per channel
+    Guild,
+    /// One limit shared by everyone
+    Global,
+}
+
+/// Allow a command to be used `rate` times every `per`, counted per [`CooldownBucket`].
+///
+/// Clones share the same counts.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use vivcord::framework::{Check, Cooldown, CooldownBucket};
+/// // 3 uses per user every 10 seconds
+/// let check = Check::Cooldown(Cooldown::new(CooldownBucket::User, 3, Duration::from_secs(10)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cooldown {
+    bucket: CooldownBucket,
+    rate: usize,
+    per: Duration,
+    /// Times of the uses within the window, per bucket key
+    uses: Arc<Mutex<HashMap<Option<Snowflake>, VecDeque<Instant>>>>,
+}
+
+impl Cooldown {
+    /// Allow `rate` uses every `per`
+    #[must_use]
+    pub fn new(bucket: CooldownBucket, rate: usize, per: Duration) -> Self {
+        Self {
+            bucket,
+            rate,
+            per,
+            uses: Arc::default(),
+        }
+    }
+
+    /// Key of the bucket the invocation is counted in
+    fn key(&self, data: &CheckData) -> Option<Snowflake> {
+        match self.bucket {
+            CooldownBucket::User => data.user_id,
+            CooldownBucket::Channel => data.channel_id,
+            CooldownBucket::Guild => data.guild_id.or(data.channel_id),
+            CooldownBucket::Global => None,
+        }
+    }
+
+    /// Count a use if the command can be used now, otherwise return how long until it can be used again.
+    ///
+    /// Also drops the uses of other keys that are no longer within the window.
+    fn try_use(&self, data: &CheckData, now: Instant) -> Result<(), Duration> {
+        let in_window = |time: &Instant| now.duration_since(*time) < self.per;
+
+        let mut uses = self.uses.lock().unwrap();
+        uses.retain(|_, times| times.back().is_some_and(in_window));
+        let times = uses.entry(self.key(data)).or_default();
+        while times.front().is_some_and(|time| !in_window(time)) {
+            times.pop_front();
+        }
+
+        if times.len() < self.rate {
+            times.push_back(now);
+            return Ok(());
+        }
+        Err(times.front().map_or(Duration::ZERO, |oldest| {
+            self.per.saturating_sub(now.duration_since(*oldest))
+        }))
+    }
+
+    /// Take back a use counted by [`try_use`][Self::try_use]
+    fn undo(&self, data: &CheckData, now: Instant) {
+        let key = self.key(data);
+        let mut uses = self.uses.lock().unwrap();
+        let Some(times) = uses.get_mut(&key) else {
+            return;
+        };
+        if let Some(index) = times.iter().rposition(|time| *time == now) {
+            times.remove(index);
+        }
+        if times.is_empty() {
+            uses.remove(&key);
+        }
+    }
+}
+
+/// Reason a command was not run, the messages are meant to be shown to the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CheckFailure {
+    /// Used outside of a guild
+    GuildOnly,
+    /// Used in a guild
+    DmOnly,
+    /// Used by someone that is not a owner
+    NotOwner,
+    /// The user is missing permissions
+    MissingPermissions {
+        /// The missing permissions
+        missing: Permissions,
+        /// Were the permissions of the user known, if not `missing` contains all required permissions
+        known: bool,
+    },
+    /// The bot is missing permissions
+    BotMissingPermissions {
+        /// The missing permissions
+        missing: Permissions,
+        /// Were the permissions of the bot known, if not `missing` contains all required permissions
+        known: bool,
+    },
+    /// The user has none of the roles
+    MissingRole(Vec<Snowflake>),
+    /// Used outside of a age restricted channel
+    NsfwOnly,
+    /// Used too often
+    Cooldown {
+        /// How long until the command can be used again
+        retry_after: Duration,
+    },
+}
+
+impl std::fmt::Display for CheckFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GuildOnly => write!(f, "this command can only be used in servers"),
+            Self::DmOnly => write!(f, "this command can only be used in dms"),
+            Self::NotOwner => write!(f, "this command can only be used by the bot owners"),
+            Self::MissingPermissions { missing, .. } => {
+                write!(f, "you are missing permissions: {missing:?}")
+            }
+            Self::BotMissingPermissions { missing, .. } => {
+                write!(f, "the bot is missing permissions: {missing:?}")
+            }
+            Self::MissingRole(roles) => {
+                let mentions: Vec<_> = roles.iter().map(|role| format!("<@&{}>", role.0)).collect();
+                write!(f, "you need one of these roles: {}", mentions.join(", "))
+            }
+            Self::NsfwOnly => write!(
+                f,
+                "this command can only be used in age restricted channels"
+            ),
+            Self::Cooldown { retry_after } => write!(
+                f,
+                "this command is on cooldown, try again in {:.1} seconds",
+                retry_after.as_secs_f64()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CheckFailure {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guild_data() -> CheckData {
+        CheckData {
+            guild_id: Some(Snowflake(1)),
+            channel_id: Some(Snowflake(2)),
+            user_id: Some(Snowflake(3)),
+            roles: vec![Snowflake(4)],
+            permissions: Some(Permissions::SEND_MESSAGES | Permissions::KICK_MEMBERS),
+            bot_permissions: Some(Permissions::ADMINISTRATOR),
+            nsfw: Some(false),
+        }
+    }
+
+    #[test]
+    fn test_checks() {
+        let data = guild_data();
+        let owners = [Snowflake(3)];
+        let run = |check: Check| run_checks(&[check], &data, &owners);
+
+        assert_eq!(run(Check::GuildOnly), Ok(()));
+        assert_eq!(run(Check::DmOnly), Err(CheckFailure::DmOnly));
+        assert_eq!(run(Check::OwnerOnly), Ok(()));
+        assert_eq!(
+            run(Check::UserPermissions(
+                Permissions::KICK_MEMBERS | Permissions::BAN_MEMBERS
+            )),
+            Err(CheckFailure::MissingPermissions {
+                missing: Permissions::BAN_MEMBERS,
+                known: true
+            })
+        );
+        assert_eq!(run(Check::BotPermissions(Permissions::BAN_MEMBERS)), Ok(()));
+        assert_eq!(
+            run(Check::AnyRole(vec![Snowflake(5), Snowflake(4)])),
+            Ok(())
+        );
+        assert_eq!(
+            run(Check::AnyRole(vec![Snowflake(5)])),
+            Err(CheckFailure::MissingRole(vec![Snowflake(5)]))
+        );
+        assert_eq!(run(Check::NsfwOnly), Err(CheckFailure::NsfwOnly));
+
+        let dm = CheckData {
+            user_id: Some(Snowflake(3)),
+            ..Default::default()
+        };
+        assert_eq!(
+            run_checks(&[Check::GuildOnly], &dm, &owners),
+            Err(CheckFailure::GuildOnly)
+        );
+        assert_eq!(
+            run_checks(
+                &[Check::UserPermissions(Permissions::BAN_MEMBERS)],
+                &dm,
+                &owners
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_cooldown() {
+        let data = guild_data();
+        let other_user = CheckData {
+            user_id: Some(Snowflake(10)),
+            ..guild_data()
+        };
+        let checks = [Check::Cooldown(Cooldown::new(
+            CooldownBucket::User,
+            2,
+            Duration::from_secs(60),
+        ))];
+
+        assert_eq!(run_checks(&checks, &data, &[]), Ok(()));
+        assert_eq!(run_checks(&checks, &data, &[]), Ok(()));
+        assert!(matches!(
+            run_checks(&checks, &data, &[]),
+            Err(CheckFailure::Cooldown { retry_after }) if retry_after <= Duration::from_secs(60)
+        ));
+        assert_eq!(run_checks(&checks, &other_user, &[]), Ok(()));
+
+        // failing checks do not use up the cooldown
+        let checks = [
+            Check::Cooldown(Cooldown::new(
+                CooldownBucket::Global,
+                1,
+                Duration::from_secs(60),
+            )),
+            Check::DmOnly,
+        ];
+        assert_eq!(run_checks(&checks, &data, &[]), Err(CheckFailure::DmOnly));
+        assert_eq!(run_checks(&checks[..1], &data, &[]), Ok(()));
+
+        // a later cooldown failing gives back the earlier ones
+        let global = Cooldown::new(CooldownBucket::Global, 1, Duration::from_secs(60));
+        let checks = [
+            Check::Cooldown(Cooldown::new(
+                CooldownBucket::User,
+                1,
+                Duration::from_secs(60),
+            )),
+            Check::Cooldown(global.clone()),
+        ];
+        assert_eq!(
+            run_checks(&[Check::Cooldown(global)], &other_user, &[]),
+            Ok(())
+        );
+        assert!(matches!(
+            run_checks(&checks, &data, &[]),
+            Err(CheckFailure::Cooldown { .. })
+        ));
+        assert_eq!(run_checks(&checks[..1], &data, &[]), Ok(()));
+    }
+
+    #[test]
+    fn test_cooldown_evicts_expired() {
+        let cooldown = Cooldown::new(CooldownBucket::User, 1, Duration::from_secs(60));
+        let now = Instant::now();
+        for user in 0..3 {
+            let data = CheckData {
+                user_id: Some(Snowflake(user)),
+                ..Default::default()
+            };
+            assert_eq!(cooldown.try_use(&data, now), Ok(()));
+        }
+        assert_eq!(cooldown.uses.lock().unwrap().len(), 3);
+
+        let later = now + Duration::from_secs(61);
+        assert_eq!(cooldown.try_use(&CheckData::default(), later), Ok(()));
+        assert_eq!(cooldown.uses.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,225 @@
+//! Traits implemented by slash commands and their options
+
+use futures::future::BoxFuture;
+
+use crate::datatypes::{
+    ApplicationCommandData, ApplicationCommandOptionType, Attachment, CommandOptionValue,
+    CreateApplicationCommandParams, PartialChannel, ResolvedData, Role, Snowflake, User,
+};
+
+/// Error returned by a command handler, any error can be converted into this with `?`
+pub type CommandError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Result returned by a command handler
+pub type CommandResult = Result<(), CommandError>;
+
+/// A slash command that can be parsed from the interaction data.
+///
+/// This is normally implemented with `#[derive(Command)]`.
+///
+/// # Example
+/// ```
+/// use vivcord::framework::Command;
+/// use vivcord::datatypes::User;
+///
+/// /// Ban a user from the guild
+/// #[derive(Command)]
+/// struct Ban {
+///     /// Who to ban
+///     user: User,
+///     /// Why they are banned
+///     #[option(max_length = 512)]
+///     reason: Option<String>,
+///     /// Days of messages to delete
+///     #[option(name = "delete_days", min_value = 0, max_value = 7)]
+///     days: Option<i64>,
+/// }
+///
+/// let definition = Ban::definition();
+/// assert_eq!(definition.name, "ban");
+/// assert_eq!(definition.options.len(), 3);
+/// assert!(definition.options[0].required);
+/// ```
+pub trait Command: Sized {
+    /// Name of the command
+    const NAME: &'static str;
+
+    /// Definition to register with discord
+    fn definition() -> CreateApplicationCommandParams;
+
+    /// Read the options of a invocation of the command
+    ///
+    /// # Errors
+    /// If a required option is missing, or has the wrong type.
+    fn parse(data: &ApplicationCommandData) -> Result<Self, CommandParseError>;
+}
+
+/// A command that knows how to run itself, created by `#[command]`.
+///
+/// # Example
+/// ```
+/// use vivcord::framework::{command, CommandContext, CommandResult, Framework};
+///
+/// /// Say something
+/// #[command]
+/// async fn say(ctx: CommandContext<()>, #[option(description = "What to say")] text: String) -> CommandResult {
+///     ctx.reply(text).await?;
+///     Ok(())
+/// }
+///
+/// let framework = Framework::new().register::<say>();
+/// assert_eq!(framework.definitions()[0].name, "say");
+/// ```
+pub trait CommandHandler: Command {
+    /// Context passed to the command, normally [`CommandContext`][super::CommandContext]
+    type Context;
+
+    /// Run the command
+    fn run(context: Self::Context, args: Self) -> BoxFuture<'static, CommandResult>;
+}
+
+/// Type that can be used as a option of a command
+pub trait CommandOption: Sized {
+    /// Option type shown to the user
+    const KIND: ApplicationCommandOptionType;
+    /// Must the user fill in the option
+    const REQUIRED: bool = true;
+
+    /// Read the option value, `value` is `None` when the user did not fill in the option.
+    ///
+    /// # Errors
+    /// If the value is missing or has the wrong type.
+    fn parse(
+        name: &str,
+        value: Option<&CommandOptionValue>,
+        resolved: &ResolvedData,
+    ) -> Result<Self, CommandParseError>;
+}
+
+/// Error reading the options of a command
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CommandParseError {
+    /// A required option was not filled in
+    MissingOption(String),
+    /// The option had a different type than expected, the command definition is likely outdated.
+    InvalidType {
+        /// Name of the option
+        option: String,
+        /// Type the command expected
+        expected: ApplicationCommandOptionType,
+    },
+    /// The referenced user, role, channel or attachment was not included in the interaction
+    Unresolved {
+        /// Name of the option
+        option: String,
+        /// Id of the missing value
+        id: Snowflake,
+    },
+}
+
+impl std::fmt::Display for CommandParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingOption(option) => write!(f, "missing required option {option}"),
+            Self::InvalidType { option, expected } => {
+                write!(f, "option {option} is not of type {expected:?}")
+            }
+            Self::Unresolved { option, id } => {
+                write!(
+                    f,
+                    "option {option} references {} which was not resolved",
+                    id.0
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommandParseError {}
+
+/// Get the value of a required option, or a [`CommandParseError::MissingOption`]
+fn required<'a>(
+    name: &str,
+    value: Option<&'a CommandOptionValue>,
+) -> Result<&'a CommandOptionValue, CommandParseError> {
+    value.ok_or_else(|| CommandParseError::MissingOption(name.to_owned()))
+}
+
+/// Implement [`CommandOption`] for a type stored directly in the value
+macro_rules! value_option {
+    ($ty: ty, $kind: ident, $getter: expr) => {
+        impl CommandOption for $ty {
+            const KIND: ApplicationCommandOptionType = ApplicationCommandOptionType::$kind;
+
+            fn parse(
+                name: &str,
+                value: Option<&CommandOptionValue>,
+                _resolved: &ResolvedData,
+            ) -> Result<Self, CommandParseError> {
+                $getter(required(name, value)?).ok_or_else(|| CommandParseError::InvalidType {
+                    option: name.to_owned(),
+                    expected: Self::KIND,
+                })
+            }
+        }
+    };
+}
+
+/// Implement [`CommandOption`] for a type looked up by id in [`ResolvedData`]
+macro_rules! resolved_option {
+    ($ty: ty, $kind: ident, $field: ident) => {
+        impl CommandOption for $ty {
+            const KIND: ApplicationCommandOptionType = ApplicationCommandOptionType::$kind;
+
+            fn parse(
+                name: &str,
+                value: Option<&CommandOptionValue>,
+                resolved: &ResolvedData,
+            ) -> Result<Self, CommandParseError> {
+                let id = required(name, value)?.as_snowflake().ok_or_else(|| {
+                    CommandParseError::InvalidType {
+                        option: name.to_owned(),
+                        expected: Self::KIND,
+                    }
+                })?;
+                resolved
+                    .$field
+                    .get(&id)
+                    .cloned()
+                    .ok_or_else(|| CommandParseError::Unresolved {
+                        option: name.to_owned(),
+                        id,
+                    })
+            }
+        }
+    };
+}
+
+value_option!(String, String, |value: &CommandOptionValue| value
+    .as_str()
+    .map(ToOwned::to_owned));
+value_option!(i64, Integer, CommandOptionValue::as_i64);
+value_option!(f64, Number, CommandOptionValue::as_f64);
+value_option!(bool, Boolean, CommandOptionValue::as_bool);
+value_option!(Snowflake, Mentionable, CommandOptionValue::as_snowflake);
+
+resolved_option!(User, User, users);
+resolved_option!(Role, Role, roles);
+resolved_option!(PartialChannel, Channel, channels);
+resolved_option!(Attachment, Attachment, attachments);
+
+impl<T: CommandOption> CommandOption for Option<T> {
+    const KIND: ApplicationCommandOptionType = T::KIND;
+    const REQUIRED: bool = false;
+
+    fn parse(
+        name: &str,
+        value: Option<&CommandOptionValue>,
+        resolved: &ResolvedData,
+    ) -> Result<Self, CommandParseError> {
+        value
+            .map(|value| T::parse(name, Some(value), resolved))
+            .transpose()
+    }
+}
@@ -0,0 +1,198 @@
+//! Context passed to command handlers
+
+use std::future::Future;
+use std::time::Duration;
+
+use super::{CommandError, ModalForm, ModalParseError};
+use crate::api::Error;
+use crate::datatypes::{
+    EditMessageParams, Interaction, InteractionMessage, InteractionResponse, InteractionType,
+    Message, Modal,
+};
+use crate::{Context, EventData};
+
+/// The context and the interaction that invoked a command
+#[allow(missing_debug_implementations)]
+#[derive(Clone)]
+pub struct CommandContext<S> {
+    /// Context of the event that contained the interaction
+    pub context: Context<S>,
+    /// Interaction that invoked the command
+    pub interaction: Interaction,
+}
+
+impl<S> CommandContext<S> {
+    /// Respond to the interaction, this must be done within 3 seconds.
+    ///
+    /// # Errors
+    /// See [`Api::create_interaction_response`][crate::Api::create_interaction_response]
+    pub async fn respond(&self, response: InteractionResponse) -> Result<(), Error> {
+        self.context
+            .api
+            .create_interaction_response(self.interaction.id, &self.interaction.token, response)
+            .await
+    }
+
+    /// Respond with a message
+    ///
+    /// # Errors
+    /// See [`Api::create_interaction_response`][crate::Api::create_interaction_response]
+    pub async fn reply<M: Into<InteractionMessage>>(&self, message: M) -> Result<(), Error> {
+        self.respond(InteractionResponse::ChannelMessageWithSource(
+            message.into(),
+        ))
+        .await
+    }
+
+    /// Show a loading state, for commands that take longer than 3 seconds.
+    /// The response is later sent with [`edit_response`][Self::edit_response].
+    ///
+    /// # Errors
+    /// See [`Api::create_interaction_response`][crate::Api::create_interaction_response]
+    pub async fn defer(&self, ephemeral: bool) -> Result<(), Error> {
+        self.respond(InteractionResponse::DeferredChannelMessageWithSource { ephemeral })
+            .await
+    }
+
+    /// Edit the response, or complete a deferred response
+    ///
+    /// # Errors
+    /// See [`Api::edit_original_interaction_response`][crate::Api::edit_original_interaction_response]
+    pub async fn edit_response(&self, msg: EditMessageParams) -> Result<Message, Error> {
+        self.context
+            .api
+            .edit_original_interaction_response(
+                self.interaction.application_id,
+                &self.interaction.token,
+                msg,
+            )
+            .await
+    }
+
+    /// Send a extra message after responding
+    ///
+    /// # Errors
+    /// See [`Api::create_followup_message`][crate::Api::create_followup_message]
+    pub async fn followup<M: Into<InteractionMessage>>(
+        &self,
+        message: M,
+    ) -> Result<Message, Error> {
+        self.context
+            .api
+            .create_followup_message(
+                self.interaction.application_id,
+                &self.interaction.token,
+                message.into(),
+            )
+            .await
+    }
+}
+
+impl<S: Clone> CommandContext<S> {
+    /// Show a modal, this must be the first response to the interaction.
+    ///
+    /// # Errors
+    /// See [`Api::create_interaction_response`][crate::Api::create_interaction_response]
+    pub async fn modal(&self, modal: Modal) -> Result<(), Error> {
+        self.respond(InteractionResponse::Modal(modal)).await
+    }
+
+    /// Wait for the user to submit the modal with `custom_id`,
+    /// returns the context of the submit interaction, or `None` after `timeout`.
+    ///
+    /// The submit interaction must be responded to as well.
+    pub async fn wait_for_modal(&self, custom_id: &str, timeout: Duration) -> Option<Self> {
+        self.modal_submit(custom_id, timeout).await
+    }
+
+    /// Start listening for the modal submit right away, so a submit that comes in before the future is polled is not missed
+    fn modal_submit(
+        &self,
+        custom_id: &str,
+        timeout: Duration,
+    ) -> impl Future<Output = Option<Self>> {
+        let user_id = self.interaction.author().map(|user| user.id);
+        let custom_id = custom_id.to_owned();
+        let submit = self.context.gateway.wait_for(move |event| match event {
+            EventData::InteractionCreate(interaction)
+                if interaction.kind == InteractionType::ModalSubmit
+                    && interaction.author().map(|user| user.id) == user_id
+                    && interaction
+                        .modal_data()
+                        .is_some_and(|data| data.custom_id == custom_id) =>
+            {
+                Some(*interaction)
+            }
+            _ => None,
+        });
+
+        let context = self.context.clone();
+        async move {
+            let interaction = tokio::time::timeout(timeout, submit).await.ok()?;
+            Some(Self {
+                context,
+                interaction,
+            })
+        }
+    }
+
+    /// Values submitted in the modal, when this is the context of a modal submit
+    ///
+    /// # Errors
+    /// If this is not a modal submit, or a required value is missing.
+    pub fn modal_values<F: ModalForm>(&self) -> Result<F, ModalParseError> {
+        let data = self
+            .interaction
+            .modal_data()
+            .ok_or(ModalParseError::NotModalSubmit)?;
+        F::parse(data)
+    }
+
+    /// Show the modal of `F`, wait for the user to submit it and read the values.
+    ///
+    /// Returns the context of the submit interaction together with the values, or `None` after `timeout`.
+    ///
+    /// # Errors
+    /// If the modal could not be shown, or the submitted values could not be read.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use vivcord::framework::{command, CommandContext, CommandResult, ModalForm};
+    ///
+    /// #[derive(ModalForm)]
+    /// struct Report {
+    ///     /// What happened
+    ///     #[input(paragraph)]
+    ///     details: String,
+    /// }
+    ///
+    /// /// Report a problem
+    /// #[command]
+    /// async fn report(ctx: CommandContext<()>) -> CommandResult {
+    ///     let Some((submit, report)) = ctx
+    ///         .prompt::<Report>("report", "Report a problem", Duration::from_secs(300))
+    ///         .await?
+    ///     else {
+    ///         return Ok(());
+    ///     };
+    ///     submit.reply(format!("Got it: {}", report.details)).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn prompt<F: ModalForm>(
+        &self,
+        custom_id: &str,
+        title: &str,
+        timeout: Duration,
+    ) -> Result<Option<(Self, F)>, CommandError> {
+        // listen before showing the modal, a fast submit could otherwise be missed
+        let submit = self.modal_submit(custom_id, timeout);
+        self.modal(F::modal(custom_id, title)).await?;
+        let Some(submit) = submit.await else {
+            return Ok(None);
+        };
+        let values = submit.modal_values()?;
+        Ok(Some((submit, values)))
+    }
+}
@@ -0,0 +1,803 @@
+//! Slash command framework
+//!
+//! Text commands invoked with a prefix are in [`prefix`].
+//!
+//! Commands are defined with `#[derive(Command)]` or `#[command]`,
+//! registered on a [`Framework`], and the framework is given every event to dispatch the commands.
+//!
+//! # Example
+//! ```no_run
+//! use std::sync::Arc;
+//! use vivcord::framework::{command, CommandContext, CommandResult, Framework};
+//! use vivcord::datatypes::User;
+//!
+//! /// Greet a user
+//! #[command]
+//! async fn hello(ctx: CommandContext<()>, #[option(description = "Who to greet")] user: User) -> CommandResult {
+//!     ctx.reply(format!("Hello {}!", user.username)).await?;
+//!     Ok(())
+//! }
+//!
+//! # tokio_test::block_on(async move {
+//! let client = vivcord::Client::new("TOKEN".to_owned(), ());
+//! let framework = Arc::new(Framework::new().register::<hello>());
+//! framework.sync(&client.api, 12345, None).await.unwrap();
+//!
+//! client
+//!     .run(move |event, ctx| {
+//!         let framework = framework.clone();
+//!         async move {
+//!             if let Err(err) = framework.handle(&event, &ctx).await {
+//!                 eprintln!("{err}");
+//!             }
+//!         }
+//!     })
+//!     .await;
+//! # });
+//! ```
+
+mod checks;
+mod command;
+mod context;
+mod modal;
+pub mod prefix;
+
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+use futures::Future;
+
+pub use futures::future::BoxFuture;
+pub use vivcord_macros::{command, Command, ModalForm};
+
+pub use checks::{Check, CheckData, CheckFailure, Cooldown, CooldownBucket};
+pub use command::{
+    Command, CommandError, CommandHandler, CommandOption, CommandParseError, CommandResult,
+};
+pub use context::CommandContext;
+pub use modal::{ModalForm, ModalParseError, ModalValue};
+
+use crate::api::Error;
+use crate::datatypes::{
+    ApplicationCommand, ApplicationCommandData, ApplicationCommandOption,
+    ApplicationCommandOptionChoice, CommandDataOption, CreateApplicationCommandParams, Interaction,
+    InteractionResponse, InteractionType, Snowflake, MAX_AUTOCOMPLETE_CHOICES,
+};
+use crate::{Api, Context, EventData};
+
+/// Parses the interaction data and starts the handler
+type CommandRunner<S> = Box<
+    dyn Fn(
+            CommandContext<S>,
+            &ApplicationCommandData,
+        ) -> Result<BoxFuture<'static, CommandResult>, CommandParseError>
+        + Send
+        + Sync,
+>;
+
+/// Produces the suggestions for a option, from the partial value and the focused option
+type AutocompleteRunner<S> = Box<
+    dyn Fn(
+            CommandContext<S>,
+            String,
+            CommandDataOption,
+        ) -> BoxFuture<'static, Vec<ApplicationCommandOptionChoice>>
+        + Send
+        + Sync,
+>;
+
+/// How long a autocomplete callback can take, counted from when the interaction was created.
+///
+/// Discord requires a response within 3 seconds, this leaves time to send it.
+pub const AUTOCOMPLETE_DEADLINE: Duration = Duration::from_millis(2500);
+
+/// A command registered on the framework
+struct RegisteredCommand<S> {
+    definition: CreateApplicationCommandParams,
+    run: CommandRunner<S>,
+}
+
+/// Error while dispatching a command
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum FrameworkError {
+    /// The options of the command could not be read
+    Parse {
+        /// Name of the command
+        command: String,
+        /// The parse error
+        error: CommandParseError,
+    },
+    /// The command handler returned a error
+    Command {
+        /// Name of the command
+        command: String,
+        /// The returned error
+        error: CommandError,
+    },
+    /// The autocomplete callback did not finish within [`AUTOCOMPLETE_DEADLINE`]
+    AutocompleteTimeout {
+        /// Name of the command
+        command: String,
+        /// Name of the focused option
+        option: String,
+    },
+    /// A check of the command failed, the command was not run
+    Check {
+        /// Name of the command
+        command: String,
+        /// Why the check failed
+        failure: CheckFailure,
+    },
+    /// Sending the response to discord failed
+    Respond {
+        /// Name of the command
+        command: String,
+        /// The api error
+        error: Error,
+    },
+}
+
+impl std::fmt::Display for FrameworkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse { command, error } => write!(f, "failed to parse /{command}: {error}"),
+            Self::Command { command, error } => write!(f, "/{command} failed: {error}"),
+            Self::AutocompleteTimeout { command, option } => write!(
+                f,
+                "autocomplete of {option} in /{command} took longer than {AUTOCOMPLETE_DEADLINE:?}"
+            ),
+            Self::Check { command, failure } => write!(f, "can not use /{command}: {failure}"),
+            Self::Respond { command, error } => {
+                write!(f, "failed to respond to /{command}: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FrameworkError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse { error, .. } => Some(error),
+            Self::Command { error, .. } => Some(&**error),
+            Self::Check { failure, .. } => Some(failure),
+            Self::Respond { error, .. } => Some(error),
+            Self::AutocompleteTimeout { .. } => None,
+        }
+    }
+}
+
+/// Collection of slash commands, that dispatches interactions to them
+#[allow(missing_debug_implementations)]
+pub struct Framework<S> {
+    commands: HashMap<String, RegisteredCommand<S>>,
+    /// Keyed by command name and option name
+    autocompletes: HashMap<(String, String), AutocompleteRunner<S>>,
+    /// Keyed by command name
+    checks: HashMap<String, Vec<Check>>,
+    owners: Vec<Snowflake>,
+}
+
+impl<S> Default for Framework<S> {
+    fn default() -> Self {
+        Self {
+            commands: HashMap::new(),
+            autocompletes: HashMap::new(),
+            checks: HashMap::new(),
+            owners: Vec::new(),
+        }
+    }
+}
+
+impl<S> Framework<S>
+where
+    S: Clone + Send + 'static,
+{
+    /// Framework without any commands
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a command with a separate handler function.
+    /// A command with the same name replaces the earlier one.
+    ///
+    /// # Example
+    /// ```
+    /// use vivcord::framework::{Command, CommandContext, Framework};
+    ///
+    /// /// Check if the bot is alive
+    /// #[derive(Command)]
+    /// struct Ping;
+    ///
+    /// let framework = Framework::new().command(|ctx: CommandContext<()>, _: Ping| async move {
+    ///     ctx.reply("Pong!").await?;
+    ///     Ok(())
+    /// });
+    /// ```
+    #[must_use]
+    pub fn command<C, F, Fut>(mut self, handler: F) -> Self
+    where
+        C: Command + 'static,
+        F: Fn(CommandContext<S>, C) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = CommandResult> + Send + 'static,
+    {
+        let run: CommandRunner<S> = Box::new(move |context, data| {
+            let args = C::parse(data)?;
+            Ok(Box::pin(handler(context, args)))
+        });
+        self.commands.insert(
+            C::NAME.to_owned(),
+            RegisteredCommand {
+                definition: C::definition(),
+                run,
+            },
+        );
+        self
+    }
+
+    /// Add a command created with `#[command]`
+    #[must_use]
+    pub fn register<C>(self) -> Self
+    where
+        C: CommandHandler<Context = CommandContext<S>> + 'static,
+    {
+        self.command::<C, _, _>(C::run)
+    }
+
+    /// Suggest values for a option while the user is typing.
+    ///
+    /// The option must have `autocomplete` enabled, with `#[option(autocomplete)]`.
+    /// The callback gets the partially typed value and the focused option,
+    /// and returns the suggestions, only the first 25 are sent.
+    /// It must finish within [`AUTOCOMPLETE_DEADLINE`] of the interaction being created, otherwise nothing is sent.
+    ///
+    /// # Example
+    /// ```
+    /// use vivcord::framework::{Command, CommandContext, Framework};
+    /// use vivcord::datatypes::ApplicationCommandOptionChoice;
+    ///
+    /// /// Show a color
+    /// #[derive(Command)]
+    /// struct Color {
+    ///     /// Name of the color
+    ///     #[option(autocomplete)]
+    ///     name: String,
+    /// }
+    ///
+    /// let framework = Framework::new()
+    ///     .command(|ctx: CommandContext<()>, args: Color| async move {
+    ///         ctx.reply(args.name).await?;
+    ///         Ok(())
+    ///     })
+    ///     .autocomplete(Color::NAME, "name", |_ctx, partial, _option| async move {
+    ///         ["red", "green", "blue"]
+    ///             .into_iter()
+    ///             .filter(|color| color.starts_with(&partial))
+    ///             .map(|color| ApplicationCommandOptionChoice::new(color, color))
+    ///             .collect()
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn autocomplete<F, Fut>(mut self, command: &str, option: &str, callback: F) -> Self
+    where
+        F: Fn(CommandContext<S>, String, CommandDataOption) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Vec<ApplicationCommandOptionChoice>> + Send + 'static,
+    {
+        let run: AutocompleteRunner<S> =
+            Box::new(move |context, partial, option| Box::pin(callback(context, partial, option)));
+        self.autocompletes
+            .insert((command.to_owned(), option.to_owned()), run);
+        self
+    }
+
+    /// Add a check that must pass before the command runs.
+    ///
+    /// When a check fails the command is not run,
+    /// and [`handle`][Self::handle] returns [`FrameworkError::Check`] with the reason.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use vivcord::framework::{Check, Command, CommandContext, Cooldown, CooldownBucket, Framework};
+    /// use vivcord::datatypes::Permissions;
+    ///
+    /// /// Remove messages
+    /// #[derive(Command)]
+    /// struct Purge;
+    ///
+    /// let framework = Framework::new()
+    ///     .command(|ctx: CommandContext<()>, _: Purge| async move { Ok(()) })
+    ///     .check(Purge::NAME, Check::GuildOnly)
+    ///     .check(Purge::NAME, Check::UserPermissions(Permissions::MANAGE_MESSAGES))
+    ///     .check(
+    ///         Purge::NAME,
+    ///         Check::Cooldown(Cooldown::new(CooldownBucket::Guild, 1, Duration::from_secs(30))),
+    ///     );
+    /// ```
+    #[must_use]
+    pub fn check(mut self, command: &str, check: Check) -> Self {
+        self.checks
+            .entry(command.to_owned())
+            .or_default()
+            .push(check);
+        self
+    }
+
+    /// Users allowed to use commands with [`Check::OwnerOnly`]
+    #[must_use]
+    pub fn owners<I: IntoIterator<Item = Snowflake>>(mut self, owners: I) -> Self {
+        self.owners.extend(owners);
+        self
+    }
+
+    /// Definitions of all commands, sorted by name
+    #[must_use]
+    pub fn definitions(&self) -> Vec<CreateApplicationCommandParams> {
+        let mut definitions: Vec<_> = self
+            .commands
+            .values()
+            .map(|command| command.definition.clone())
+            .collect();
+        definitions.sort_by(|a, b| a.name.cmp(&b.name));
+        definitions
+    }
+
+    /// Register the commands with discord, globally or in a single guild.
+    ///
+    /// The registered commands are only overwritten when they differ from the framework,
+    /// so this can be called every time the bot starts.
+    /// Returns `true` if the commands were updated.
+    ///
+    /// # Errors
+    /// Can be due to connection error to discord, invalid json response.
+    /// [`Error::ValidationErr`] if a command exceeds discords limits.
+    pub async fn sync<A: Into<Snowflake>>(
+        &self,
+        api: &Api,
+        application_id: A,
+        guild_id: Option<Snowflake>,
+    ) -> Result<bool, Error> {
+        let application_id = application_id.into();
+        let definitions = self.definitions();
+
+        let existing = match guild_id {
+            Some(guild_id) => {
+                api.get_guild_application_commands(application_id, guild_id, true)
+                    .await?
+            }
+            None => {
+                api.get_global_application_commands(application_id, true)
+                    .await?
+            }
+        };
+        let unchanged = existing.len() == definitions.len()
+            && definitions.iter().all(|definition| {
+                existing
+                    .iter()
+                    .any(|command| is_same_command(command, definition))
+            });
+        if unchanged {
+            return Ok(false);
+        }
+
+        match guild_id {
+            Some(guild_id) => {
+                api.bulk_overwrite_guild_application_commands(
+                    application_id,
+                    guild_id,
+                    definitions,
+                )
+                .await?;
+            }
+            None => {
+                api.bulk_overwrite_global_application_commands(application_id, definitions)
+                    .await?;
+            }
+        }
+        Ok(true)
+    }
+
+    /// Run the command the event invoked, or answer a autocomplete request.
+    /// Other events are ignored.
+    ///
+    /// # Errors
+    /// If a check failed, the options could not be read, the command returned a error,
+    /// or the autocomplete response could not be sent in time.
+    pub async fn handle(&self, event: &EventData, ctx: &Context<S>) -> Result<(), FrameworkError> {
+        let EventData::InteractionCreate(interaction) = event else {
+            return Ok(());
+        };
+        let Some(data) = interaction.command_data() else {
+            return Ok(());
+        };
+
+        match interaction.kind {
+            InteractionType::ApplicationCommand => self.run_command(interaction, data, ctx).await,
+            InteractionType::ApplicationCommandAutocomplete => {
+                self.run_autocomplete(interaction, data, ctx).await
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Run the checks, parse the options and run the handler
+    async fn run_command(
+        &self,
+        interaction: &Interaction,
+        data: &ApplicationCommandData,
+        ctx: &Context<S>,
+    ) -> Result<(), FrameworkError> {
+        let Some(command) = self.commands.get(&data.name) else {
+            return Ok(());
+        };
+
+        let checks = self.checks.get(&data.name).map_or(&[][..], Vec::as_slice);
+        checks::run_checks(checks, &CheckData::from(interaction), &self.owners).map_err(
+            |failure| FrameworkError::Check {
+                command: data.name.clone(),
+                failure,
+            },
+        )?;
+
+        let context = CommandContext {
+            context: ctx.clone(),
+            interaction: interaction.clone(),
+        };
+        let future = (command.run)(context, data).map_err(|error| FrameworkError::Parse {
+            command: data.name.clone(),
+            error,
+        })?;
+        future.await.map_err(|error| FrameworkError::Command {
+            command: data.name.clone(),
+            error,
+        })
+    }
+
+    /// Get the suggestions for the focused option and send them
+    async fn run_autocomplete(
+        &self,
+        interaction: &Interaction,
+        data: &ApplicationCommandData,
+        ctx: &Context<S>,
+    ) -> Result<(), FrameworkError> {
+        let context = CommandContext {
+            context: ctx.clone(),
+            interaction: interaction.clone(),
+        };
+        let Some(response) = self.autocomplete_response(&context, data).await? else {
+            return Ok(());
+        };
+
+        context
+            .respond(response)
+            .await
+            .map_err(|error| FrameworkError::Respond {
+                command: data.name.clone(),
+                error,
+            })
+    }
+
+    /// Run the callback of the focused option within the time left to respond,
+    /// [`None`] if there is no callback for it.
+    async fn autocomplete_response(
+        &self,
+        context: &CommandContext<S>,
+        data: &ApplicationCommandData,
+    ) -> Result<Option<InteractionResponse>, FrameworkError> {
+        let Some(focused) = data.focused() else {
+            return Ok(None);
+        };
+        let Some(callback) = self
+            .autocompletes
+            .get(&(data.name.clone(), focused.name.clone()))
+        else {
+            return Ok(None);
+        };
+
+        let partial = focused
+            .value
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+
+        // time spent before the framework got the interaction counts towards the deadline
+        let elapsed = SystemTime::now()
+            .duration_since(context.interaction.id.created_at())
+            .unwrap_or_default();
+        let mut choices = tokio::time::timeout(
+            AUTOCOMPLETE_DEADLINE.saturating_sub(elapsed),
+            callback(context.clone(), partial, focused.clone()),
+        )
+        .await
+        .map_err(|_| FrameworkError::AutocompleteTimeout {
+            command: data.name.clone(),
+            option: focused.name.clone(),
+        })?;
+        choices.truncate(MAX_AUTOCOMPLETE_CHOICES);
+
+        Ok(Some(
+            InteractionResponse::ApplicationCommandAutocompleteResult(choices),
+        ))
+    }
+}
+
+/// Does the registered command match the definition,
+/// fields not set in the definition use the defaults discord would fill in.
+fn is_same_command(
+    command: &ApplicationCommand,
+    definition: &CreateApplicationCommandParams,
+) -> bool {
+    fn optional_eq<T: PartialEq>(expected: Option<&T>, actual: Option<&T>) -> bool {
+        expected.is_none() || expected == actual
+    }
+
+    command.name == definition.name
+        && command.kind == definition.kind.unwrap_or_default()
+        && command.description == definition.description.as_deref().unwrap_or_default()
+        && normalize_options(&command.options) == normalize_options(&definition.options)
+        && command.default_member_permissions == definition.default_member_permissions
+        && command.nsfw == definition.nsfw
+        && command.name_localizations.clone().unwrap_or_default()
+            == definition.name_localizations.clone().unwrap_or_default()
+        && command
+            .description_localizations
+            .clone()
+            .unwrap_or_default()
+            == definition
+                .description_localizations
+                .clone()
+                .unwrap_or_default()
+        && optional_eq(
+            definition.integration_types.as_ref(),
+            command.integration_types.as_ref(),
+        )
+        && optional_eq(definition.contexts.as_ref(), command.contexts.as_ref())
+}
+
+/// Options with `min_value` and `max_value` as floats,
+/// discord echoes a number option defined with `1.0` as `1` so they are compared by value.
+fn normalize_options(options: &[ApplicationCommandOption]) -> Vec<ApplicationCommandOption> {
+    let as_float = |number: Option<serde_json::Number>| {
+        number
+            .and_then(|number| number.as_f64())
+            .and_then(serde_json::Number::from_f64)
+    };
+
+    options
+        .iter()
+        .cloned()
+        .map(|mut option| {
+            option.min_value = as_float(option.min_value);
+            option.max_value = as_float(option.max_value);
+            option.options = normalize_options(&option.options);
+            option
+        })
+        .collect()
+}
+
+/// Used by the code generated by the macros
+#[doc(hidden)]
+pub mod __private {
+    use super::{CommandOption, CommandParseError};
+    use crate::datatypes::ApplicationCommandData;
+
+    /// Find and parse a option by name
+    pub fn parse_option<T: CommandOption>(
+        data: &ApplicationCommandData,
+        name: &str,
+    ) -> Result<T, CommandParseError> {
+        let value = data
+            .options
+            .iter()
+            .find(|option| option.name == name)
+            .and_then(|option| option.value.as_ref());
+        T::parse(name, value, &data.resolved)
+    }
+
+    /// Number usable as `min_value` or `max_value`
+    pub trait IntoNumber {
+        /// Convert to a json number
+        fn into_number(self) -> serde_json::Number;
+    }
+
+    macro_rules! int_into_number {
+        ($($ty: ty),*) => {
+            $(impl IntoNumber for $ty {
+                fn into_number(self) -> serde_json::Number {
+                    self.into()
+                }
+            })*
+        };
+    }
+    int_into_number!(i32, i64, u32, u64);
+
+    impl IntoNumber for f64 {
+        fn into_number(self) -> serde_json::Number {
+            serde_json::Number::from_f64(self).expect("min_value and max_value must be finite")
+        }
+    }
+
+    /// Convert a `min_value` or `max_value` to a json number
+    pub fn number<N: IntoNumber>(value: N) -> serde_json::Number {
+        value.into_number()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::{ApplicationCommandOptionType, Interaction, User};
+
+    /// Ban a user
+    #[derive(Command)]
+    #[command_info(name = "ban")]
+    struct BanCommand {
+        /// Who to ban
+        user: User,
+        /// Days of messages to delete
+        #[option(min_value = 0, max_value = 7)]
+        days: Option<i64>,
+        /// Why they are banned
+        #[option(autocomplete)]
+        reason: Option<String>,
+    }
+
+    /// Pay someone
+    #[allow(dead_code)] // only the definition is used
+    #[derive(Command)]
+    #[command_info(name = "pay")]
+    struct PayCommand {
+        /// Who to pay
+        user: User,
+        /// How much to pay
+        #[option(min_value = 1.0, max_value = 1000.5)]
+        amount: f64,
+    }
+
+    #[test]
+    fn test_is_same_command() {
+        let definition = PayCommand::definition();
+        // what discord returns after registering the definition
+        let mut command: ApplicationCommand = serde_json::from_value(serde_json::json!({
+            "id": "10",
+            "application_id": "2",
+            "version": "11",
+            "default_member_permissions": null,
+            "type": 1,
+            "name": "pay",
+            "name_localizations": null,
+            "description": "Pay someone",
+            "description_localizations": null,
+            "guild_id": "3",
+            "nsfw": false,
+            "integration_types": [0],
+            "contexts": null,
+            "options": [
+                {"type": 6, "name": "user", "name_localizations": null, "description": "Who to pay", "description_localizations": null, "required": true},
+                {"type": 10, "name": "amount", "name_localizations": null, "description": "How much to pay", "description_localizations": null, "required": true, "min_value": 1, "max_value": 1000.5}
+            ]
+        }))
+        .unwrap();
+        assert!(is_same_command(&command, &definition));
+
+        command.options[1].max_value = Some(1000.into());
+        assert!(!is_same_command(&command, &definition));
+    }
+
+    /// Autocomplete interaction of `/ban` with `option` focused
+    fn autocomplete_context(id: Snowflake, option: &str) -> CommandContext<()> {
+        let interaction: Interaction = serde_json::from_value(serde_json::json!({
+            "id": id, "application_id": "2", "type": 4, "token": "abc", "version": 1,
+            "data": {
+                "id": "3", "name": "ban", "type": 1,
+                "options": [
+                    {"name": "user", "type": 6, "value": "4"},
+                    {"name": option, "type": 3, "value": "sp", "focused": true}
+                ]
+            }
+        }))
+        .unwrap();
+        CommandContext {
+            context: crate::Client::new("TOKEN".to_owned(), ()).context(),
+            interaction,
+        }
+    }
+
+    fn autocomplete_framework() -> Framework<()> {
+        Framework::new()
+            .command(|_: CommandContext<()>, _: BanCommand| async { Ok(()) })
+            .autocomplete("ban", "reason", |_, partial, _| async move {
+                (0..30)
+                    .map(|index| {
+                        ApplicationCommandOptionChoice::new(format!("{partial}{index}"), "x")
+                    })
+                    .collect()
+            })
+            .autocomplete("ban", "slow", |_, _, _| async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Vec::new()
+            })
+    }
+
+    #[tokio::test]
+    async fn test_autocomplete_response() {
+        let framework = autocomplete_framework();
+        let context = autocomplete_context(Snowflake::from_time(SystemTime::now()), "reason");
+        let data = context.interaction.command_data().unwrap();
+
+        let response = framework
+            .autocomplete_response(&context, data)
+            .await
+            .unwrap();
+        let Some(InteractionResponse::ApplicationCommandAutocompleteResult(choices)) = response
+        else {
+            panic!("expected autocomplete result, got {response:?}");
+        };
+        assert_eq!(choices.len(), MAX_AUTOCOMPLETE_CHOICES);
+        assert_eq!(choices[0].name, "sp0");
+
+        let context = autocomplete_context(Snowflake::from_time(SystemTime::now()), "unknown");
+        let data = context.interaction.command_data().unwrap();
+        let response = framework
+            .autocomplete_response(&context, data)
+            .await
+            .unwrap();
+        assert!(response.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_autocomplete_timeout() {
+        let framework = autocomplete_framework();
+        // created long ago, so there is no time left
+        let context = autocomplete_context(Snowflake(1 << 22), "slow");
+        let data = context.interaction.command_data().unwrap();
+
+        let result = framework.autocomplete_response(&context, data).await;
+        assert!(matches!(
+            result,
+            Err(FrameworkError::AutocompleteTimeout { option, .. }) if option == "slow"
+        ));
+    }
+
+    #[test]
+    fn test_derive_command() {
+        let definition = BanCommand::definition();
+        assert_eq!(definition.name, "ban");
+        assert_eq!(definition.description.as_deref(), Some("Ban a user"));
+        assert!(definition.validate().is_ok());
+
+        let user = &definition.options[0];
+        assert_eq!(user.kind, ApplicationCommandOptionType::User);
+        assert!(user.required);
+
+        let days = &definition.options[1];
+        assert_eq!(days.kind, ApplicationCommandOptionType::Integer);
+        assert!(!days.required);
+        assert_eq!(days.max_value, Some(7.into()));
+        assert!(definition.options[2].autocomplete);
+
+        let interaction: Interaction = serde_json::from_str(
+            r#"{
+                "id": "1", "application_id": "2", "type": 2, "token": "abc", "version": 1,
+                "data": {
+                    "id": "3", "name": "ban", "type": 1,
+                    "options": [{"name": "user", "type": 6, "value": "4"}],
+                    "resolved": {"users": {"4": {"id": "4", "username": "vivax", "discriminator": "0"}}}
+                }
+            }"#,
+        )
+        .unwrap();
+        let args = BanCommand::parse(interaction.command_data().unwrap()).unwrap();
+        assert_eq!(args.user.id, Snowflake(4));
+        assert_eq!(args.days, None);
+        assert_eq!(args.reason, None);
+
+        let mut data = interaction.command_data().unwrap().clone();
+        data.resolved.users.clear();
+        assert!(matches!(
+            BanCommand::parse(&data),
+            Err(CommandParseError::Unresolved { .. })
+        ));
+    }
+}
@@ -0,0 +1,143 @@
+//! Typed values of submitted modals
+
+use crate::datatypes::{Component, Modal, ModalSubmitData};
+
+/// A modal whose submitted values can be read into a struct.
+///
+/// This is normally implemented with `#[derive(ModalForm)]`,
+/// each field becomes a text input with the field name as custom id.
+/// The label is taken from the doc comment, or from `#[input(label = "...")]`.
+/// The other `#[input(...)]` settings are `custom_id`, `paragraph`, `min_length`, `max_length`, `placeholder` and `value`.
+///
+/// # Example
+/// ```
+/// use vivcord::framework::ModalForm;
+///
+/// #[derive(ModalForm)]
+/// struct Feedback {
+///     /// Topic
+///     #[input(max_length = 50)]
+///     topic: String,
+///     /// Details
+///     #[input(paragraph, placeholder = "What happened?")]
+///     details: Option<String>,
+/// }
+///
+/// let modal = Feedback::modal("feedback", "Send feedback");
+/// assert!(modal.validate().is_ok());
+/// ```
+pub trait ModalForm: Sized {
+    /// Action rows with a text input for each field
+    fn components() -> Vec<Component>;
+
+    /// Read the submitted values
+    ///
+    /// # Errors
+    /// If a required value is missing.
+    fn parse(data: &ModalSubmitData) -> Result<Self, ModalParseError>;
+
+    /// Modal showing the text inputs
+    fn modal<I: Into<String>, T: Into<String>>(custom_id: I, title: T) -> Modal {
+        Modal {
+            custom_id: custom_id.into(),
+            title: title.into(),
+            components: Self::components(),
+        }
+    }
+}
+
+/// Type that can be used as a field of a [`ModalForm`]
+pub trait ModalValue: Sized {
+    /// Must the user fill in the text input
+    const REQUIRED: bool = true;
+
+    /// Read the submitted value, `value` is `None` when the text input was not submitted.
+    ///
+    /// # Errors
+    /// If the value is missing.
+    fn parse(custom_id: &str, value: Option<&str>) -> Result<Self, ModalParseError>;
+}
+
+impl ModalValue for String {
+    fn parse(custom_id: &str, value: Option<&str>) -> Result<Self, ModalParseError> {
+        value
+            .filter(|value| !value.is_empty())
+            .map(ToOwned::to_owned)
+            .ok_or_else(|| ModalParseError::MissingValue(custom_id.to_owned()))
+    }
+}
+
+impl ModalValue for Option<String> {
+    const REQUIRED: bool = false;
+
+    fn parse(_custom_id: &str, value: Option<&str>) -> Result<Self, ModalParseError> {
+        Ok(value
+            .filter(|value| !value.is_empty())
+            .map(ToOwned::to_owned))
+    }
+}
+
+/// Error reading the values of a submitted modal
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ModalParseError {
+    /// The interaction is not a modal submit
+    NotModalSubmit,
+    /// A required text input was not filled in
+    MissingValue(String),
+}
+
+impl std::fmt::Display for ModalParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotModalSubmit => write!(f, "interaction is not a modal submit"),
+            Self::MissingValue(custom_id) => write!(f, "missing value for text input {custom_id}"),
+        }
+    }
+}
+
+impl std::error::Error for ModalParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{ModalParseError, ModalSubmitData};
+    use crate::framework::ModalForm;
+
+    #[derive(ModalForm)]
+    struct Feedback {
+        /// Topic
+        #[input(max_length = 50)]
+        topic: String,
+        /// Details
+        #[input(custom_id = "more", paragraph)]
+        details: Option<String>,
+    }
+
+    #[test]
+    fn test_derive_modal_form() {
+        let modal = Feedback::modal("feedback", "Send feedback");
+        assert!(modal.validate().is_ok());
+        assert_eq!(modal.components.len(), 2);
+
+        let data: ModalSubmitData = serde_json::from_str(
+            r#"{
+                "custom_id": "feedback",
+                "components": [
+                    {"type": 1, "components": [{"type": 4, "custom_id": "topic", "value": "bugs"}]},
+                    {"type": 1, "components": [{"type": 4, "custom_id": "more", "value": ""}]}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let feedback = Feedback::parse(&data).unwrap();
+        assert_eq!(feedback.topic, "bugs");
+        assert_eq!(feedback.details, None);
+
+        let data: ModalSubmitData =
+            serde_json::from_str(r#"{"custom_id": "feedback", "components": []}"#).unwrap();
+        assert_eq!(
+            Feedback::parse(&data).err(),
+            Some(ModalParseError::MissingValue("topic".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,377 @@
+//! Splitting and parsing the arguments of text commands
+
+use std::time::Duration;
+
+use crate::datatypes::Snowflake;
+
+/// Arguments of a text command, split on whitespace.
+///
+/// Text in double quotes is kept as a single argument, `\"` can be used for a quote inside of it.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use vivcord::framework::prefix::Args;
+/// # use vivcord::datatypes::Snowflake;
+/// let mut args = Args::new(r#"<@1234> 1h30m "being rude" and more"#)?;
+/// assert_eq!(args.required::<Snowflake>()?, Snowflake(1234));
+/// assert_eq!(args.required::<Duration>()?, Duration::from_secs(90 * 60));
+/// assert_eq!(args.required::<String>()?, "being rude");
+/// assert_eq!(args.optional::<i64>().ok(), None);
+/// assert_eq!(args.rest(), "and more");
+/// # Ok::<(), vivcord::framework::prefix::ArgError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Args {
+    input: String,
+    /// Start of each argument in `input`, and its value
+    tokens: Vec<(usize, String)>,
+    position: usize,
+}
+
+impl Args {
+    /// Split the input into arguments
+    ///
+    /// # Errors
+    /// [`ArgError::UnclosedQuote`] if a quoted argument is not closed.
+    pub fn new<I: Into<String>>(input: I) -> Result<Self, ArgError> {
+        let input = input.into();
+        let mut tokens = Vec::new();
+        let mut chars = input.char_indices().peekable();
+
+        while let Some(&(start, character)) = chars.peek() {
+            if character.is_whitespace() {
+                chars.next();
+                continue;
+            }
+
+            let mut token = String::new();
+            if character == '"' {
+                chars.next();
+                let mut closed = false;
+                while let Some((_, character)) = chars.next() {
+                    match character {
+                        '"' => {
+                            closed = true;
+                            break;
+                        }
+                        '\\' => {
+                            if let Some((_, escaped)) = chars.next() {
+                                token.push(escaped);
+                            }
+                        }
+                        _ => token.push(character),
+                    }
+                }
+                if !closed {
+                    return Err(ArgError::UnclosedQuote);
+                }
+            } else {
+                while let Some(&(_, character)) = chars.peek() {
+                    if character.is_whitespace() {
+                        break;
+                    }
+                    token.push(character);
+                    chars.next();
+                }
+            }
+            tokens.push((start, token));
+        }
+
+        Ok(Self {
+            input,
+            tokens,
+            position: 0,
+        })
+    }
+
+    /// Parse the next argument
+    ///
+    /// # Errors
+    /// [`ArgError::Missing`] if there are no arguments left, [`ArgError::Invalid`] if it could not be parsed.
+    pub fn required<T: FromArg>(&mut self) -> Result<T, ArgError> {
+        self.optional()?.ok_or(ArgError::Missing {
+            index: self.position,
+            expected: T::NAME,
+        })
+    }
+
+    /// Parse the next argument if there is one
+    ///
+    /// # Errors
+    /// [`ArgError::Invalid`] if the argument could not be parsed, the argument is not consumed in that case.
+    pub fn optional<T: FromArg>(&mut self) -> Result<Option<T>, ArgError> {
+        let Some((_, value)) = self.tokens.get(self.position) else {
+            return Ok(None);
+        };
+        let parsed = T::from_arg(value).ok_or_else(|| ArgError::Invalid {
+            index: self.position,
+            value: value.clone(),
+            expected: T::NAME,
+        })?;
+        self.position += 1;
+        Ok(Some(parsed))
+    }
+
+    /// All remaining input as written by the user, consuming the remaining arguments
+    pub fn rest(&mut self) -> &str {
+        let start = self
+            .tokens
+            .get(self.position)
+            .map_or(self.input.len(), |(start, _)| *start);
+        self.position = self.tokens.len();
+        self.input[start..].trim_end()
+    }
+
+    /// Amount of arguments not parsed yet
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.tokens.len() - self.position
+    }
+
+    /// Check that all arguments were parsed
+    ///
+    /// # Errors
+    /// [`ArgError::TooMany`] if there are arguments left.
+    pub fn finish(&self) -> Result<(), ArgError> {
+        match self.tokens.get(self.position) {
+            Some((start, _)) => Err(ArgError::TooMany {
+                extra: self.input[*start..].trim_end().to_owned(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Type that can be parsed from a text command argument
+pub trait FromArg: Sized {
+    /// What the argument should look like, shown in errors
+    const NAME: &'static str;
+
+    /// Parse the argument, `None` if it is invalid
+    fn from_arg(arg: &str) -> Option<Self>;
+}
+
+impl FromArg for String {
+    const NAME: &'static str = "text";
+
+    fn from_arg(arg: &str) -> Option<Self> {
+        Some(arg.to_owned())
+    }
+}
+
+impl FromArg for i64 {
+    const NAME: &'static str = "whole number";
+
+    fn from_arg(arg: &str) -> Option<Self> {
+        arg.parse().ok()
+    }
+}
+
+impl FromArg for u64 {
+    const NAME: &'static str = "positive whole number";
+
+    fn from_arg(arg: &str) -> Option<Self> {
+        arg.parse().ok()
+    }
+}
+
+impl FromArg for f64 {
+    const NAME: &'static str = "number";
+
+    fn from_arg(arg: &str) -> Option<Self> {
+        arg.parse().ok().filter(|number: &f64| number.is_finite())
+    }
+}
+
+impl FromArg for bool {
+    const NAME: &'static str = "yes or no";
+
+    fn from_arg(arg: &str) -> Option<Self> {
+        match arg.to_lowercase().as_str() {
+            "true" | "yes" | "y" | "on" => Some(true),
+            "false" | "no" | "n" | "off" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+/// Ids can be given directly, or as a user, channel or role mention.
+impl FromArg for Snowflake {
+    const NAME: &'static str = "mention or id";
+
+    fn from_arg(arg: &str) -> Option<Self> {
+        let id = match arg.strip_prefix('<').and_then(|arg| arg.strip_suffix('>')) {
+            Some(mention) => ["@!", "@&", "@", "#"]
+                .into_iter()
+                .find_map(|prefix| mention.strip_prefix(prefix))?,
+            None => arg,
+        };
+        id.parse().ok().map(Snowflake)
+    }
+}
+
+/// Durations are written as numbers with a unit, such as `1h30m`.
+///
+/// The units are `s`, `m`, `h`, `d` and `w`, a number without a unit is in seconds.
+impl FromArg for Duration {
+    const NAME: &'static str = "duration like 1h30m";
+
+    fn from_arg(arg: &str) -> Option<Self> {
+        if let Ok(seconds) = arg.parse() {
+            return Some(Duration::from_secs(seconds));
+        }
+
+        let mut total: u64 = 0;
+        let mut number = String::new();
+        for character in arg.chars() {
+            if character.is_ascii_digit() {
+                number.push(character);
+                continue;
+            }
+            let unit = match character.to_ascii_lowercase() {
+                's' => 1,
+                'm' => 60,
+                'h' => 60 * 60,
+                'd' => 24 * 60 * 60,
+                'w' => 7 * 24 * 60 * 60,
+                _ => return None,
+            };
+            let amount: u64 = number.parse().ok()?;
+            total = total.checked_add(amount.checked_mul(unit)?)?;
+            number.clear();
+        }
+
+        if number.is_empty() && !arg.is_empty() {
+            Some(Duration::from_secs(total))
+        } else {
+            None
+        }
+    }
+}
+
+/// Error parsing the arguments of a text command, the messages are meant to be shown to the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ArgError {
+    /// A quoted argument was not closed
+    UnclosedQuote,
+    /// A required argument was not given
+    Missing {
+        /// Position of the argument, starting at 0
+        index: usize,
+        /// What the argument should look like
+        expected: &'static str,
+    },
+    /// A argument could not be parsed
+    Invalid {
+        /// Position of the argument, starting at 0
+        index: usize,
+        /// The given value
+        value: String,
+        /// What the argument should look like
+        expected: &'static str,
+    },
+    /// More arguments were given than the command takes
+    TooMany {
+        /// The extra input
+        extra: String,
+    },
+}
+
+impl std::fmt::Display for ArgError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnclosedQuote => write!(f, "missing closing quote"),
+            Self::Missing { index, expected } => {
+                write!(
+                    f,
+                    "argument {} is missing, expected a {expected}",
+                    index + 1
+                )
+            }
+            Self::Invalid {
+                index,
+                value,
+                expected,
+            } => write!(
+                f,
+                "argument {} `{value}` is not valid, expected a {expected}",
+                index + 1
+            ),
+            Self::TooMany { extra } => write!(f, "unexpected arguments `{extra}`"),
+        }
+    }
+}
+
+impl std::error::Error for ArgError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_quotes() {
+        let mut args = Args::new(r#"  one "two three" "say \"hi\""  "#).unwrap();
+        assert_eq!(args.remaining(), 3);
+        assert_eq!(args.required::<String>().unwrap(), "one");
+        assert_eq!(args.required::<String>().unwrap(), "two three");
+        assert_eq!(args.required::<String>().unwrap(), r#"say "hi""#);
+        assert!(args.finish().is_ok());
+
+        assert_eq!(Args::new(r#"a "b"#).err(), Some(ArgError::UnclosedQuote));
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut args = Args::new("abc").unwrap();
+        assert_eq!(
+            args.required::<i64>(),
+            Err(ArgError::Invalid {
+                index: 0,
+                value: "abc".to_owned(),
+                expected: "whole number"
+            })
+        );
+        // the invalid argument is not consumed
+        assert_eq!(
+            args.finish(),
+            Err(ArgError::TooMany {
+                extra: "abc".to_owned()
+            })
+        );
+        assert_eq!(args.rest(), "abc");
+        assert_eq!(
+            args.required::<String>(),
+            Err(ArgError::Missing {
+                index: 1,
+                expected: "text"
+            })
+        );
+    }
+
+    #[test]
+    fn test_mentions() {
+        for mention in ["123", "<@123>", "<@!123>", "<#123>", "<@&123>"] {
+            assert_eq!(Snowflake::from_arg(mention), Some(Snowflake(123)));
+        }
+        assert_eq!(Snowflake::from_arg("<:emoji:123>"), None);
+        assert_eq!(Snowflake::from_arg("<@abc>"), None);
+    }
+
+    #[test]
+    fn test_durations() {
+        assert_eq!(Duration::from_arg("90"), Some(Duration::from_secs(90)));
+        assert_eq!(Duration::from_arg("1h30m"), Some(Duration::from_secs(90 * 60)));
+        assert_eq!(Duration::from_arg("2d"), Some(Duration::from_secs(48 * 60 * 60)));
+        assert_eq!(
+            Duration::from_arg("1w1s"),
+            Some(Duration::from_secs(604_801))
+        );
+        assert_eq!(Duration::from_arg("10"), Some(Duration::from_secs(10)));
+        assert_eq!(Duration::from_arg("h"), None);
+        assert_eq!(Duration::from_arg("5x"), None);
+        assert_eq!(Duration::from_arg("1h30"), None);
+        assert_eq!(Duration::from_arg(""), None);
+    }
+}
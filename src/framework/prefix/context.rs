@@ -0,0 +1,50 @@
+//! Context passed to text command handlers
+
+use crate::api::Error;
+use crate::datatypes::{CreateMessageParams, Message};
+use crate::Context;
+
+/// The context and the message that invoked a text command
+#[allow(missing_debug_implementations)]
+#[derive(Clone)]
+pub struct TextContext<S> {
+    /// Context of the event that contained the message
+    pub context: Context<S>,
+    /// Message that invoked the command
+    pub message: Message,
+    /// Prefix the message used
+    pub prefix: String,
+    /// Name of the command, subcommands are separated by spaces
+    pub command: String,
+}
+
+impl<S> TextContext<S> {
+    /// Send a message in the same channel
+    ///
+    /// # Errors
+    /// See [`Api::create_message`][crate::Api::create_message]
+    pub async fn say<C: Into<String>>(&self, content: C) -> Result<Message, Error> {
+        self.context
+            .say(self.message.channel_id, content.into())
+            .await
+    }
+
+    /// Reply to the message
+    ///
+    /// # Errors
+    /// See [`Context::reply`]
+    pub async fn reply<C: Into<String>>(&self, content: C) -> Result<Message, Error> {
+        self.context.reply(&self.message, content.into()).await
+    }
+
+    /// Send a message in the same channel
+    ///
+    /// # Errors
+    /// See [`Api::create_message`][crate::Api::create_message]
+    pub async fn send(&self, msg: CreateMessageParams) -> Result<Message, Error> {
+        self.context
+            .api
+            .create_message(self.message.channel_id, msg)
+            .await
+    }
+}
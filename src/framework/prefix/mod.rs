@@ -0,0 +1,578 @@
+//! Text commands invoked with a prefix, such as `!ban @user`
+//!
+//! # Example
+//! ```no_run
+//! use std::sync::Arc;
+//! use vivcord::datatypes::Snowflake;
+//! use vivcord::framework::prefix::{PrefixFramework, TextCommand};
+//!
+//! # tokio_test::block_on(async move {
+//! let framework = Arc::new(
+//!     PrefixFramework::new()
+//!         .prefix("!")
+//!         .command(
+//!             TextCommand::new("ban", "Ban a user", |ctx, mut args| async move {
+//!                 let user: Snowflake = args.required()?;
+//!                 let reason = args.rest().to_owned();
+//!                 ctx.reply(format!("Banned <@{}>: {reason}", user.0)).await?;
+//!                 Ok(())
+//!             })
+//!             .alias("b")
+//!             .usage("<user> [reason]"),
+//!         ),
+//! );
+//!
+//! let intents = vivcord::Intents::GUILD_MESSAGES | vivcord::Intents::MESSAGE_CONTENT;
+//! let client = vivcord::Client::builder("TOKEN".to_owned(), ())
+//!     .intents(intents)
+//!     .build()
+//!     .unwrap();
+//! client
+//!     .run(move |event, ctx| {
+//!         let framework = framework.clone();
+//!         async move {
+//!             if let Err(err) = framework.handle(&event, &ctx).await {
+//!                 eprintln!("{err}");
+//!             }
+//!         }
+//!     })
+//!     .await;
+//! # });
+//! ```
+
+mod args;
+mod context;
+
+use futures::future::BoxFuture;
+use futures::Future;
+
+pub use args::{ArgError, Args, FromArg};
+pub use context::TextContext;
+
+use super::checks::run_checks;
+use super::{Check, CheckData, CheckFailure, CommandError, CommandResult};
+use crate::api::Error;
+use crate::datatypes::{Message, Snowflake};
+use crate::{Context, EventData};
+
+/// Runs a text command with its arguments
+type TextCommandRunner<S> =
+    Box<dyn Fn(TextContext<S>, Args) -> BoxFuture<'static, CommandResult> + Send + Sync>;
+
+/// Looks up the prefix of a guild, using the client state
+type GuildPrefix<S> = Box<dyn Fn(Snowflake, S) -> BoxFuture<'static, Option<String>> + Send + Sync>;
+
+/// Fills in the data checks need that messages do not include, using the client state
+type CheckDataLookup<S> =
+    Box<dyn Fn(Message, CheckData, S) -> BoxFuture<'static, CheckData> + Send + Sync>;
+
+/// A text command, with optional aliases and subcommands
+#[allow(missing_debug_implementations)]
+pub struct TextCommand<S> {
+    name: String,
+    description: String,
+    aliases: Vec<String>,
+    usage: Option<String>,
+    subcommands: Vec<TextCommand<S>>,
+    checks: Vec<Check>,
+    run: Option<TextCommandRunner<S>>,
+}
+
+impl<S> TextCommand<S> {
+    /// Command running `handler` when invoked
+    pub fn new<N, D, F, Fut>(name: N, description: D, handler: F) -> Self
+    where
+        N: Into<String>,
+        D: Into<String>,
+        F: Fn(TextContext<S>, Args) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = CommandResult> + Send + 'static,
+    {
+        Self {
+            run: Some(Box::new(move |context, args| {
+                Box::pin(handler(context, args))
+            })),
+            ..Self::group(name, description)
+        }
+    }
+
+    /// Command that only holds subcommands
+    pub fn group<N: Into<String>, D: Into<String>>(name: N, description: D) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            aliases: Vec::new(),
+            usage: None,
+            subcommands: Vec::new(),
+            checks: Vec::new(),
+            run: None,
+        }
+    }
+
+    /// Add a alternative name
+    #[must_use]
+    pub fn alias<A: Into<String>>(mut self, alias: A) -> Self {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    /// Describe the arguments in the help, such as `<user> [reason]`
+    #[must_use]
+    pub fn usage<U: Into<String>>(mut self, usage: U) -> Self {
+        self.usage = Some(usage.into());
+        self
+    }
+
+    /// Add a subcommand, invoked as `!command subcommand`
+    #[must_use]
+    pub fn subcommand(mut self, subcommand: TextCommand<S>) -> Self {
+        self.subcommands.push(subcommand);
+        self
+    }
+
+    /// Add a check that must pass before the command runs, it also applies to the subcommands
+    #[must_use]
+    pub fn check(mut self, check: Check) -> Self {
+        self.checks.push(check);
+        self
+    }
+
+    /// Is `name` the name or a alias of this command, ignoring case
+    fn is_named(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+            || self
+                .aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(name))
+    }
+
+    /// Find the subcommand named `name`
+    fn subcommand_named(&self, name: &str) -> Option<&Self> {
+        self.subcommands
+            .iter()
+            .find(|command| command.is_named(name))
+    }
+
+    /// Help line of the command, such as `!ban <user> [reason] - Ban a user`
+    fn summary(&self, invocation: &str) -> String {
+        let mut line = invocation.to_owned();
+        if let Some(usage) = &self.usage {
+            line.push(' ');
+            line.push_str(usage);
+        }
+        if !self.description.is_empty() {
+            line.push_str(" - ");
+            line.push_str(&self.description);
+        }
+        line
+    }
+}
+
+/// Error while dispatching a text command, the messages are meant to be shown to the user.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TextCommandError {
+    /// The message used a prefix, but no command has the name
+    UnknownCommand(String),
+    /// The command only holds subcommands, and none was given
+    MissingSubcommand(String),
+    /// The arguments could not be parsed
+    Args {
+        /// Name of the command
+        command: String,
+        /// The parse error
+        error: ArgError,
+    },
+    /// A check of the command failed, the command was not run
+    Check {
+        /// Name of the command
+        command: String,
+        /// Why the check failed
+        failure: CheckFailure,
+    },
+    /// The command handler returned a error
+    Command {
+        /// Name of the command
+        command: String,
+        /// The returned error
+        error: CommandError,
+    },
+    /// Sending the help message failed
+    Respond(Error),
+}
+
+impl std::fmt::Display for TextCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownCommand(name) => write!(f, "unknown command {name}"),
+            Self::MissingSubcommand(command) => write!(f, "{command} needs a subcommand"),
+            Self::Args { command, error } => write!(f, "{command}: {error}"),
+            Self::Check { command, failure } => write!(f, "can not use {command}: {failure}"),
+            Self::Command { command, error } => write!(f, "{command} failed: {error}"),
+            Self::Respond(error) => write!(f, "failed to send help: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for TextCommandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Args { error, .. } => Some(error),
+            Self::Check { failure, .. } => Some(failure),
+            Self::Command { error, .. } => Some(&**error),
+            Self::Respond(error) => Some(error),
+            Self::UnknownCommand(_) | Self::MissingSubcommand(_) => None,
+        }
+    }
+}
+
+/// Collection of text commands, that dispatches messages starting with a prefix to them.
+///
+/// Messages from bots are ignored.
+/// A `help` command listing the commands is added unless disabled with [`help`][Self::help].
+#[allow(missing_debug_implementations)]
+pub struct PrefixFramework<S> {
+    prefixes: Vec<String>,
+    mention: Option<Snowflake>,
+    guild_prefix: Option<GuildPrefix<S>>,
+    commands: Vec<TextCommand<S>>,
+    help: bool,
+    owners: Vec<Snowflake>,
+    check_data: Option<CheckDataLookup<S>>,
+}
+
+impl<S> Default for PrefixFramework<S> {
+    fn default() -> Self {
+        Self {
+            prefixes: Vec::new(),
+            mention: None,
+            guild_prefix: None,
+            commands: Vec::new(),
+            help: true,
+            owners: Vec::new(),
+            check_data: None,
+        }
+    }
+}
+
+impl<S> PrefixFramework<S>
+where
+    S: Clone + Send + 'static,
+{
+    /// Framework without any prefixes or commands
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a prefix, such as `!`
+    #[must_use]
+    pub fn prefix<P: Into<String>>(mut self, prefix: P) -> Self {
+        self.prefixes.push(prefix.into());
+        self
+    }
+
+    /// Allow mentioning the bot as prefix, such as `@bot ban`
+    #[must_use]
+    pub fn mention<B: Into<Snowflake>>(mut self, bot_id: B) -> Self {
+        self.mention = Some(bot_id.into());
+        self
+    }
+
+    /// Look up the prefix of a guild, for example from a database in the client state.
+    ///
+    /// The guild prefix is checked before the static prefixes, `None` uses only the static prefixes.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use std::sync::{Arc, Mutex};
+    /// # use vivcord::datatypes::Snowflake;
+    /// # use vivcord::framework::prefix::PrefixFramework;
+    /// type Prefixes = Arc<Mutex<HashMap<Snowflake, String>>>;
+    ///
+    /// let framework = PrefixFramework::new()
+    ///     .prefix("!")
+    ///     .guild_prefix(|guild_id, prefixes: Prefixes| async move {
+    ///         prefixes.lock().unwrap().get(&guild_id).cloned()
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn guild_prefix<F, Fut>(mut self, lookup: F) -> Self
+    where
+        F: Fn(Snowflake, S) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Option<String>> + Send + 'static,
+    {
+        self.guild_prefix = Some(Box::new(move |guild_id, state| {
+            Box::pin(lookup(guild_id, state))
+        }));
+        self
+    }
+
+    /// Add a command
+    #[must_use]
+    pub fn command(mut self, command: TextCommand<S>) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Enable or disable the generated `help` command
+    #[must_use]
+    pub fn help(mut self, enabled: bool) -> Self {
+        self.help = enabled;
+        self
+    }
+
+    /// Users allowed to use commands with [`Check::OwnerOnly`]
+    #[must_use]
+    pub fn owners<I: IntoIterator<Item = Snowflake>>(mut self, owners: I) -> Self {
+        self.owners.extend(owners);
+        self
+    }
+
+    /// Fill in the data the checks need that messages do not include,
+    /// the permissions of the user and the bot, and if the channel is age restricted.
+    ///
+    /// Without this [`Check::UserPermissions`], [`Check::BotPermissions`] and [`Check::NsfwOnly`]
+    /// always fail in guilds, as the permissions are not known.
+    ///
+    /// # Example
+    /// ```
+    /// # use vivcord::datatypes::Permissions;
+    /// # use vivcord::framework::prefix::PrefixFramework;
+    /// let framework = PrefixFramework::new()
+    ///     .prefix("!")
+    ///     .check_data(|_message, mut data, _state: ()| async move {
+    ///         // for example computed from cached roles and channel overwrites
+    ///         data.permissions = Some(Permissions::SEND_MESSAGES);
+    ///         data.nsfw = Some(false);
+    ///         data
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn check_data<F, Fut>(mut self, lookup: F) -> Self
+    where
+        F: Fn(Message, CheckData, S) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = CheckData> + Send + 'static,
+    {
+        self.check_data = Some(Box::new(move |message, data, state| {
+            Box::pin(lookup(message, data, state))
+        }));
+        self
+    }
+
+    /// Help text listing all commands when `path` is empty,
+    /// otherwise the help of the command at `path`, such as `config prefix`.
+    ///
+    /// `None` if there is no command at `path`.
+    #[must_use]
+    pub fn help_text(&self, prefix: &str, path: &str) -> Option<String> {
+        let mut words = path.split_whitespace();
+        let Some(first) = words.next() else {
+            let lines: Vec<_> = self
+                .commands
+                .iter()
+                .map(|command| command.summary(&format!("{prefix}{}", command.name)))
+                .collect();
+            return Some(format!("Commands:\n{}", lines.join("\n")));
+        };
+
+        let mut command = self
+            .commands
+            .iter()
+            .find(|command| command.is_named(first))?;
+        let mut invocation = format!("{prefix}{}", command.name);
+        for word in words {
+            command = command.subcommand_named(word)?;
+            invocation.push(' ');
+            invocation.push_str(&command.name);
+        }
+
+        let mut text = command.summary(&invocation);
+        if !command.aliases.is_empty() {
+            text.push_str("\nAliases: ");
+            text.push_str(&command.aliases.join(", "));
+        }
+        if !command.subcommands.is_empty() {
+            text.push_str("\nSubcommands:");
+            for subcommand in &command.subcommands {
+                text.push('\n');
+                text.push_str(&subcommand.summary(&format!("{invocation} {}", subcommand.name)));
+            }
+        }
+        Some(text)
+    }
+
+    /// Prefix the message starts with, and the content after it
+    async fn strip_prefix<'a>(&self, message: &'a Message, state: S) -> Option<(String, &'a str)> {
+        let content = message.content.as_str();
+
+        let guild_prefix = match (&self.guild_prefix, message.guild_id) {
+            (Some(lookup), Some(guild_id)) => lookup(guild_id, state).await,
+            _ => None,
+        };
+        let mut prefixes: Vec<String> = guild_prefix.into_iter().collect();
+        prefixes.extend(self.prefixes.iter().cloned());
+        if let Some(bot_id) = self.mention {
+            prefixes.push(format!("<@{}>", bot_id.0));
+            prefixes.push(format!("<@!{}>", bot_id.0));
+        }
+
+        prefixes.into_iter().find_map(|prefix| {
+            let rest = content.strip_prefix(&prefix)?;
+            Some((prefix, rest.trim_start()))
+        })
+    }
+
+    /// Run the command the message invoked, other events are ignored.
+    ///
+    /// # Errors
+    /// If the command does not exist, a check failed, the arguments could not be parsed,
+    /// or the command returned a error.
+    pub async fn handle(
+        &self,
+        event: &EventData,
+        ctx: &Context<S>,
+    ) -> Result<(), TextCommandError> {
+        let EventData::MessageCreate(message) = event else {
+            return Ok(());
+        };
+        if message.author.bot {
+            return Ok(());
+        }
+        let Some((prefix, input)) = self.strip_prefix(message, ctx.state.clone()).await else {
+            return Ok(());
+        };
+
+        let (name, mut rest) = split_word(input);
+        if name.is_empty() {
+            return Ok(());
+        }
+
+        let context = |command: String| TextContext {
+            context: ctx.clone(),
+            message: message.clone(),
+            prefix: prefix.clone(),
+            command,
+        };
+
+        if self.help && name.eq_ignore_ascii_case("help") {
+            let text = self
+                .help_text(&prefix, rest)
+                .ok_or_else(|| TextCommandError::UnknownCommand(rest.trim().to_owned()))?;
+            context("help".to_owned())
+                .say(text)
+                .await
+                .map_err(TextCommandError::Respond)?;
+            return Ok(());
+        }
+
+        let mut command = self
+            .commands
+            .iter()
+            .find(|command| command.is_named(name))
+            .ok_or_else(|| TextCommandError::UnknownCommand(name.to_owned()))?;
+        let mut path = command.name.clone();
+        let mut checks: Vec<&Check> = command.checks.iter().collect();
+        loop {
+            let (word, after) = split_word(rest);
+            let Some(subcommand) = command.subcommand_named(word) else {
+                break;
+            };
+            command = subcommand;
+            checks.extend(&command.checks);
+            path.push(' ');
+            path.push_str(&command.name);
+            rest = after;
+        }
+
+        let Some(run) = &command.run else {
+            return Err(TextCommandError::MissingSubcommand(path));
+        };
+
+        if !checks.is_empty() {
+            let mut data = CheckData::from(message);
+            if let Some(lookup) = &self.check_data {
+                data = lookup(message.clone(), data, ctx.state.clone()).await;
+            }
+            run_checks(checks.iter().copied(), &data, &self.owners).map_err(|failure| {
+                TextCommandError::Check {
+                    command: path.clone(),
+                    failure,
+                }
+            })?;
+        }
+        let args = Args::new(rest).map_err(|error| TextCommandError::Args {
+            command: path.clone(),
+            error,
+        })?;
+
+        run(context(path.clone()), args).await.map_err(|error| {
+            // arg errors returned with `?` are reported as such, so they can be shown to the user
+            match error.downcast::<ArgError>() {
+                Ok(error) => TextCommandError::Args {
+                    command: path,
+                    error: *error,
+                },
+                Err(error) => TextCommandError::Command {
+                    command: path,
+                    error,
+                },
+            }
+        })
+    }
+}
+
+/// Split off the first word, returning it and the rest without leading whitespace
+fn split_word(input: &str) -> (&str, &str) {
+    let input = input.trim_start();
+    let end = input.find(char::is_whitespace).unwrap_or(input.len());
+    let (word, rest) = input.split_at(end);
+    (word, rest.trim_start())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn framework() -> PrefixFramework<()> {
+        PrefixFramework::new()
+            .prefix("!")
+            .command(
+                TextCommand::new("ban", "Ban a user", |_, _| async { Ok(()) })
+                    .alias("b")
+                    .usage("<user> [reason]"),
+            )
+            .command(
+                TextCommand::group("config", "Change settings").subcommand(TextCommand::new(
+                    "prefix",
+                    "Change the prefix",
+                    |_, _| async { Ok(()) },
+                )),
+            )
+    }
+
+    #[test]
+    fn test_split_word() {
+        assert_eq!(split_word("  ban  @user reason"), ("ban", "@user reason"));
+        assert_eq!(split_word("ban"), ("ban", ""));
+        assert_eq!(split_word(""), ("", ""));
+    }
+
+    #[test]
+    fn test_help_text() {
+        let framework = framework();
+        assert_eq!(
+            framework.help_text("!", "").unwrap(),
+            "Commands:\n!ban <user> [reason] - Ban a user\n!config - Change settings"
+        );
+        assert_eq!(
+            framework.help_text("!", "B").unwrap(),
+            "!ban <user> [reason] - Ban a user\nAliases: b"
+        );
+        assert_eq!(
+            framework.help_text("!", "config").unwrap(),
+            "!config - Change settings\nSubcommands:\n!config prefix - Change the prefix"
+        );
+        assert!(framework.help_text("!", "config reset").is_none());
+    }
+}
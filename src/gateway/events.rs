@@ -2,6 +2,12 @@
 
 use serde::Deserialize;
 
+use crate::datatypes::{
+    AutoModerationActionExecution, AutoModerationRule, Guild, GuildScheduledEvent, Integration, Interaction,
+    Invite,
+    Snowflake, StageInstance,
+};
+
 
 /// Discord Events, returned from [`Gateway`][crate::Gateway]
 #[derive(Deserialize, Debug, Clone)]
@@ -34,32 +40,198 @@ pub enum EventData {
     /// Sent when the client has successfully connected.
     Ready {},
 
+    /// Sent for each guild after connecting, when the bot joins a guild and when a guild becomes available again
+    ///
+    /// Requires [`Intents::GUILDS`][crate::Intents::GUILDS]
+    GuildCreate(Box<Guild>),
+
     /// Send when somebody sends a message
     /// 
     /// # Important
     /// This is also sent when the bot creates a message, make sure to avoid infinite loops!
     MessageCreate(crate::datatypes::Message),
+
+    /// Sent when a message is edited
+    ///
+    /// Requires [`Intents::GUILD_MESSAGES`][crate::Intents::GUILD_MESSAGES] or [`Intents::DIRECT_MESSAGES`][crate::Intents::DIRECT_MESSAGES]
+    MessageUpdate(crate::datatypes::Message),
+
+    /// Sent when a message is deleted
+    ///
+    /// Requires [`Intents::GUILD_MESSAGES`][crate::Intents::GUILD_MESSAGES] or [`Intents::DIRECT_MESSAGES`][crate::Intents::DIRECT_MESSAGES]
+    MessageDelete {
+        /// Id of the deleted message
+        id: Snowflake,
+        /// Channel of the message
+        channel_id: Snowflake,
+        /// Guild of the message, missing for dms
+        guild_id: Option<Snowflake>,
+    },
+
+    /// Sent when multiple messages are deleted at once
+    ///
+    /// Requires [`Intents::GUILD_MESSAGES`][crate::Intents::GUILD_MESSAGES]
+    MessageDeleteBulk {
+        /// Ids of the deleted messages
+        ids: Vec<Snowflake>,
+        /// Channel of the messages
+        channel_id: Snowflake,
+        /// Guild of the messages
+        guild_id: Option<Snowflake>,
+    },
+
+    /// Sent when a invite is created
+    ///
+    /// Requires [`Intents::GUILD_INVITES`][crate::Intents::GUILD_INVITES]
+    InviteCreate(Invite),
+
+    /// Sent when a invite is deleted
+    ///
+    /// Requires [`Intents::GUILD_INVITES`][crate::Intents::GUILD_INVITES]
+    InviteDelete {
+        /// Channel of the invite
+        channel_id: Snowflake,
+        /// Guild of the invite, missing for group dm invites
+        guild_id: Option<Snowflake>,
+        /// Unique invite code
+        code: String,
+    },
+
+    /// Sent when a webhook in a channel is created, updated or deleted
+    ///
+    /// Requires [`Intents::GUILD_WEBHOOKS`][crate::Intents::GUILD_WEBHOOKS]
+    WebhooksUpdate {
+        /// Guild of the channel
+        guild_id: Snowflake,
+        /// Channel where the webhooks changed
+        channel_id: Snowflake,
+    },
+
+    /// Sent when a integration is added to a guild
+    ///
+    /// Requires [`Intents::GUILD_INTEGRATIONS`][crate::Intents::GUILD_INTEGRATIONS]
+    IntegrationCreate(Integration),
+
+    /// Sent when a integration is updated
+    ///
+    /// Requires [`Intents::GUILD_INTEGRATIONS`][crate::Intents::GUILD_INTEGRATIONS]
+    IntegrationUpdate(Integration),
+
+    /// Sent when a integration is removed from a guild
+    ///
+    /// Requires [`Intents::GUILD_INTEGRATIONS`][crate::Intents::GUILD_INTEGRATIONS]
+    IntegrationDelete {
+        /// Integration id
+        id: Snowflake,
+        /// Guild the integration was removed from
+        guild_id: Snowflake,
+        /// Bot/oauth2 application for discord integrations
+        application_id: Option<Snowflake>,
+    },
+
+    /// Sent when a stage goes live
+    ///
+    /// Requires [`Intents::GUILDS`][crate::Intents::GUILDS]
+    StageInstanceCreate(StageInstance),
+
+    /// Sent when a stage is updated
+    ///
+    /// Requires [`Intents::GUILDS`][crate::Intents::GUILDS]
+    StageInstanceUpdate(StageInstance),
+
+    /// Sent when a stage is closed
+    ///
+    /// Requires [`Intents::GUILDS`][crate::Intents::GUILDS]
+    StageInstanceDelete(StageInstance),
+
+    /// Sent when a scheduled event is created
+    ///
+    /// Requires [`Intents::GUILD_SCHEDULED_EVENTS`][crate::Intents::GUILD_SCHEDULED_EVENTS]
+    GuildScheduledEventCreate(GuildScheduledEvent),
+
+    /// Sent when a scheduled event is updated, including when it starts, ends or is canceled
+    ///
+    /// Requires [`Intents::GUILD_SCHEDULED_EVENTS`][crate::Intents::GUILD_SCHEDULED_EVENTS]
+    GuildScheduledEventUpdate(GuildScheduledEvent),
+
+    /// Sent when a scheduled event is deleted
+    ///
+    /// Requires [`Intents::GUILD_SCHEDULED_EVENTS`][crate::Intents::GUILD_SCHEDULED_EVENTS]
+    GuildScheduledEventDelete(GuildScheduledEvent),
+
+    /// Sent when a user subscribes to a scheduled event
+    ///
+    /// Requires [`Intents::GUILD_SCHEDULED_EVENTS`][crate::Intents::GUILD_SCHEDULED_EVENTS]
+    GuildScheduledEventUserAdd {
+        /// Event the user subscribed to
+        guild_scheduled_event_id: Snowflake,
+        /// User that subscribed
+        user_id: Snowflake,
+        /// Guild of the event
+        guild_id: Snowflake,
+    },
+
+    /// Sent when a user unsubscribes from a scheduled event
+    ///
+    /// Requires [`Intents::GUILD_SCHEDULED_EVENTS`][crate::Intents::GUILD_SCHEDULED_EVENTS]
+    GuildScheduledEventUserRemove {
+        /// Event the user unsubscribed from
+        guild_scheduled_event_id: Snowflake,
+        /// User that unsubscribed
+        user_id: Snowflake,
+        /// Guild of the event
+        guild_id: Snowflake,
+    },
+
+    /// Sent when a auto moderation rule is created
+    ///
+    /// Requires [`Intents::AUTO_MODERATION_CONFIGURATION`][crate::Intents::AUTO_MODERATION_CONFIGURATION]
+    AutoModerationRuleCreate(AutoModerationRule),
+
+    /// Sent when a auto moderation rule is updated
+    ///
+    /// Requires [`Intents::AUTO_MODERATION_CONFIGURATION`][crate::Intents::AUTO_MODERATION_CONFIGURATION]
+    AutoModerationRuleUpdate(AutoModerationRule),
+
+    /// Sent when a auto moderation rule is deleted
+    ///
+    /// Requires [`Intents::AUTO_MODERATION_CONFIGURATION`][crate::Intents::AUTO_MODERATION_CONFIGURATION]
+    AutoModerationRuleDelete(AutoModerationRule),
+
+    /// Sent when a auto moderation rule is triggered and a action is executed
+    ///
+    /// Requires [`Intents::AUTO_MODERATION_EXECUTION`][crate::Intents::AUTO_MODERATION_EXECUTION]
+    AutoModerationActionExecution(AutoModerationActionExecution),
+
+    /// Sent when a user uses a application command, clicks a component or submits a modal
+    ///
+    /// Respond using [`Api::create_interaction_response`][crate::Api::create_interaction_response]
+    InteractionCreate(Box<Interaction>),
+
+    /// Events without a type in vivcord, these are skipped by the gateway and never sent to listeners
+    #[doc(hidden)]
+    #[serde(other)]
+    Unknown,
 }
 
 /// Raw data from discord api, used to convert into [`GatewayEvent`]
 #[derive(Deserialize)]
-struct RawEventData {
+pub struct RawEventData {
     #[serde(rename = "op")]
     opcode: u8,
     #[serde(rename = "t")]
     event_name: Option<String>,
     #[serde(rename = "s")]
-    sequence_number: Option<u32>,
+    pub sequence_number: Option<u32>,
     #[serde(rename = "d")]
     data: Option<serde_json::Value>,
 }
 
 /// Stores general event data
 #[derive(Deserialize, Debug, Clone)]
-#[serde(from = "RawEventData")]
+#[serde(try_from = "RawEventData")]
 pub struct GatewayEvent {
     pub data: EventData,
-    pub sequence_number: Option<u32>
 }
 
 
@@ -67,19 +239,28 @@ pub struct GatewayEvent {
 // this lets us use the same enum for all event types!
 
 
-impl From<RawEventData> for GatewayEvent {
-    fn from(raw_event: RawEventData) -> Self {
-        let event_name = if raw_event.opcode == 0 { raw_event.event_name.expect("Missing type field") } else { raw_event.opcode.to_string() };
+impl TryFrom<RawEventData> for GatewayEvent {
+    type Error = String;
+
+    fn try_from(raw_event: RawEventData) -> Result<Self, Self::Error> {
+        let event_name = if raw_event.opcode == 0 {
+            raw_event.event_name.ok_or("Missing type field")?
+        } else {
+            raw_event.opcode.to_string()
+        };
+
+        // the data of events we have no type for does not fit the unit `Unknown` variant, so check the name alone first
+        let unknown = serde_json::from_value(serde_json::json!({ "event_name": event_name }));
+        if let Ok(EventData::Unknown) = unknown {
+            return Ok(GatewayEvent { data: EventData::Unknown });
+        }
 
         let data: EventData = serde_json::from_value(serde_json::json!({
             "event_name": event_name,
             "data": raw_event.data
-        })).unwrap();
+        })).map_err(|err| format!("failed to parse {event_name} event: {err}"))?;
 
-        GatewayEvent {
-            data,
-            sequence_number: raw_event.sequence_number
-        }
+        Ok(GatewayEvent { data })
     }
 }
 
@@ -96,6 +277,20 @@ mod tests {
         assert!(matches!(event.data, EventData::HearthBeatAck));
     }
     
+    #[test]
+    fn test_unknown_event() {
+        let event: GatewayEvent = serde_json::from_str(r#"{"op": 0, "t": "TYPING_START", "s": 2, "d": {"channel_id": "1"}}"#).unwrap();
+
+        assert!(matches!(event.data, EventData::Unknown));
+    }
+
+    #[test]
+    fn test_invalid_event() {
+        let result = serde_json::from_str::<GatewayEvent>(r#"{"op": 0, "t": "INTERACTION_CREATE", "s": 2, "d": {"id": "1"}}"#);
+
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_hello() {
         let event: GatewayEvent = serde_json::from_str("{\"op\": 10, \"d\": {\"heartbeat_interval\": 45000}}").unwrap();
@@ -104,7 +299,199 @@ mod tests {
         if let EventData::Hello { heartbeat_interval } = data {
             assert_eq!(heartbeat_interval, 45000);
         } else {
-            panic!("Expected Hello Event got {:?}", data);
+            panic!("Expected Hello Event got {data:?}");
+        }
+    }
+
+    #[test]
+    fn test_invite_create() {
+        let event: GatewayEvent = serde_json::from_str(r#"{
+            "op": 0,
+            "t": "INVITE_CREATE",
+            "s": 3,
+            "d": {
+                "code": "abcdef",
+                "channel_id": "123",
+                "guild_id": "456",
+                "created_at": "2022-10-01T12:00:00.000000+00:00",
+                "max_age": 86400,
+                "max_uses": 0,
+                "uses": 0,
+                "temporary": false,
+                "target_type": 1
+            }
+        }"#).unwrap();
+        let data = event.data;
+
+        if let EventData::InviteCreate(invite) = data {
+            assert_eq!(invite.code, "abcdef");
+            assert_eq!(invite.guild_id, Some(Snowflake(456)));
+            assert_eq!(invite.target_type, Some(crate::datatypes::InviteTargetType::Stream));
+        } else {
+            panic!("Expected InviteCreate Event got {data:?}");
+        }
+    }
+
+    #[test]
+    fn test_guild_create() {
+        let event: GatewayEvent = serde_json::from_str(r#"{
+            "op": 0,
+            "t": "GUILD_CREATE",
+            "s": 2,
+            "d": {
+                "id": "1",
+                "name": "Cool guild",
+                "icon": null,
+                "owner_id": "2",
+                "roles": [{
+                    "id": "1",
+                    "name": "@everyone",
+                    "color": 0,
+                    "hoist": false,
+                    "position": 0,
+                    "permissions": "1024",
+                    "managed": false,
+                    "mentionable": false
+                }],
+                "emojis": [],
+                "features": ["COMMUNITY"],
+                "joined_at": "2022-10-01T12:00:00.000000+00:00",
+                "large": false,
+                "unavailable": false,
+                "member_count": 3,
+                "members": [{"user": {"id": "3", "username": "bot", "discriminator": "0"}, "roles": []}],
+                "channels": [{"id": "4", "type": 0, "name": "general"}],
+                "threads": [],
+                "stage_instances": [],
+                "guild_scheduled_events": []
+            }
+        }"#).unwrap();
+        let data = event.data;
+
+        if let EventData::GuildCreate(guild) = data {
+            assert_eq!(guild.name, "Cool guild");
+            assert_eq!(guild.roles[0].permissions, "1024");
+            assert_eq!(guild.channels[0].name.as_deref(), Some("general"));
+            assert_eq!(guild.member_count, Some(3));
+        } else {
+            panic!("Expected GuildCreate Event got {data:?}");
+        }
+
+        let event: GatewayEvent = serde_json::from_str(
+            r#"{"op": 0, "t": "GUILD_CREATE", "s": 3, "d": {"id": "5", "unavailable": true}}"#
+        ).unwrap();
+
+        assert!(matches!(event.data, EventData::GuildCreate(guild) if guild.unavailable && guild.id == Snowflake(5)));
+    }
+
+    #[test]
+    fn test_message_delete_bulk() {
+        let event: GatewayEvent = serde_json::from_str(
+            r#"{"op": 0, "t": "MESSAGE_DELETE_BULK", "s": 4, "d": {"ids": ["1", "2"], "channel_id": "3", "guild_id": "4"}}"#
+        ).unwrap();
+
+        assert!(matches!(
+            event.data,
+            EventData::MessageDeleteBulk { ids, channel_id: Snowflake(3), guild_id: Some(Snowflake(4)) } if ids == [Snowflake(1), Snowflake(2)]
+        ));
+    }
+
+    #[test]
+    fn test_integration_delete() {
+        let event: GatewayEvent = serde_json::from_str(
+            r#"{"op": 0, "t": "INTEGRATION_DELETE", "s": 4, "d": {"id": "1", "guild_id": "2"}}"#
+        ).unwrap();
+
+        assert!(matches!(
+            event.data,
+            EventData::IntegrationDelete { id: Snowflake(1), guild_id: Snowflake(2), application_id: None }
+        ));
+    }
+
+    #[test]
+    fn test_scheduled_event_update() {
+        let event: GatewayEvent = serde_json::from_str(r#"{
+            "op": 0,
+            "t": "GUILD_SCHEDULED_EVENT_UPDATE",
+            "s": 5,
+            "d": {
+                "id": "1",
+                "guild_id": "2",
+                "channel_id": null,
+                "name": "Movie night",
+                "scheduled_start_time": "2022-10-01T20:00:00+00:00",
+                "scheduled_end_time": "2022-10-01T22:00:00+00:00",
+                "privacy_level": 2,
+                "status": 2,
+                "entity_type": 3,
+                "entity_id": null,
+                "entity_metadata": {"location": "The cinema"}
+            }
+        }"#).unwrap();
+        let data = event.data;
+
+        if let EventData::GuildScheduledEventUpdate(scheduled) = data {
+            assert_eq!(scheduled.status, crate::datatypes::GuildScheduledEventStatus::Active);
+            assert_eq!(scheduled.entity_metadata.unwrap().location.as_deref(), Some("The cinema"));
+        } else {
+            panic!("Expected GuildScheduledEventUpdate Event got {data:?}");
+        }
+    }
+
+    #[test]
+    fn test_auto_moderation_action_execution() {
+        let event: GatewayEvent = serde_json::from_str(r#"{
+            "op": 0,
+            "t": "AUTO_MODERATION_ACTION_EXECUTION",
+            "s": 6,
+            "d": {
+                "guild_id": "1",
+                "action": {"type": 3, "metadata": {"duration_seconds": 60}},
+                "rule_id": "2",
+                "rule_trigger_type": 1,
+                "user_id": "3",
+                "channel_id": "4",
+                "message_id": "5",
+                "content": "what the heck",
+                "matched_keyword": "heck",
+                "matched_content": "heck"
+            }
+        }"#).unwrap();
+        let data = event.data;
+
+        if let EventData::AutoModerationActionExecution(execution) = data {
+            assert_eq!(execution.action.kind, crate::datatypes::AutoModerationActionType::Timeout);
+            assert_eq!(execution.action.metadata.unwrap().duration_seconds, Some(60));
+            assert_eq!(execution.matched_keyword.as_deref(), Some("heck"));
+        } else {
+            panic!("Expected AutoModerationActionExecution Event got {data:?}");
+        }
+    }
+
+    #[test]
+    fn test_interaction_create() {
+        let event: GatewayEvent = serde_json::from_str(r#"{
+            "op": 0,
+            "t": "INTERACTION_CREATE",
+            "s": 7,
+            "d": {
+                "id": "1",
+                "application_id": "2",
+                "type": 2,
+                "guild_id": "3",
+                "channel_id": "4",
+                "token": "token",
+                "version": 1,
+                "data": {"id": "5", "name": "ping", "type": 1}
+            }
+        }"#).unwrap();
+        let data = event.data;
+
+        if let EventData::InteractionCreate(interaction) = data {
+            assert_eq!(interaction.kind, crate::datatypes::InteractionType::ApplicationCommand);
+            assert_eq!(interaction.command_data().unwrap().name, "ping");
+        } else {
+            panic!("Expected InteractionCreate Event got {data:?}");
         }
     }
 }
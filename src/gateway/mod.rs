@@ -7,10 +7,17 @@ use std::{sync::Arc, time::Duration};
 
 pub use events::EventData;
 
+use crate::datatypes::Presence;
+use crate::Intents;
+
+/// Default amount of events buffered for slow listeners, older events are dropped for them once it is full
+pub const DEFAULT_EVENT_BUFFER: usize = 5;
+
 use futures::{Future, SinkExt, StreamExt};
 use std::sync::Mutex;
 use tokio::select;
 use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
 use tokio_tungstenite::tungstenite::{protocol::WebSocketConfig, Error, Message};
 
 /// Same as [`Gateway::wait_for`] but operates on a stream.
@@ -110,10 +117,88 @@ async fn create_connection(
     connection.split()
 }
 
-/// Websocket for getting events from discord gateway.
-#[derive(Debug)]
+/// What to send discord when identifying, see [`Gateway::connect_with`]
+///
+/// # Example
+/// ```
+/// # use vivcord::gateway::ConnectOptions;
+/// # use vivcord::datatypes::{Activity, Presence, Status};
+/// let options = ConnectOptions::new(vivcord::Intents::GUILD_MESSAGES)
+///     .shard(0, 2)
+///     .presence(Presence::new(Status::Online).activity(Activity::playing("with the api")));
+/// ```
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ConnectOptions {
+    /// Events to receive
+    pub intents: Intents,
+    /// `[shard_id, shard_count]` of this connection
+    pub shard: Option<[u32; 2]>,
+    /// Presence shown once connected
+    pub presence: Option<Presence>,
+    /// Member count from which discord stops sending offline members of a guild, between 50 and 250
+    pub large_threshold: Option<u8>,
+}
+
+impl ConnectOptions {
+    /// Options receiving the given intents, on a single shard
+    pub fn new(intents: Intents) -> Self {
+        Self {
+            intents,
+            shard: None,
+            presence: None,
+            large_threshold: None,
+        }
+    }
+
+    /// Connect as shard `shard_id` out of `shard_count`
+    pub fn shard(mut self, shard_id: u32, shard_count: u32) -> Self {
+        self.shard = Some([shard_id, shard_count]);
+        self
+    }
+
+    /// Presence shown once connected
+    pub fn presence(mut self, presence: Presence) -> Self {
+        self.presence = Some(presence);
+        self
+    }
+
+    /// Member count from which discord stops sending offline members of a guild, between 50 and 250
+    pub fn large_threshold(mut self, large_threshold: u8) -> Self {
+        self.large_threshold = Some(large_threshold);
+        self
+    }
+
+    /// The `d` field of the identify payload
+    fn identify(&self, token: &str) -> serde_json::Value {
+        let mut data = serde_json::json!({
+            "token": token,
+            "intents": self.intents.bits(),
+            "properties": {
+                "os": std::env::consts::OS,
+                "browser": "vivcord-rs",
+                "device": "vivcord-rs"
+            }
+        });
+        if let Some(shard) = self.shard {
+            data["shard"] = serde_json::json!(shard);
+        }
+        if let Some(presence) = &self.presence {
+            data["presence"] = serde_json::to_value(presence).unwrap();
+        }
+        if let Some(large_threshold) = self.large_threshold {
+            data["large_threshold"] = large_threshold.into();
+        }
+        data
+    }
+}
+
+/// Handle to the websocket for getting events from discord gateway.
+///
+/// Cloning is cheap, clones receive the events of the same connection.
+#[derive(Debug, Clone)]
 pub struct Gateway {
-    event_reader: Option<broadcast::Receiver<EventData>>,
+    event_writer: broadcast::Sender<EventData>,
 }
 
 impl Default for Gateway {
@@ -126,7 +211,20 @@ impl Gateway {
     /// Create gateway instance.
     #[must_use]
     pub fn new() -> Self {
-        Self { event_reader: None }
+        // IMPORTANT: Should this be larger/smaller?
+        // In theory all events should be processed almost at once
+        // as long as the user doesn't block the thread (HEY MATISSE, SOUNDS FAMILIAR?)
+        Self::with_capacity(DEFAULT_EVENT_BUFFER)
+    }
+
+    /// Create gateway instance buffering up to `capacity` events for slow listeners.
+    ///
+    /// # Panics
+    /// If `capacity` is `0`
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let (event_writer, _) = broadcast::channel::<events::EventData>(capacity);
+        Self { event_writer }
     }
 
     /// Create new gateway connection using a oauth token. <br>
@@ -135,15 +233,21 @@ impl Gateway {
     ///
     /// # Panics
     /// If there is an error connecting to the gateway.
+    pub async fn connect(&self, url: &str, token: &str, intents: &crate::Intents) {
+        self.connect_with(url, token, &ConnectOptions::new(*intents))
+            .await;
+    }
+
+    /// Same as [`Gateway::connect`], but with control over the shard, presence and large threshold.
+    ///
+    /// # Panics
+    /// If there is an error connecting to the gateway.
     #[allow(unreachable_code)]
-    pub async fn connect(&mut self, url: &str, token: &str, intents: &crate::Intents) {
+    pub async fn connect_with(&self, url: &str, token: &str, options: &ConnectOptions) {
         let (mut stream_writer, stream_reader) = create_connection(url).await;
 
-        // IMPORTANT: Should this be larger/smaller?
-        // In theory all events should be processed almost at once
-        // as long as the user doesn't block the thread (HEY MATISSE, SOUNDS FAMILIAR?)
-        let (event_writer, event_reader) = broadcast::channel::<events::EventData>(5);
-        self.event_reader = Some(event_reader);
+        // subscribe before starting the event loop, so the hello event is not missed
+        let mut event_reader = self.event_writer.subscribe();
 
         // create sequence number with Mutex so the event reader and heartbeat can both use it
         // we don't store this on the struct because it should only be useful to those 2 tasks
@@ -152,11 +256,14 @@ impl Gateway {
 
         tokio::spawn(Gateway::event_loop(
             stream_reader,
-            event_writer,
+            self.event_writer.clone(),
             sequence_number.clone(), // lets event reader thread update sequence number
         ));
-        let hearth_interval =
-            wait_for!(self, EventData::Hello { heartbeat_interval } => heartbeat_interval).await;
+        let hearth_interval = wait_for_S!(
+            &mut event_reader,
+            EventData::Hello { heartbeat_interval } => heartbeat_interval
+        )
+        .await;
 
         // Send identify packet
         // Api allows us (and actually tells us) to send heartbeat's while doing this
@@ -164,15 +271,7 @@ impl Gateway {
         let data = Message::Text(
             serde_json::to_string(&serde_json::json!({
                 "op": 2,
-                "d": {
-                    "token": token,
-                    "intents": intents.bits(),
-                    "properties": {
-                        "os": std::env::consts::OS,
-                        "browser": "vivcord-rs",
-                        "device": "vivcord-rs"
-                    }
-                }
+                "d": options.identify(token)
             }))
             .unwrap(),
         );
@@ -180,7 +279,7 @@ impl Gateway {
 
         tokio::spawn(Gateway::heartbeat(
             stream_writer,
-            self.event_reader.as_ref().unwrap().resubscribe(),
+            event_reader,
             hearth_interval,
             sequence_number,
         ));
@@ -199,9 +298,30 @@ impl Gateway {
             let msg = reader.next().await.unwrap().unwrap();
             match msg {
                 Message::Text(data) => {
-                    let event: events::GatewayEvent = serde_json::from_str(&data).unwrap();
-                    eprintln!("got event {:?}", event.data);
-                    *sequence_number.lock().unwrap() = event.sequence_number;
+                    let raw: events::RawEventData = match serde_json::from_str(&data) {
+                        Ok(raw) => raw,
+                        Err(err) => {
+                            eprintln!("[WARNING] Invalid gateway message: {err}");
+                            continue;
+                        }
+                    };
+                    // only dispatch events have a sequence number, heartbeats send the last one
+                    if raw.sequence_number.is_some() {
+                        *sequence_number.lock().unwrap() = raw.sequence_number;
+                    }
+
+                    // events that do not match our types are skipped instead of stopping the event loop,
+                    // only warning about the ones we have a type for
+                    let event = match events::GatewayEvent::try_from(raw) {
+                        Ok(events::GatewayEvent {
+                            data: EventData::Unknown,
+                        }) => continue,
+                        Ok(event) => event,
+                        Err(err) => {
+                            eprintln!("[WARNING] Skipping event, {err}");
+                            continue;
+                        }
+                    };
 
                     // Ok => just amount it was sent to
                     // Err => nobody is listening, but they might in the future
@@ -235,7 +355,6 @@ impl Gateway {
 
         loop {
             // send heartbeat event
-            println!("sending heartbeat");
             let data = serde_json::json!({
                 "op": 1,
                 "d": sequence_number.lock().unwrap().clone()
@@ -260,8 +379,8 @@ impl Gateway {
 
             // Wait for one of those tasks to finish, dropping (canceling) the other.
             select! {
-                _ = sleeper_task => (),
-                _ = requests_waiter => ()
+                () = sleeper_task => (),
+                () = requests_waiter => ()
             };
         }
     }
@@ -272,6 +391,9 @@ impl Gateway {
     /// once the predicate returns a `Some(value)` this function will then return the `value`,
     /// if the passed event is not the desired one return [`None`]
     ///
+    /// This only returns once [`Gateway::connect`] has been called.
+    /// Events are listened for from the moment this is called, not from when the future is first polled.
+    ///
     /// # Example
     /// ```no_run
     /// # use vivcord::{Gateway, EventData};
@@ -288,16 +410,12 @@ impl Gateway {
     /// }).await;
     /// # });
     /// ```
-    pub async fn wait_for<T, F>(&self, predicate: F) -> T
+    pub fn wait_for<T, F>(&self, predicate: F) -> impl Future<Output = T>
     where
         F: FnMut(EventData) -> Option<T>,
     {
-        let mut reader = self
-            .event_reader
-            .as_ref()
-            .expect("Event loop not started")
-            .resubscribe();
-        wait_for(&mut reader, predicate).await
+        let mut reader = self.event_writer.subscribe();
+        async move { wait_for(&mut reader, predicate).await }
     }
 
     /// Keep calling `callback` with events gotten forever,
@@ -308,8 +426,7 @@ impl Gateway {
     ///
     /// You can also define a struct to hold multiple Mutexes, to make the code more efficient (the less data behind a single lock the better);
     ///
-    /// # Panics
-    /// When the event loop has not been started yet, or if there is an error with reading the events from the event loop
+    /// Events are skipped when the callback is called slower than events come in and the event buffer fills up.
     ///
     /// # Example
     /// ```no_run
@@ -329,14 +446,84 @@ impl Gateway {
         A: Future<Output = ()> + Send + 'static,
         S: Clone,
     {
-        let mut reader = self
-            .event_reader
-            .as_ref()
-            .expect("Event loop not started")
-            .resubscribe();
+        let mut reader = self.event_writer.subscribe();
         loop {
-            let event = reader.recv().await.unwrap();
+            let event = match reader.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(skipped)) => {
+                    eprintln!("[WARNING] Fell behind on events, skipped {skipped}");
+                    continue;
+                }
+                Err(RecvError::Closed) => unreachable!("the gateway keeps the sender alive"),
+            };
             tokio::spawn(callback(event, state.clone()));
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::Status;
+
+    #[test]
+    fn test_identify_options() {
+        let plain = ConnectOptions::new(Intents::GUILDS).identify("TOKEN");
+        assert_eq!(plain["intents"], 1);
+        assert!(plain.get("shard").is_none());
+        assert!(plain.get("presence").is_none());
+
+        let data = ConnectOptions::new(Intents::GUILDS)
+            .shard(1, 4)
+            .presence(Presence::new(Status::Idle))
+            .large_threshold(100)
+            .identify("TOKEN");
+        assert_eq!(data["shard"], serde_json::json!([1, 4]));
+        assert_eq!(data["presence"]["status"], "idle");
+        assert_eq!(data["large_threshold"], 100);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_listens_before_polled() {
+        let gateway = Gateway::new();
+        let ready = gateway.wait_for(|event| matches!(event, EventData::Ready {}).then_some(()));
+        gateway.event_writer.send(EventData::Ready {}).unwrap();
+
+        tokio::time::timeout(Duration::from_secs(1), ready)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_on_skips_lagged_events() {
+        let gateway = Gateway::with_capacity(1);
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let listener = gateway.clone();
+        tokio::spawn(async move {
+            listener
+                .on(sender, |event, sender| {
+                    sender.send(event).unwrap();
+                    async {}
+                })
+                .await;
+        });
+        // let the listener subscribe
+        tokio::task::yield_now().await;
+
+        for heartbeat_interval in 0..3 {
+            gateway
+                .event_writer
+                .send(EventData::Hello { heartbeat_interval })
+                .unwrap();
+        }
+
+        let event = receiver.recv().await.unwrap();
+        assert!(matches!(
+            event,
+            EventData::Hello {
+                heartbeat_interval: 2
+            }
+        ));
+        assert!(receiver.try_recv().is_err());
+    }
+}
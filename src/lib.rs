@@ -4,21 +4,32 @@
 #![warn(missing_docs)]
 #![warn(missing_copy_implementations)]
 #![warn(missing_debug_implementations)]
+// `Duration::from_mins` and `Duration::from_hours` need rust 1.91
+#![allow(clippy::duration_suboptimal_units)]
+
+// lets the code generated by `vivcord-macros` refer to `::vivcord` inside this crate
+extern crate self as vivcord;
 
 #[macro_use]
 mod macros;
 
+pub mod cache;
 pub mod client;
 pub mod api;
 pub mod gateway;
 pub mod datatypes;
+pub mod framework;
 
 pub use client::Client;
+pub use client::Context;
 
 pub use api::Api;
 
 pub use gateway::Gateway;
 pub use gateway::EventData;
 
+/// Used to implement [`EventHandler`][client::EventHandler]
+pub use async_trait::async_trait;
+
 pub use datatypes::Intents;
 pub use datatypes::CreateMessageParams;
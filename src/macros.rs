@@ -36,6 +36,59 @@ macro_rules! comp_by_field {
     };
 }
 
+/// Create a enum for a integer based discord type.
+///
+/// Discord adds new values to these types from time to time,
+/// so values not known by `vivcord` are stored in a `Unknown` variant instead of failing to parse.
+///
+/// syntax is `int_enum! { pub enum Name: u8 { Variant = 1, } }`
+macro_rules! int_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident: $int:ty {
+            $( $(#[$variant_meta:meta])* $variant:ident = $value:literal, )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $( $(#[$variant_meta])* $variant, )*
+            /// Value not known by `vivcord`
+            Unknown($int),
+        }
+
+        impl From<$int> for $name {
+            fn from(value: $int) -> Self {
+                match value {
+                    $( $value => Self::$variant, )*
+                    other => Self::Unknown(other),
+                }
+            }
+        }
+
+        impl From<$name> for $int {
+            fn from(value: $name) -> Self {
+                match value {
+                    $( $name::$variant => $value, )*
+                    $name::Unknown(other) => other,
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <$int as serde::Deserialize>::deserialize(deserializer).map(Self::from)
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serde::Serialize::serialize(&<$int>::from(*self), serializer)
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     struct TestingStruct {
@@ -62,4 +115,24 @@ mod tests {
     fn test_cmp_lt() {
         assert!(TestingStruct { field: 0} < TestingStruct { field: 1}); 
     }
+
+    int_enum! {
+        enum TestingEnum: u8 {
+            First = 1,
+            Second = 2,
+        }
+    }
+
+    #[test]
+    fn test_int_enum_known() {
+        let value: TestingEnum = serde_json::from_str("2").unwrap();
+        assert_eq!(value, TestingEnum::Second);
+    }
+
+    #[test]
+    fn test_int_enum_unknown() {
+        let value: TestingEnum = serde_json::from_str("7").unwrap();
+        assert_eq!(value, TestingEnum::Unknown(7));
+        assert_eq!(serde_json::to_string(&value).unwrap(), "7");
+    }
 }
@@ -0,0 +1,401 @@
+//! `#[derive(Command)]` and `#[command]`
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::parse::Parser;
+use syn::spanned::Spanned;
+use syn::{Attribute, Expr, FnArg, Ident, LitStr, Pat, Type};
+
+/// Settings of the command itself
+#[derive(Default)]
+struct CommandInfo {
+    name: Option<String>,
+    description: Option<String>,
+}
+
+impl CommandInfo {
+    /// Parse a single `name = "..."` or `description = "..."` setting
+    fn parse_meta(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("name") {
+            self.name = Some(meta.value()?.parse::<LitStr>()?.value());
+        } else if meta.path.is_ident("description") {
+            self.description = Some(meta.value()?.parse::<LitStr>()?.value());
+        } else {
+            return Err(meta.error("unknown command setting, expected `name` or `description`"));
+        }
+        Ok(())
+    }
+}
+
+/// A option of the command, created from a struct field or function argument
+struct OptionInfo {
+    ident: Ident,
+    ty: Type,
+    name: String,
+    description: String,
+    autocomplete: bool,
+    min_value: Option<Expr>,
+    max_value: Option<Expr>,
+    min_length: Option<Expr>,
+    max_length: Option<Expr>,
+}
+
+impl OptionInfo {
+    /// Read the `#[option(...)]` attributes and doc comment of a field or argument
+    fn new(ident: Ident, ty: Type, attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut description = doc_comment(attrs);
+        let mut info = Self {
+            name: ident.to_string().trim_start_matches("r#").to_owned(),
+            ident,
+            ty,
+            description: String::new(),
+            autocomplete: false,
+            min_value: None,
+            max_value: None,
+            min_length: None,
+            max_length: None,
+        };
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("option")) {
+            attr.parse_nested_meta(|meta| {
+                let setting = meta
+                    .path
+                    .get_ident()
+                    .map(ToString::to_string)
+                    .unwrap_or_default();
+                match setting.as_str() {
+                    "name" => info.name = meta.value()?.parse::<LitStr>()?.value(),
+                    "description" => {
+                        description = Some(meta.value()?.parse::<LitStr>()?.value());
+                    }
+                    "autocomplete" => info.autocomplete = true,
+                    "min_value" => info.min_value = Some(meta.value()?.parse()?),
+                    "max_value" => info.max_value = Some(meta.value()?.parse()?),
+                    "min_length" => info.min_length = Some(meta.value()?.parse()?),
+                    "max_length" => info.max_length = Some(meta.value()?.parse()?),
+                    _ => return Err(meta.error(
+                        "unknown option setting, expected one of `name`, `description`, `autocomplete`, `min_value`, `max_value`, `min_length`, `max_length`",
+                    )),
+                }
+                Ok(())
+            })?;
+        }
+
+        info.description = description.ok_or_else(|| {
+            syn::Error::new(
+                info.ident.span(),
+                "option needs a description, add a doc comment or `#[option(description = \"...\")]`",
+            )
+        })?;
+        Ok(info)
+    }
+
+    /// Expression creating the `ApplicationCommandOption`
+    fn definition(&self) -> TokenStream {
+        let Self {
+            ty,
+            name,
+            description,
+            autocomplete,
+            ..
+        } = self;
+        let optional = |setting: &Option<Expr>, wrap: fn(&Expr) -> TokenStream| {
+            setting.as_ref().map_or_else(
+                || quote!(::core::option::Option::None),
+                |value| {
+                    let value = wrap(value);
+                    quote!(::core::option::Option::Some(#value))
+                },
+            )
+        };
+        let number = |value: &Expr| quote!(::vivcord::framework::__private::number(#value));
+        let length = |value: &Expr| quote!(#value);
+
+        let min_value = optional(&self.min_value, number);
+        let max_value = optional(&self.max_value, number);
+        let min_length = optional(&self.min_length, length);
+        let max_length = optional(&self.max_length, length);
+
+        quote! {
+            ::vivcord::datatypes::ApplicationCommandOption {
+                required: <#ty as ::vivcord::framework::CommandOption>::REQUIRED,
+                autocomplete: #autocomplete,
+                min_value: #min_value,
+                max_value: #max_value,
+                min_length: #min_length,
+                max_length: #max_length,
+                ..::vivcord::datatypes::ApplicationCommandOption::new(
+                    <#ty as ::vivcord::framework::CommandOption>::KIND,
+                    #name,
+                    #description,
+                )
+            }
+        }
+    }
+}
+
+/// Join the lines of the doc comment, if there is one
+pub(crate) fn doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(line),
+                        ..
+                    }),
+                ..
+            }) => Some(line.value().trim().to_owned()),
+            _ => None,
+        })
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join(" "))
+    }
+}
+
+/// `BanUser` => `ban_user`
+fn snake_case(name: &str) -> String {
+    let mut result = String::new();
+    for (index, character) in name.chars().enumerate() {
+        if character.is_uppercase() && index != 0 {
+            result.push('_');
+        }
+        result.extend(character.to_lowercase());
+    }
+    result
+}
+
+/// Implement `Command` for `ident`
+fn impl_command(
+    ident: &Ident,
+    info: CommandInfo,
+    options: &[OptionInfo],
+    span: Span,
+) -> syn::Result<TokenStream> {
+    let name = info
+        .name
+        .unwrap_or_else(|| snake_case(ident.to_string().trim_start_matches("r#")));
+    let description = info.description.ok_or_else(|| {
+        syn::Error::new(
+            span,
+            "command needs a description, add a doc comment or `description = \"...\"`",
+        )
+    })?;
+
+    let definitions = options.iter().map(OptionInfo::definition);
+    let fields = options.iter().map(|option| {
+        let ident = &option.ident;
+        let name = &option.name;
+        quote!(#ident: ::vivcord::framework::__private::parse_option(data, #name)?)
+    });
+
+    Ok(quote! {
+        impl ::vivcord::framework::Command for #ident {
+            const NAME: &'static str = #name;
+
+            fn definition() -> ::vivcord::datatypes::CreateApplicationCommandParams {
+                ::vivcord::datatypes::CreateApplicationCommandParams {
+                    options: ::std::vec![#(#definitions),*],
+                    ..::vivcord::datatypes::CreateApplicationCommandParams::chat_input(#name, #description)
+                }
+            }
+
+            fn parse(
+                data: &::vivcord::datatypes::ApplicationCommandData,
+            ) -> ::core::result::Result<Self, ::vivcord::framework::CommandParseError> {
+                ::core::result::Result::Ok(Self {
+                    #(#fields,)*
+                })
+            }
+        }
+    })
+}
+
+/// `#[derive(Command)]`
+pub fn derive(input: &syn::DeriveInput) -> syn::Result<TokenStream> {
+    let syn::Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            "Command can only be derived for structs",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "commands can not be generic",
+        ));
+    }
+
+    let mut info = CommandInfo {
+        description: doc_comment(&input.attrs),
+        ..Default::default()
+    };
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("command_info"))
+    {
+        attr.parse_nested_meta(|meta| info.parse_meta(&meta))?;
+    }
+
+    let options = match &data.fields {
+        syn::Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| {
+                let ident = field.ident.clone().expect("named fields have a name");
+                OptionInfo::new(ident, field.ty.clone(), &field.attrs)
+            })
+            .collect::<syn::Result<Vec<_>>>()?,
+        syn::Fields::Unit => Vec::new(),
+        syn::Fields::Unnamed(fields) => {
+            return Err(syn::Error::new(
+                fields.span(),
+                "command options need a name, use named fields",
+            ))
+        }
+    };
+
+    impl_command(&input.ident, info, &options, input.ident.span())
+}
+
+/// `#[command]`
+pub fn attribute(attr: TokenStream, mut item: syn::ItemFn) -> syn::Result<TokenStream> {
+    let mut info = CommandInfo {
+        description: doc_comment(&item.attrs),
+        ..Default::default()
+    };
+    syn::meta::parser(|meta| info.parse_meta(&meta)).parse2(attr)?;
+
+    let sig = &item.sig;
+    if sig.asyncness.is_none() {
+        return Err(syn::Error::new(
+            sig.fn_token.span(),
+            "commands must be async",
+        ));
+    }
+    if !sig.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            sig.generics.span(),
+            "commands can not be generic",
+        ));
+    }
+
+    let mut inputs = item.sig.inputs.iter_mut();
+    let Some(FnArg::Typed(context)) = inputs.next() else {
+        return Err(syn::Error::new(
+            item.sig.ident.span(),
+            "the first argument of a command must be the context",
+        ));
+    };
+    let context_ty = context.ty.clone();
+
+    let mut options = Vec::new();
+    for input in inputs {
+        let FnArg::Typed(input) = input else {
+            return Err(syn::Error::new(input.span(), "commands can not take self"));
+        };
+        let Pat::Ident(pattern) = &*input.pat else {
+            return Err(syn::Error::new(
+                input.pat.span(),
+                "command options must be plain names",
+            ));
+        };
+        options.push(OptionInfo::new(
+            pattern.ident.clone(),
+            (*input.ty).clone(),
+            &input.attrs,
+        )?);
+        // the attributes are only used by us, they are not allowed on the real function
+        input.attrs.retain(|attr| !attr.path().is_ident("option"));
+    }
+
+    let ident = item.sig.ident.clone();
+    let vis = item.vis.clone();
+    let (docs, attrs): (Vec<_>, Vec<_>) = item
+        .attrs
+        .drain(..)
+        .partition(|attr| attr.path().is_ident("doc"));
+    let fields = options.iter().map(|option| {
+        let OptionInfo {
+            ident,
+            ty,
+            description,
+            ..
+        } = option;
+        quote!(#[doc = #description] #vis #ident: #ty)
+    });
+    let arguments: Vec<_> = options.iter().map(|option| &option.ident).collect();
+    let command_impl = impl_command(&ident, info, &options, ident.span())?;
+
+    Ok(quote! {
+        #(#docs)*
+        #[allow(non_camel_case_types)]
+        #vis struct #ident {
+            #(#fields,)*
+        }
+
+        #command_impl
+
+        impl ::vivcord::framework::CommandHandler for #ident {
+            type Context = #context_ty;
+
+            fn run(
+                context: Self::Context,
+                args: Self,
+            ) -> ::vivcord::framework::BoxFuture<'static, ::vivcord::framework::CommandResult> {
+                #(#attrs)*
+                #item
+
+                let Self { #(#arguments),* } = args;
+                ::std::boxed::Box::pin(#ident(context, #(#arguments),*))
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("Ping"), "ping");
+        assert_eq!(snake_case("BanUser"), "ban_user");
+    }
+
+    #[test]
+    fn test_doc_comment() {
+        let item: syn::ItemStruct = syn::parse_quote! {
+            /// Ban a user
+            ///
+            /// from the guild
+            struct Ban;
+        };
+
+        assert_eq!(
+            doc_comment(&item.attrs).as_deref(),
+            Some("Ban a user from the guild")
+        );
+    }
+
+    #[test]
+    fn test_missing_description() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            /// Ban a user
+            struct Ban {
+                user: User,
+            }
+        };
+
+        assert!(derive(&input).is_err());
+    }
+}
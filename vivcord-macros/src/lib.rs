@@ -0,0 +1,61 @@
+//! Procedural macros for the [`vivcord`](https://docs.rs/vivcord) command framework.
+//!
+//! These are re-exported from `vivcord::framework`, depend on that crate instead of this one.
+
+#![warn(clippy::pedantic)]
+#![warn(missing_docs)]
+
+mod command;
+mod modal;
+
+use proc_macro::TokenStream;
+
+/// Turn a struct into a slash command, with a option for each field.
+///
+/// The command description is taken from the doc comment, or from `#[command_info(description = "...")]`.
+/// The name defaults to the struct name in snake case, and can be changed with `#[command_info(name = "...")]`.
+///
+/// Fields use `#[option(...)]` to set `name`, `description`, `autocomplete`,
+/// `min_value`, `max_value`, `min_length` and `max_length`.
+/// Their description is taken from their doc comment when not set.
+///
+/// See `vivcord::framework::Command` for a example.
+#[proc_macro_derive(Command, attributes(command_info, option))]
+pub fn derive_command(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    command::derive(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Turn a async function into a slash command.
+///
+/// The first argument is the context, the other arguments become options of the command
+/// and accept the same `#[option(...)]` attribute as `#[derive(Command)]` fields.
+///
+/// This replaces the function with a struct of the same name,
+/// which implements `Command` and `CommandHandler` and can be registered with `Framework::register`.
+///
+/// See `vivcord::framework::CommandHandler` for a example.
+#[proc_macro_attribute]
+pub fn command(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = syn::parse_macro_input!(item as syn::ItemFn);
+    command::attribute(attr.into(), item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Read the values of a submitted modal into a struct, with a text input for each field.
+///
+/// Fields must be `String` or `Option<String>`, optional fields do not have to be filled in.
+/// The label is taken from the doc comment, and fields use `#[input(...)]` to set
+/// `custom_id`, `label`, `paragraph`, `min_length`, `max_length`, `placeholder` and `value`.
+///
+/// See `vivcord::framework::ModalForm` for a example.
+#[proc_macro_derive(ModalForm, attributes(input))]
+pub fn derive_modal_form(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    modal::derive(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
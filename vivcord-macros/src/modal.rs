@@ -0,0 +1,178 @@
+//! `#[derive(ModalForm)]`
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{Expr, Ident, LitStr, Type};
+
+use crate::command::doc_comment;
+
+/// A text input of the modal, created from a struct field
+struct InputInfo {
+    ident: Ident,
+    ty: Type,
+    custom_id: String,
+    label: String,
+    paragraph: bool,
+    min_length: Option<Expr>,
+    max_length: Option<Expr>,
+    placeholder: Option<LitStr>,
+    value: Option<LitStr>,
+}
+
+impl InputInfo {
+    /// Read the `#[input(...)]` attributes and doc comment of a field
+    fn new(field: &syn::Field) -> syn::Result<Self> {
+        let ident = field.ident.clone().expect("named fields have a name");
+        let mut label = doc_comment(&field.attrs);
+        let mut info = Self {
+            custom_id: ident.to_string().trim_start_matches("r#").to_owned(),
+            ident,
+            ty: field.ty.clone(),
+            label: String::new(),
+            paragraph: false,
+            min_length: None,
+            max_length: None,
+            placeholder: None,
+            value: None,
+        };
+
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("input"))
+        {
+            attr.parse_nested_meta(|meta| {
+                let setting = meta
+                    .path
+                    .get_ident()
+                    .map(ToString::to_string)
+                    .unwrap_or_default();
+                match setting.as_str() {
+                    "custom_id" => info.custom_id = meta.value()?.parse::<LitStr>()?.value(),
+                    "label" => label = Some(meta.value()?.parse::<LitStr>()?.value()),
+                    "paragraph" => info.paragraph = true,
+                    "min_length" => info.min_length = Some(meta.value()?.parse()?),
+                    "max_length" => info.max_length = Some(meta.value()?.parse()?),
+                    "placeholder" => info.placeholder = Some(meta.value()?.parse()?),
+                    "value" => info.value = Some(meta.value()?.parse()?),
+                    _ => return Err(meta.error(
+                        "unknown input setting, expected one of `custom_id`, `label`, `paragraph`, `min_length`, `max_length`, `placeholder`, `value`",
+                    )),
+                }
+                Ok(())
+            })?;
+        }
+
+        info.label = label.ok_or_else(|| {
+            syn::Error::new(
+                info.ident.span(),
+                "input needs a label, add a doc comment or `#[input(label = \"...\")]`",
+            )
+        })?;
+        Ok(info)
+    }
+
+    /// Expression creating the action row holding the `TextInput`
+    fn component(&self) -> TokenStream {
+        let Self {
+            ty,
+            custom_id,
+            label,
+            ..
+        } = self;
+        let style = if self.paragraph {
+            quote!(::vivcord::datatypes::TextInputStyle::Paragraph)
+        } else {
+            quote!(::vivcord::datatypes::TextInputStyle::Short)
+        };
+        let optional = |setting: Option<TokenStream>| {
+            setting.map_or_else(
+                || quote!(::core::option::Option::None),
+                |value| quote!(::core::option::Option::Some(#value)),
+            )
+        };
+        let min_length = optional(self.min_length.as_ref().map(|value| quote!(#value)));
+        let max_length = optional(self.max_length.as_ref().map(|value| quote!(#value)));
+        let placeholder = optional(
+            self.placeholder
+                .as_ref()
+                .map(|value| quote!(::std::string::String::from(#value))),
+        );
+        let value = optional(
+            self.value
+                .as_ref()
+                .map(|value| quote!(::std::string::String::from(#value))),
+        );
+
+        quote! {
+            ::vivcord::datatypes::Component::from(::vivcord::datatypes::ActionRow::new([
+                ::vivcord::datatypes::TextInput {
+                    required: ::core::option::Option::Some(
+                        <#ty as ::vivcord::framework::ModalValue>::REQUIRED,
+                    ),
+                    min_length: #min_length,
+                    max_length: #max_length,
+                    placeholder: #placeholder,
+                    value: #value,
+                    ..::vivcord::datatypes::TextInput::new(#style, #custom_id, #label)
+                },
+            ]))
+        }
+    }
+}
+
+/// `#[derive(ModalForm)]`
+pub fn derive(input: &syn::DeriveInput) -> syn::Result<TokenStream> {
+    let syn::Data::Struct(syn::DataStruct {
+        fields: syn::Fields::Named(fields),
+        ..
+    }) = &input.data
+    else {
+        return Err(syn::Error::new(
+            input.span(),
+            "ModalForm can only be derived for structs with named fields",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "modals can not be generic",
+        ));
+    }
+
+    let inputs = fields
+        .named
+        .iter()
+        .map(InputInfo::new)
+        .collect::<syn::Result<Vec<_>>>()?;
+    let components = inputs.iter().map(InputInfo::component);
+    let values = inputs.iter().map(|input| {
+        let InputInfo {
+            ident,
+            ty,
+            custom_id,
+            ..
+        } = input;
+        quote! {
+            #ident: <#ty as ::vivcord::framework::ModalValue>::parse(#custom_id, data.value(#custom_id))?
+        }
+    });
+    let ident = &input.ident;
+
+    Ok(quote! {
+        impl ::vivcord::framework::ModalForm for #ident {
+            fn components() -> ::std::vec::Vec<::vivcord::datatypes::Component> {
+                ::std::vec![#(#components),*]
+            }
+
+            fn parse(
+                data: &::vivcord::datatypes::ModalSubmitData,
+            ) -> ::core::result::Result<Self, ::vivcord::framework::ModalParseError> {
+                ::core::result::Result::Ok(Self {
+                    #(#values,)*
+                })
+            }
+        }
+    })
+}